@@ -0,0 +1,253 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Values that can be compared against an `[alerts]` threshold
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AlertMetric {
+    GpuTemp,
+    GpuUtil,
+    CpuTemp,
+    MemPct,
+}
+
+impl AlertMetric {
+    /// Config key, also passed to the alert command
+    pub fn code(self) -> &'static str {
+        match self {
+            AlertMetric::GpuTemp => "gpu_temp",
+            AlertMetric::GpuUtil => "gpu_util",
+            AlertMetric::CpuTemp => "cpu_temp",
+            AlertMetric::MemPct => "mem_pct",
+        }
+    }
+
+    pub fn unit(self) -> &'static str {
+        match self {
+            AlertMetric::GpuTemp | AlertMetric::CpuTemp => "°C",
+            AlertMetric::GpuUtil | AlertMetric::MemPct => "%",
+        }
+    }
+}
+
+/// Thresholds from the `[alerts]` config section; `None` disables a metric
+#[derive(Clone, Copy, Debug, Default)]
+pub struct AlertThresholds {
+    pub gpu_temp: Option<f32>,
+    pub gpu_util: Option<f32>,
+    pub cpu_temp: Option<f32>,
+    pub mem_pct: Option<f32>,
+    pub low_mem: Option<LowMemThreshold>,
+}
+
+impl AlertThresholds {
+    pub fn get(&self, metric: AlertMetric) -> Option<f32> {
+        match metric {
+            AlertMetric::GpuTemp => self.gpu_temp,
+            AlertMetric::GpuUtil => self.gpu_util,
+            AlertMetric::CpuTemp => self.cpu_temp,
+            AlertMetric::MemPct => self.mem_pct,
+        }
+    }
+}
+
+/// `[alerts] low_mem`: available memory below a share of RAM or a fixed size
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LowMemThreshold {
+    Percent(f32),
+    Bytes(u64),
+}
+
+impl LowMemThreshold {
+    /// Parses "10%" or a size such as "512M" / "1.5GiB" (binary units).
+    pub fn parse(value: &str) -> Option<Self> {
+        let value = value.trim();
+        if let Some(pct) = value.strip_suffix('%') {
+            let pct = pct.trim().parse::<f32>().ok()?;
+            return (pct > 0.0 && pct < 100.0).then_some(LowMemThreshold::Percent(pct));
+        }
+        let upper = value.to_ascii_uppercase();
+        let digits = upper.trim_end_matches(['B', 'I']);
+        let (number, scale) = match digits.chars().last()? {
+            'K' => (&digits[..digits.len() - 1], 1u64 << 10),
+            'M' => (&digits[..digits.len() - 1], 1 << 20),
+            'G' => (&digits[..digits.len() - 1], 1 << 30),
+            'T' => (&digits[..digits.len() - 1], 1 << 40),
+            _ => (digits, 1),
+        };
+        let number = number.trim().parse::<f64>().ok()?;
+        let bytes = (number * scale as f64) as u64;
+        (bytes > 0).then_some(LowMemThreshold::Bytes(bytes))
+    }
+
+    pub fn is_low(self, available: u64, total: u64) -> bool {
+        match self {
+            LowMemThreshold::Percent(pct) => {
+                total > 0 && (available as f64) < total as f64 * f64::from(pct) / 100.0
+            }
+            LowMemThreshold::Bytes(bytes) => available < bytes,
+        }
+    }
+}
+
+/// What happens besides the flash when a threshold is crossed
+#[derive(Clone, Debug, Default)]
+pub struct AlertActions {
+    /// Write a terminal bell
+    pub bell: bool,
+    /// Shell command run with the metric, source and value as `$1`..`$3`
+    pub command: Option<String>,
+}
+
+/// A value must fall this far below its threshold before it can fire again,
+/// so readings hovering around the limit do not alert on every sample.
+const ALERT_HYSTERESIS: f32 = 2.0;
+/// While memory stays low, the low-memory warning repeats at most this often
+const LOW_MEM_REPEAT: Duration = Duration::from_secs(60);
+
+/// Remembers which sources are above their threshold so that only upward
+/// crossings start a flash or an alert action.
+pub struct AlertState {
+    thresholds: AlertThresholds,
+    flash_duration: Duration,
+    /// Sources above their threshold on the last observation
+    above: HashMap<(AlertMetric, String), bool>,
+    flash_until: HashMap<(AlertMetric, String), Instant>,
+    /// Last low-memory warning; cleared once memory recovers
+    low_mem_warned: Option<Instant>,
+}
+
+impl AlertState {
+    pub fn new(thresholds: AlertThresholds, flash_duration: Duration) -> Self {
+        Self {
+            thresholds,
+            flash_duration,
+            above: HashMap::new(),
+            flash_until: HashMap::new(),
+            low_mem_warned: None,
+        }
+    }
+
+    /// Records a reading for `source` (GPU id, sensor label) and returns true
+    /// when it has just crossed the threshold.
+    pub fn observe(&mut self, metric: AlertMetric, source: &str, value: f32, now: Instant) -> bool {
+        let Some(threshold) = self.thresholds.get(metric) else {
+            return false;
+        };
+        let key = (metric, source.to_string());
+        let was_above = self.above.get(&key).copied().unwrap_or(false);
+        let above = if was_above {
+            value > threshold - ALERT_HYSTERESIS
+        } else {
+            value > threshold
+        };
+        self.above.insert(key.clone(), above);
+        let crossed = above && !was_above;
+        if crossed {
+            self.flash_until.insert(key, now + self.flash_duration);
+        }
+        crossed
+    }
+
+    /// Records available memory and returns true when a low-memory warning
+    /// is due: on the first low reading, then every `LOW_MEM_REPEAT`.
+    pub fn observe_low_mem(&mut self, available: u64, total: u64, now: Instant) -> bool {
+        let Some(threshold) = self.thresholds.low_mem else {
+            return false;
+        };
+        if !threshold.is_low(available, total) {
+            self.low_mem_warned = None;
+            return false;
+        }
+        let due = self
+            .low_mem_warned
+            .is_none_or(|warned| now.saturating_duration_since(warned) >= LOW_MEM_REPEAT);
+        if due {
+            self.low_mem_warned = Some(now);
+        }
+        due
+    }
+
+    pub fn threshold(&self, metric: AlertMetric) -> Option<f32> {
+        self.thresholds.get(metric)
+    }
+
+    pub fn is_flashing(&self, metric: AlertMetric, source: &str, now: Instant) -> bool {
+        self.flash_until
+            .get(&(metric, source.to_string()))
+            .is_some_and(|until| now < *until)
+    }
+
+    /// Drops expired flashes.
+    pub fn clear_expired(&mut self, now: Instant) {
+        self.flash_until.retain(|_, until| now < *until);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flashes_once_per_crossing() {
+        let thresholds = AlertThresholds {
+            gpu_temp: Some(85.0),
+            ..AlertThresholds::default()
+        };
+        let mut state = AlertState::new(thresholds, Duration::from_secs(2));
+        let start = Instant::now();
+        assert!(!state.observe(AlertMetric::GpuTemp, "nvidia:0", 80.0, start));
+        assert!(state.observe(AlertMetric::GpuTemp, "nvidia:0", 90.0, start));
+        assert!(state.is_flashing(AlertMetric::GpuTemp, "nvidia:0", start));
+        assert!(!state.observe(AlertMetric::GpuTemp, "nvidia:0", 91.0, start));
+        let later = start + Duration::from_secs(3);
+        assert!(!state.is_flashing(AlertMetric::GpuTemp, "nvidia:0", later));
+        // Dipping just under the limit does not re-arm the alert
+        assert!(!state.observe(AlertMetric::GpuTemp, "nvidia:0", 84.0, later));
+        assert!(!state.observe(AlertMetric::GpuTemp, "nvidia:0", 86.0, later));
+        assert!(!state.observe(AlertMetric::GpuTemp, "nvidia:0", 70.0, later));
+        assert!(state.observe(AlertMetric::GpuTemp, "nvidia:0", 86.0, later));
+        // Disabled metrics never fire
+        assert!(!state.observe(AlertMetric::CpuTemp, "coretemp", 120.0, later));
+    }
+
+    #[test]
+    fn low_mem_threshold_parse() {
+        assert_eq!(
+            LowMemThreshold::parse("10%"),
+            Some(LowMemThreshold::Percent(10.0))
+        );
+        assert_eq!(
+            LowMemThreshold::parse("512M"),
+            Some(LowMemThreshold::Bytes(512 << 20))
+        );
+        assert_eq!(
+            LowMemThreshold::parse("1.5GiB"),
+            Some(LowMemThreshold::Bytes(3 << 29))
+        );
+        assert_eq!(
+            LowMemThreshold::parse("4096"),
+            Some(LowMemThreshold::Bytes(4096))
+        );
+        assert_eq!(LowMemThreshold::parse(""), None);
+        assert_eq!(LowMemThreshold::parse("0%"), None);
+        assert_eq!(LowMemThreshold::parse("lots"), None);
+    }
+
+    #[test]
+    fn low_mem_warning_is_debounced() {
+        let thresholds = AlertThresholds {
+            low_mem: Some(LowMemThreshold::Percent(10.0)),
+            ..AlertThresholds::default()
+        };
+        let mut state = AlertState::new(thresholds, Duration::from_secs(2));
+        let start = Instant::now();
+        assert!(!state.observe_low_mem(500, 1000, start));
+        assert!(state.observe_low_mem(50, 1000, start));
+        assert!(!state.observe_low_mem(40, 1000, start + Duration::from_secs(5)));
+        assert!(state.observe_low_mem(40, 1000, start + LOW_MEM_REPEAT));
+        // Recovering re-arms the warning immediately
+        let later = start + LOW_MEM_REPEAT + Duration::from_secs(1);
+        assert!(!state.observe_low_mem(300, 1000, later));
+        assert!(state.observe_low_mem(60, 1000, later));
+    }
+}
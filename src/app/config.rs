@@ -5,12 +5,19 @@ use std::time::Duration;
 
 use serde::Deserialize;
 
+use super::alerts::{AlertActions, AlertThresholds, LowMemThreshold};
 use super::state::Language;
-use super::{IconMode, LogoMode, LogoQuality};
+use super::{ContainerSort, IconMode, LogoMode, LogoQuality, PageMode, UpdateStrategy, ViewMode};
 use crate::data::{GpuPreference, SortDir, SortKey};
+use crate::utils::{ByteUnit, TempUnit, TimeFormat};
 
 const MIN_TICK_MS: u64 = 100;
 const DEFAULT_TICK_MS: u64 = 1000;
+const DEFAULT_IDLE_SLOWDOWN: u32 = 4;
+const DEFAULT_DOUBLE_CLICK_MS: u64 = 400;
+const DEFAULT_NAME_MIN_WIDTH: u16 = 10;
+const MAX_NAME_MIN_WIDTH: u16 = 200;
+const DEFAULT_SEARCH_PANEL_PCT: u16 = 30;
 
 /// Runtime configuration
 pub struct Config {
@@ -18,12 +25,74 @@ pub struct Config {
     pub vram_enabled: bool,
     pub sort_key: SortKey,
     pub sort_dir: SortDir,
+    /// Tiebreaker applied before PID when the sort column ties
+    pub sort_secondary: Option<SortKey>,
     pub gpu_pref: GpuPreference,
     pub gpu_poll_rate: Duration,
     pub language: Language,
     pub icon_mode: IconMode,
     pub logo_mode: LogoMode,
     pub logo_quality: LogoQuality,
+    pub default_view: ViewMode,
+    pub show_top_cpu: bool,
+    /// Show the time until the next refresh in the footer
+    pub show_countdown: bool,
+    /// 24h or 12h clock for absolute timestamps
+    pub time_format: TimeFormat,
+    /// IEC (KiB) or SI (kB) sizes
+    pub byte_unit: ByteUnit,
+    /// Celsius or Fahrenheit for temperatures
+    pub temp_unit: TempUnit,
+    /// GPU id or 1-based index to select at startup (`[display] gpu_select`)
+    pub gpu_select: Option<String>,
+    /// Let the GPU dashboard take the whole view while no processes use the GPU
+    pub gpu_compact: bool,
+    /// List every GPU in the system overview instead of the selected one
+    pub overview_all_gpus: bool,
+    /// Drop flashes, blinking and rotating hints
+    pub reduce_motion: bool,
+    /// No colors; text attributes only
+    pub monochrome: bool,
+    pub page_mode: PageMode,
+    /// Width of the Overview search panel in percent
+    pub search_panel_pct: u16,
+    /// `search_panel_pct = 0`: hide the search panel until the filter is used
+    pub search_panel_collapsed: bool,
+    pub no_exec: bool,
+    pub confirm_quit: bool,
+    /// Allow opening a process's working directory with `xdg-open`
+    pub open_cwd: bool,
+    /// Slow refresh down after this long without input (None = never)
+    pub idle_timeout: Option<Duration>,
+    pub idle_slowdown: u32,
+    /// Max gap between clicks on a process row for a double-click (None = off)
+    pub double_click: Option<Duration>,
+    pub name_min_width: u16,
+    pub script_names: bool,
+    /// Show the PRI and NI columns
+    pub show_priority: bool,
+    /// Show the systemd UNIT column
+    pub show_unit: bool,
+    /// Show the per-process SWAP column
+    pub show_swap: bool,
+    /// Show the per-process GPU%/VRAM columns
+    pub show_gpu: bool,
+    /// Keep only the top N rows of the sorted list (None = all)
+    pub max_rows: Option<usize>,
+    /// Process name patterns (`*` / `?` wildcards) hidden from the list
+    pub ignore: Vec<String>,
+    pub update_strategy: UpdateStrategy,
+    pub hide_fs: Vec<String>,
+    pub show_fs: Vec<String>,
+    /// Show the UPTIME column in the Container view
+    pub container_uptime: bool,
+    pub container_sort: ContainerSort,
+    pub alerts: AlertThresholds,
+    pub alert_actions: AlertActions,
+    /// Print the key reference and exit (`--keys`)
+    pub print_keys: bool,
+    /// Start with the timing overlay shown (`--debug`)
+    pub debug_overlay: bool,
 }
 
 /// File-based configuration (TOML)
@@ -32,6 +101,10 @@ pub struct Config {
 struct FileConfig {
     general: GeneralConfig,
     display: DisplayConfig,
+    processes: ProcessesConfig,
+    disks: DisksConfig,
+    containers: ContainersConfig,
+    alerts: AlertsConfig,
 }
 
 #[derive(Debug, Deserialize)]
@@ -39,6 +112,12 @@ struct FileConfig {
 struct GeneralConfig {
     tick_rate_ms: u64,
     gpu_poll_ms: u64,
+    no_exec: bool,
+    confirm_quit: bool,
+    open_cwd: bool,
+    idle_timeout_secs: u64,
+    idle_slowdown: u32,
+    double_click_ms: u64,
 }
 
 impl Default for GeneralConfig {
@@ -46,6 +125,12 @@ impl Default for GeneralConfig {
         Self {
             tick_rate_ms: DEFAULT_TICK_MS,
             gpu_poll_ms: 2000,
+            no_exec: false,
+            confirm_quit: false,
+            open_cwd: false,
+            idle_timeout_secs: 0,
+            idle_slowdown: DEFAULT_IDLE_SLOWDOWN,
+            double_click_ms: DEFAULT_DOUBLE_CLICK_MS,
         }
     }
 }
@@ -56,11 +141,25 @@ struct DisplayConfig {
     show_vram: bool,
     default_sort: String,
     sort_dir: String,
+    secondary_sort: String,
     gpu_preference: String,
     language: String,
     icon_mode: String,
     logo_mode: String,
     logo_quality: String,
+    default_view: String,
+    show_top_cpu: bool,
+    show_countdown: bool,
+    gpu_compact: bool,
+    overview_all_gpus: bool,
+    reduce_motion: bool,
+    monochrome: bool,
+    search_panel_pct: u16,
+    page_mode: String,
+    gpu_select: String,
+    time_format: String,
+    byte_unit: String,
+    temp_unit: String,
 }
 
 impl Default for DisplayConfig {
@@ -69,19 +168,156 @@ impl Default for DisplayConfig {
             show_vram: true,
             default_sort: "cpu".to_string(),
             sort_dir: String::new(),
+            secondary_sort: String::new(),
             gpu_preference: "auto".to_string(),
             language: "en".to_string(),
             icon_mode: "text".to_string(),
             logo_mode: "ascii".to_string(),
             logo_quality: "medium".to_string(),
+            default_view: "overview".to_string(),
+            show_top_cpu: true,
+            show_countdown: false,
+            gpu_compact: false,
+            overview_all_gpus: false,
+            reduce_motion: false,
+            monochrome: false,
+            search_panel_pct: DEFAULT_SEARCH_PANEL_PCT,
+            page_mode: "cursor".to_string(),
+            gpu_select: String::new(),
+            time_format: "24h".to_string(),
+            byte_unit: "iec".to_string(),
+            temp_unit: "c".to_string(),
         }
     }
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(default)]
+struct ProcessesConfig {
+    name_min_width: u16,
+    script_names: bool,
+    show_priority: bool,
+    show_unit: bool,
+    show_swap: bool,
+    show_gpu: bool,
+    max_rows: usize,
+    ignore: Vec<String>,
+    update_strategy: String,
+}
+
+impl Default for ProcessesConfig {
+    fn default() -> Self {
+        Self {
+            name_min_width: DEFAULT_NAME_MIN_WIDTH,
+            script_names: false,
+            show_priority: false,
+            show_unit: false,
+            show_swap: false,
+            show_gpu: false,
+            max_rows: 0,
+            ignore: Vec::new(),
+            update_strategy: "full".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct DisksConfig {
+    hide_fs: Vec<String>,
+    show_fs: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(default)]
+struct ContainersConfig {
+    show_uptime: bool,
+    sort: String,
+}
+
+impl Default for ContainersConfig {
+    fn default() -> Self {
+        Self {
+            show_uptime: true,
+            sort: "cpu".to_string(),
+        }
+    }
+}
+
+/// Alert thresholds (0 disables a metric) and actions
+#[derive(Debug, Deserialize)]
+#[serde(default)]
+struct AlertsConfig {
+    gpu_temp: f32,
+    gpu_util: f32,
+    cpu_temp: f32,
+    mem_pct: f32,
+    low_mem: String,
+    bell: bool,
+    command: String,
+}
+
+impl Default for AlertsConfig {
+    fn default() -> Self {
+        Self {
+            gpu_temp: 0.0,
+            gpu_util: 0.0,
+            cpu_temp: 0.0,
+            mem_pct: 0.0,
+            low_mem: String::new(),
+            bell: false,
+            command: String::new(),
+        }
+    }
+}
+
+impl AlertsConfig {
+    fn thresholds(&self) -> AlertThresholds {
+        let enabled = |value: f32| (value > 0.0).then_some(value);
+        AlertThresholds {
+            gpu_temp: enabled(self.gpu_temp),
+            gpu_util: enabled(self.gpu_util),
+            cpu_temp: enabled(self.cpu_temp),
+            mem_pct: enabled(self.mem_pct),
+            low_mem: parse_low_mem(&self.low_mem).unwrap_or_else(|message| {
+                eprintln!("{message}");
+                None
+            }),
+        }
+    }
+
+    fn actions(&self) -> AlertActions {
+        let command = self.command.trim();
+        AlertActions {
+            bell: self.bell,
+            command: (!command.is_empty()).then(|| command.to_string()),
+        }
+    }
+}
+
+/// `[alerts] low_mem`: empty disables the check, anything unparsable is an error
+fn parse_low_mem(value: &str) -> Result<Option<LowMemThreshold>, String> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Ok(None);
+    }
+    LowMemThreshold::parse(value)
+        .map(Some)
+        .ok_or_else(|| format!("Ignoring invalid [alerts] low_mem = {value:?}"))
+}
+
 impl Config {
     pub fn from_args() -> Result<Self, String> {
+        // Answer --version before touching the config file
+        if env::args()
+            .skip(1)
+            .any(|arg| arg == "--version" || arg == "-V")
+        {
+            return Err(version());
+        }
+
         // Load file config first
-        let file_config = match load_config_file() {
+        let mut file_config = match load_config_file() {
             Ok(Some(config)) => config,
             Ok(None) => FileConfig::default(),
             Err(message) => {
@@ -90,10 +326,30 @@ impl Config {
             }
         };
 
+        // Environment overrides the file; CLI flags override both
+        if let Some(value) = env_override("RTOP_TICK_MS", |value| value.parse::<u64>().ok()) {
+            file_config.general.tick_rate_ms = value;
+        }
+        if let Some(value) = env_override("RTOP_GPU_POLL_MS", |value| value.parse::<u64>().ok()) {
+            file_config.general.gpu_poll_ms = value;
+        }
+        if let Some(no_vram) = env_override("RTOP_NO_VRAM", parse_env_bool) {
+            file_config.display.show_vram = !no_vram;
+        }
+
+        Self::resolve(file_config, env::args().skip(1))
+    }
+
+    /// Merge file values with command-line `args`
+    fn resolve(
+        file_config: FileConfig,
+        args: impl IntoIterator<Item = String>,
+    ) -> Result<Self, String> {
         // Start with file config values
         let mut tick_ms = file_config.general.tick_rate_ms;
         let mut vram_enabled = file_config.display.show_vram;
         let mut gpu_poll_ms = file_config.general.gpu_poll_ms;
+        let mut no_exec = file_config.general.no_exec;
         let mut sort_key =
             SortKey::parse(&file_config.display.default_sort).unwrap_or(SortKey::Cpu);
         let mut sort_dir: Option<SortDir> = if file_config.display.sort_dir.is_empty() {
@@ -108,12 +364,44 @@ impl Config {
         let logo_mode = LogoMode::parse(&file_config.display.logo_mode).unwrap_or(LogoMode::Ascii);
         let logo_quality =
             LogoQuality::parse(&file_config.display.logo_quality).unwrap_or(LogoQuality::Medium);
+        let default_view =
+            ViewMode::parse(&file_config.display.default_view).unwrap_or(ViewMode::Overview);
+        let show_top_cpu = file_config.display.show_top_cpu;
+        let show_countdown = file_config.display.show_countdown;
+        let gpu_compact = file_config.display.gpu_compact;
+        let overview_all_gpus = file_config.display.overview_all_gpus;
+        let reduce_motion = file_config.display.reduce_motion;
+        let monochrome = file_config.display.monochrome;
+        let page_mode = PageMode::parse(&file_config.display.page_mode).unwrap_or_default();
+        let search_panel_collapsed = file_config.display.search_panel_pct == 0;
+        let search_panel_pct = match file_config.display.search_panel_pct {
+            0 => DEFAULT_SEARCH_PANEL_PCT,
+            pct => pct.clamp(10, 70),
+        };
+        let time_format = TimeFormat::parse(&file_config.display.time_format).unwrap_or_default();
+        let byte_unit = ByteUnit::parse(&file_config.display.byte_unit).unwrap_or_default();
+        let temp_unit = TempUnit::parse(&file_config.display.temp_unit).unwrap_or_default();
+        let gpu_select = Some(file_config.display.gpu_select.trim().to_string())
+            .filter(|value| !value.is_empty());
+        let idle_timeout = (file_config.general.idle_timeout_secs > 0)
+            .then(|| Duration::from_secs(file_config.general.idle_timeout_secs));
+        let idle_slowdown = file_config.general.idle_slowdown.max(1);
+        let double_click = (file_config.general.double_click_ms > 0)
+            .then(|| Duration::from_millis(file_config.general.double_click_ms));
+        let name_min_width = file_config
+            .processes
+            .name_min_width
+            .clamp(1, MAX_NAME_MIN_WIDTH);
 
         // Override with CLI args
-        let mut args = env::args().skip(1);
+        let mut print_keys = false;
+        let mut debug_overlay = false;
+        let mut args = args.into_iter();
         while let Some(arg) = args.next() {
             match arg.as_str() {
                 "--help" | "-h" => return Err(usage()),
+                "--keys" => print_keys = true,
+                "--debug" => debug_overlay = true,
                 "--tick-ms" => {
                     let value = args
                         .next()
@@ -123,6 +411,7 @@ impl Config {
                         .map_err(|_| format!("Invalid tick value: {value}\n\n{}", usage()))?;
                 }
                 "--no-vram" => vram_enabled = false,
+                "--no-exec" => no_exec = true,
                 "--sort" => {
                     let value = args
                         .next()
@@ -159,16 +448,65 @@ impl Config {
             vram_enabled,
             sort_key,
             sort_dir,
+            sort_secondary: SortKey::parse(&file_config.display.secondary_sort),
             gpu_pref,
             gpu_poll_rate: Duration::from_millis(gpu_poll_ms),
             language,
             icon_mode,
             logo_mode,
             logo_quality,
+            default_view,
+            show_top_cpu,
+            show_countdown,
+            time_format,
+            byte_unit,
+            temp_unit,
+            gpu_compact,
+            overview_all_gpus,
+            reduce_motion,
+            monochrome,
+            page_mode,
+            search_panel_pct,
+            search_panel_collapsed,
+            gpu_select,
+            no_exec,
+            confirm_quit: file_config.general.confirm_quit,
+            open_cwd: file_config.general.open_cwd,
+            idle_timeout,
+            idle_slowdown,
+            double_click,
+            name_min_width,
+            script_names: file_config.processes.script_names,
+            show_priority: file_config.processes.show_priority,
+            show_unit: file_config.processes.show_unit,
+            show_swap: file_config.processes.show_swap,
+            show_gpu: file_config.processes.show_gpu,
+            max_rows: (file_config.processes.max_rows > 0)
+                .then_some(file_config.processes.max_rows),
+            ignore: file_config.processes.ignore,
+            update_strategy: UpdateStrategy::parse(&file_config.processes.update_strategy)
+                .unwrap_or_default(),
+            hide_fs: file_config.disks.hide_fs,
+            show_fs: file_config.disks.show_fs,
+            container_uptime: file_config.containers.show_uptime,
+            container_sort: ContainerSort::parse(&file_config.containers.sort).unwrap_or_default(),
+            alerts: file_config.alerts.thresholds(),
+            alert_actions: file_config.alerts.actions(),
+            print_keys,
+            debug_overlay,
         })
     }
 }
 
+/// Built-in defaults, as if no config file, environment or flags were given.
+///
+/// Useful for driving [`App`](crate::app::App) from tests and benchmarks.
+impl Default for Config {
+    fn default() -> Self {
+        Self::resolve(FileConfig::default(), std::iter::empty()).expect("no arguments to reject")
+    }
+}
+
 fn config_path() -> Option<PathBuf> {
     dirs::config_dir().map(|p| p.join("rtop").join("config.toml"))
 }
@@ -222,6 +560,73 @@ pub fn save_display_preferences(
     icon_mode: IconMode,
     logo_mode: LogoMode,
     logo_quality: LogoQuality,
+) -> Result<(), String> {
+    update_display_section(|display_table| {
+        display_table.insert(
+            "language".to_string(),
+            toml::Value::String(language.code().to_string()),
+        );
+        display_table.insert(
+            "icon_mode".to_string(),
+            toml::Value::String(icon_mode.code().to_string()),
+        );
+        display_table.insert(
+            "logo_mode".to_string(),
+            toml::Value::String(logo_mode.code().to_string()),
+        );
+        display_table.insert(
+            "logo_quality".to_string(),
+            toml::Value::String(logo_quality.code().to_string()),
+        );
+    })
+}
+
+/// Stores the pinned GPU id as `[display] gpu_select`.
+pub fn save_gpu_select(gpu_id: &str) -> Result<(), String> {
+    update_display_section(|display_table| {
+        display_table.insert(
+            "gpu_select".to_string(),
+            toml::Value::String(gpu_id.to_string()),
+        );
+    })
+}
+
+/// Stores the size convention as `[display] byte_unit`.
+pub fn save_byte_unit(unit: ByteUnit) -> Result<(), String> {
+    update_display_section(|display_table| {
+        display_table.insert(
+            "byte_unit".to_string(),
+            toml::Value::String(unit.code().to_string()),
+        );
+    })
+}
+
+/// Stores the temperature scale as `[display] temp_unit`.
+pub fn save_temp_unit(unit: TempUnit) -> Result<(), String> {
+    update_display_section(|display_table| {
+        display_table.insert(
+            "temp_unit".to_string(),
+            toml::Value::String(unit.code().to_string()),
+        );
+    })
+}
+
+/// Stores an on/off option such as `[processes] show_unit`.
+pub fn save_bool_option(section: &str, key: &str, value: bool) -> Result<(), String> {
+    update_section(section, |table| {
+        table.insert(key.to_string(), toml::Value::Boolean(value));
+    })
+}
+
+fn update_display_section(
+    apply: impl FnOnce(&mut toml::map::Map<String, toml::Value>),
+) -> Result<(), String> {
+    update_section("display", apply)
+}
+
+fn update_section(
+    section: &str,
+    apply: impl FnOnce(&mut toml::map::Map<String, toml::Value>),
 ) -> Result<(), String> {
     let Some(path) = config_path() else {
         return Err("Config path unavailable".to_string());
@@ -235,31 +640,16 @@ pub fn save_display_preferences(
     let table = root
         .as_table_mut()
         .ok_or_else(|| format!("Config file {} has invalid format", path.display()))?;
-    let display = table
-        .entry("display".to_string())
+    let section_value = table
+        .entry(section.to_string())
         .or_insert_with(|| toml::Value::Table(Default::default()));
-    let display_table = display.as_table_mut().ok_or_else(|| {
+    let section_table = section_value.as_table_mut().ok_or_else(|| {
         format!(
-            "Config file {} has invalid [display] section",
+            "Config file {} has invalid [{section}] section",
             path.display()
         )
     })?;
-    display_table.insert(
-        "language".to_string(),
-        toml::Value::String(language.code().to_string()),
-    );
-    display_table.insert(
-        "icon_mode".to_string(),
-        toml::Value::String(icon_mode.code().to_string()),
-    );
-    display_table.insert(
-        "logo_mode".to_string(),
-        toml::Value::String(logo_mode.code().to_string()),
-    );
-    display_table.insert(
-        "logo_quality".to_string(),
-        toml::Value::String(logo_quality.code().to_string()),
-    );
+    apply(section_table);
 
     let output = toml::to_string_pretty(&root)
         .map_err(|err| format!("Failed to serialize config: {err}"))?;
@@ -268,6 +658,16 @@ pub fn save_display_preferences(
     Ok(())
 }
 
+/// Version line printed by `--version`: "rtop <version> (<commit> <build date>)".
+pub fn version() -> String {
+    format!(
+        "rtop {} ({} {})",
+        env!("CARGO_PKG_VERSION"),
+        env!("RTOP_GIT_COMMIT"),
+        env!("RTOP_BUILD_DATE")
+    )
+}
+
 fn usage() -> String {
     let config_location = config_path()
         .map(|p| p.display().to_string())
@@ -279,30 +679,106 @@ fn usage() -> String {
         "Options:",
         "  --tick-ms <ms>     Refresh interval in milliseconds (default: 1000, min: 100)",
         "  --no-vram          Disable GPU probing",
-        "  --sort <key>       pid | user | cpu | mem | uptime | stat | name",
+        "  --no-exec          Never spawn external commands (xrandr, nvidia-smi, ...)",
+        "  --sort <key>       pid | user | cpu | mem | swap | gpu | vram | time | uptime | stat | pri | nice | unit | name",
         "  --sort-dir <dir>   asc | desc",
         "  --gpu <pref>       auto | discrete | integrated",
+        "  --keys             Print the key bindings and exit",
+        "  --debug            Show draw/refresh timings (toggle with F3)",
+        "  -V, --version      Show version and build info",
         "  -h, --help         Show this help",
         "",
+        "Environment (overrides the config file, overridden by options):",
+        "  RTOP_TICK_MS       Refresh interval in milliseconds",
+        "  RTOP_GPU_POLL_MS   GPU polling interval in milliseconds",
+        "  RTOP_NO_VRAM       1/true disables GPU probing, 0/false enables it",
+        "",
         &format!("Config file: {config_location}"),
         "",
         "Example config.toml:",
         "  [general]",
         "  tick_rate_ms = 1000",
         "  gpu_poll_ms = 2000",
+        "  no_exec = false",
+        "  confirm_quit = false",
+        "  open_cwd = false",
+        "  idle_timeout_secs = 0",
+        "  idle_slowdown = 4",
+        "  double_click_ms = 400",
         "",
         "  [display]",
         "  show_vram = true",
         "  default_sort = \"cpu\"",
         "  sort_dir = \"desc\"",
+        "  secondary_sort = \"\"",
         "  gpu_preference = \"auto\"",
         "  language = \"en\"",
         "  logo_mode = \"ascii\"",
         "  logo_quality = \"medium\"",
+        "  default_view = \"overview\"",
+        "  show_top_cpu = true",
+        "  show_countdown = false",
+        "  gpu_compact = false",
+        "  overview_all_gpus = false",
+        "  reduce_motion = false",
+        "  monochrome = false",
+        "  search_panel_pct = 30",
+        "  page_mode = \"cursor\"",
+        "  gpu_select = \"nvidia:0\"",
+        "  time_format = \"24h\"",
+        "  byte_unit = \"iec\"",
+        "  temp_unit = \"c\"",
+        "",
+        "  [processes]",
+        "  name_min_width = 10",
+        "  script_names = false",
+        "  show_priority = false",
+        "  show_unit = false",
+        "  show_swap = false",
+        "  show_gpu = false",
+        "  max_rows = 0",
+        "  ignore = [\"kworker/*\"]",
+        "  update_strategy = \"full\"",
+        "",
+        "  [disks]",
+        "  hide_fs = [\"nfs\"]",
+        "  show_fs = [\"tmpfs\"]",
+        "",
+        "  [containers]",
+        "  show_uptime = true",
+        "  sort = \"cpu\"",
+        "",
+        "  [alerts]",
+        "  gpu_temp = 85",
+        "  gpu_util = 95",
+        "  cpu_temp = 90",
+        "  mem_pct = 90",
+        "  low_mem = \"10%\"",
+        "  bell = false",
+        "  command = \"notify-send rtop \\\"$1 $2: $3\\\"\"",
     ]
     .join("\n")
 }
 
+/// Value of the environment variable `name` if `parse` accepts it; invalid
+/// values are reported on stderr and ignored so startup continues.
+fn env_override<T>(name: &str, parse: impl Fn(&str) -> Option<T>) -> Option<T> {
+    let value = env::var(name).ok()?;
+    let parsed = parse(value.trim());
+    if parsed.is_none() {
+        eprintln!("Ignoring invalid {name}={value:?}");
+    }
+    parsed
+}
+
+fn parse_env_bool(value: &str) -> Option<bool> {
+    match value.to_ascii_lowercase().as_str() {
+        "1" | "true" | "yes" | "on" => Some(true),
+        "0" | "false" | "no" | "off" => Some(false),
+        _ => None,
+    }
+}
+
 fn normalize_tick_ms(value: u64) -> u64 {
     value.max(MIN_TICK_MS)
 }
@@ -329,14 +805,199 @@ mod tests {
         assert_eq!(normalize_gpu_poll_ms(5000), 5000);
     }
 
+    #[test]
+    fn env_values_parse_leniently() {
+        assert_eq!(parse_env_bool("TRUE"), Some(true));
+        assert_eq!(parse_env_bool("0"), Some(false));
+        assert_eq!(parse_env_bool("maybe"), None);
+        assert_eq!(
+            env_override("RTOP_TEST_UNSET_VARIABLE", |value| value
+                .parse::<u64>()
+                .ok()),
+            None
+        );
+    }
+
+    #[test]
+    fn default_config_matches_empty_file() {
+        let config = Config::default();
+        assert_eq!(config.tick_rate, Duration::from_millis(DEFAULT_TICK_MS));
+        assert!(config.sort_key == SortKey::Cpu);
+        assert!(config.sort_dir == SortKey::Cpu.default_dir());
+        assert!(!config.print_keys);
+
+        let args = ["--no-vram", "--sort", "pid"].map(String::from);
+        let config = Config::resolve(FileConfig::default(), args).unwrap();
+        assert!(!config.vram_enabled);
+        assert!(config.sort_key == SortKey::Pid);
+        assert!(Config::resolve(FileConfig::default(), ["--bogus".to_string()]).is_err());
+    }
+
+    #[test]
+    fn name_min_width_is_clamped() {
+        let mut file_config: FileConfig =
+            toml::from_str("[processes]\nname_min_width = 65535").unwrap();
+        let config = Config::resolve(file_config, std::iter::empty()).unwrap();
+        assert_eq!(config.name_min_width, MAX_NAME_MIN_WIDTH);
+        file_config = toml::from_str("[processes]\nname_min_width = 0").unwrap();
+        let config = Config::resolve(file_config, std::iter::empty()).unwrap();
+        assert_eq!(config.name_min_width, 1);
+    }
+
+    #[test]
+    fn version_includes_package_version() {
+        assert!(version().starts_with(&format!("rtop {} (", env!("CARGO_PKG_VERSION"))));
+    }
+
     #[test]
     fn file_config_defaults() {
         let config: FileConfig = toml::from_str("").unwrap();
         assert_eq!(config.general.tick_rate_ms, DEFAULT_TICK_MS);
+        assert!(!config.general.no_exec);
+        assert!(!config.general.confirm_quit);
+        assert!(!config.general.open_cwd);
+        assert_eq!(config.general.idle_timeout_secs, 0);
+        assert_eq!(config.general.idle_slowdown, DEFAULT_IDLE_SLOWDOWN);
+        assert_eq!(config.general.double_click_ms, DEFAULT_DOUBLE_CLICK_MS);
         assert!(config.display.show_vram);
         assert_eq!(config.display.default_sort, "cpu");
+        assert!(config.display.secondary_sort.is_empty());
         assert_eq!(config.display.language, "en");
         assert_eq!(config.display.logo_quality, "medium");
+        assert_eq!(config.display.default_view, "overview");
+        assert!(config.display.show_top_cpu);
+        assert!(!config.display.show_countdown);
+        assert!(!config.display.gpu_compact);
+        assert!(!config.display.overview_all_gpus);
+        assert!(!config.display.reduce_motion);
+        assert!(!config.display.monochrome);
+        assert_eq!(config.display.search_panel_pct, DEFAULT_SEARCH_PANEL_PCT);
+        assert_eq!(config.display.page_mode, "cursor");
+        assert!(config.display.gpu_select.is_empty());
+        assert_eq!(config.display.time_format, "24h");
+        assert_eq!(config.display.byte_unit, "iec");
+        assert_eq!(config.display.temp_unit, "c");
+        assert_eq!(config.processes.name_min_width, DEFAULT_NAME_MIN_WIDTH);
+        assert!(!config.processes.script_names);
+        assert!(!config.processes.show_priority);
+        assert!(!config.processes.show_unit);
+        assert!(!config.processes.show_swap);
+        assert!(!config.processes.show_gpu);
+        assert_eq!(config.processes.max_rows, 0);
+        assert!(config.processes.ignore.is_empty());
+        assert_eq!(config.processes.update_strategy, "full");
+        assert!(config.disks.hide_fs.is_empty());
+        assert!(config.disks.show_fs.is_empty());
+        assert!(config.containers.show_uptime);
+        assert_eq!(config.containers.sort, "cpu");
+        assert_eq!(config.alerts.gpu_temp, 0.0);
+        assert_eq!(config.alerts.gpu_util, 0.0);
+        assert_eq!(config.alerts.cpu_temp, 0.0);
+        assert_eq!(config.alerts.mem_pct, 0.0);
+        assert!(config.alerts.low_mem.is_empty());
+        assert!(!config.alerts.bell);
+        assert!(config.alerts.command.is_empty());
+    }
+
+    #[test]
+    fn file_config_disks_section() {
+        let config: FileConfig = toml::from_str(
+            r#"
+            [disks]
+            hide_fs = ["nfs", "cifs"]
+            show_fs = ["tmpfs"]
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.disks.hide_fs, ["nfs", "cifs"]);
+        assert_eq!(config.disks.show_fs, ["tmpfs"]);
+    }
+
+    #[test]
+    fn file_config_containers_section() {
+        let config: FileConfig = toml::from_str(
+            r#"
+            [containers]
+            show_uptime = false
+            sort = "uptime"
+            "#,
+        )
+        .unwrap();
+        assert!(!config.containers.show_uptime);
+        assert_eq!(
+            ContainerSort::parse(&config.containers.sort),
+            Some(ContainerSort::Uptime)
+        );
+    }
+
+    #[test]
+    fn file_config_alerts_section() {
+        let config: FileConfig = toml::from_str(
+            r#"
+            [alerts]
+            gpu_temp = 0
+            gpu_util = 95
+            mem_pct = 92.5
+            low_mem = "5%"
+            bell = true
+            command = " notify-send rtop "
+            "#,
+        )
+        .unwrap();
+        let thresholds = config.alerts.thresholds();
+        assert_eq!(thresholds.gpu_temp, None);
+        assert_eq!(thresholds.gpu_util, Some(95.0));
+        assert_eq!(thresholds.cpu_temp, None);
+        assert_eq!(thresholds.mem_pct, Some(92.5));
+        assert_eq!(thresholds.low_mem, Some(LowMemThreshold::Percent(5.0)));
+        let actions = config.alerts.actions();
+        assert!(actions.bell);
+        assert_eq!(actions.command.as_deref(), Some("notify-send rtop"));
+    }
+
+    #[test]
+    fn file_config_rejects_invalid_low_mem() {
+        for value in ["lots", "10 %%"] {
+            let config: FileConfig =
+                toml::from_str(&format!("[alerts]\nlow_mem = {value:?}")).unwrap();
+            assert_eq!(
+                parse_low_mem(&config.alerts.low_mem),
+                Err(format!("Ignoring invalid [alerts] low_mem = {value:?}"))
+            );
+            assert_eq!(config.alerts.thresholds().low_mem, None);
+        }
+        assert_eq!(parse_low_mem(" "), Ok(None));
+    }
+
+    #[test]
+    fn file_config_processes_section() {
+        let config: FileConfig = toml::from_str(
+            r#"
+            [processes]
+            name_min_width = 24
+            script_names = true
+            show_priority = true
+            show_unit = true
+            show_swap = true
+            show_gpu = true
+            max_rows = 500
+            ignore = ["systemd-journald", "kworker/*"]
+            update_strategy = "incremental"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.processes.name_min_width, 24);
+        assert!(config.processes.script_names);
+        assert!(config.processes.show_priority);
+        assert!(config.processes.show_unit);
+        assert!(config.processes.show_swap);
+        assert!(config.processes.show_gpu);
+        assert_eq!(config.processes.max_rows, 500);
+        assert_eq!(config.processes.ignore, ["systemd-journald", "kworker/*"]);
+        assert_eq!(
+            UpdateStrategy::parse(&config.processes.update_strategy),
+            Some(UpdateStrategy::Incremental)
+        );
     }
 
     #[test]
@@ -361,29 +1022,77 @@ mod tests {
             [general]
             tick_rate_ms = 500
             gpu_poll_ms = 1500
+            no_exec = true
+            confirm_quit = true
+            open_cwd = true
+            idle_timeout_secs = 120
+            idle_slowdown = 3
+            double_click_ms = 250
 
             [display]
             show_vram = false
             default_sort = "mem"
             sort_dir = "asc"
+            secondary_sort = "mem"
             gpu_preference = "discrete"
             language = "ru"
             icon_mode = "nerd"
             logo_mode = "svg"
             logo_quality = "quality"
+            show_countdown = true
+            gpu_compact = true
+            overview_all_gpus = true
+            reduce_motion = true
+            monochrome = true
+            search_panel_pct = 25
+            page_mode = "viewport"
+            gpu_select = "2"
+            time_format = "12h"
+            byte_unit = "si"
+            temp_unit = "f"
             "#,
         )
         .unwrap();
         assert_eq!(config.general.tick_rate_ms, 500);
         assert_eq!(config.general.gpu_poll_ms, 1500);
+        assert!(config.general.no_exec);
+        assert!(config.general.confirm_quit);
+        assert!(config.general.open_cwd);
+        assert_eq!(config.general.idle_timeout_secs, 120);
+        assert_eq!(config.general.idle_slowdown, 3);
+        assert_eq!(config.general.double_click_ms, 250);
         assert!(!config.display.show_vram);
         assert_eq!(config.display.default_sort, "mem");
+        assert_eq!(config.display.secondary_sort, "mem");
         assert_eq!(config.display.sort_dir, "asc");
         assert_eq!(config.display.gpu_preference, "discrete");
         assert_eq!(config.display.language, "ru");
         assert_eq!(config.display.icon_mode, "nerd");
         assert_eq!(config.display.logo_mode, "svg");
         assert_eq!(config.display.logo_quality, "quality");
+        assert!(config.display.show_countdown);
+        assert!(config.display.gpu_compact);
+        assert!(config.display.overview_all_gpus);
+        assert!(config.display.reduce_motion);
+        assert!(config.display.monochrome);
+        assert_eq!(config.display.search_panel_pct, 25);
+        assert_eq!(
+            PageMode::parse(&config.display.page_mode),
+            Some(PageMode::Viewport)
+        );
+        assert_eq!(config.display.gpu_select, "2");
+        assert_eq!(
+            TimeFormat::parse(&config.display.time_format),
+            Some(TimeFormat::TwelveHour)
+        );
+        assert_eq!(
+            ByteUnit::parse(&config.display.byte_unit),
+            Some(ByteUnit::Si)
+        );
+        assert_eq!(
+            TempUnit::parse(&config.display.temp_unit),
+            Some(TempUnit::Fahrenheit)
+        );
     }
 
     #[test]
@@ -475,7 +1184,7 @@ mod tests {
 
     #[test]
     fn file_config_logo_mode_options() {
-        for mode in &["ascii", "svg"] {
+        for mode in &["ascii", "svg", "none"] {
             let config: FileConfig = toml::from_str(&format!(
                 r#"
                 [display]
@@ -503,6 +1212,23 @@ mod tests {
         }
     }
 
+    #[test]
+    fn file_config_default_view_options() {
+        for view in &["overview", "systeminfo", "gpu", "container", "processes"] {
+            let config: FileConfig = toml::from_str(&format!(
+                r#"
+                [display]
+                default_view = "{}"
+                "#,
+                view
+            ))
+            .unwrap();
+            assert_eq!(config.display.default_view, *view);
+            assert!(ViewMode::parse(&config.display.default_view).is_some());
+        }
+        assert_eq!(ViewMode::parse("bogus"), None);
+    }
+
     #[test]
     fn file_config_numeric_values() {
         let config: FileConfig = toml::from_str(
@@ -1,10 +1,12 @@
+mod alerts;
 mod config;
 mod highlight;
 mod state;
 mod status;
 mod view_mode;
 
-pub use config::Config;
+pub use alerts::{AlertActions, AlertMetric, AlertState, AlertThresholds, LowMemThreshold};
+pub use config::{Config, version};
 pub use highlight::HighlightMode;
 pub use state::App;
 pub use state::logo::{
@@ -12,8 +14,10 @@ pub use state::logo::{
     RenderedLogo, RgbColor, RgbaColor, SvgLogo,
 };
 pub use state::{
-    GpuProcessHeaderRegion, GpuProcessSortKey, HeaderRegion, Language, ProcessFilterType,
-    SetupField, SystemOverviewSnapshot, SystemTab, SystemTabRegion,
+    ContainerSort, DetailField, DiskLabelMode, GpuProcessHeaderRegion, GpuProcessSortKey,
+    HeaderRegion, Language, PageMode, ProcessDetail, ProcessFilterType, ProcessGrouping,
+    RefreshStats, SetupField, SetupToggle, SystemOverviewSnapshot, SystemTab, SystemTabRegion,
+    TempsSort, UpdateStrategy,
 };
 pub use status::{StatusLevel, StatusMessage};
 pub use view_mode::{GpuFocusPanel, ViewMode};
@@ -1,6 +1,8 @@
-use sysinfo::{Pid, ProcessesToUpdate, Signal};
+use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate, Signal, UpdateKind};
 
-use super::{App, ConfirmKill, StatusLevel};
+use super::{App, ConfirmKill, DetailField, ProcessDetail, StatusLevel};
+use crate::data::{cgroup_path_for_pid, cpu_list_len, display_name, read_cpu_affinity};
+use crate::utils::{exec_disabled, spawn_detached};
 
 impl App {
     pub fn open_confirm(&mut self) {
@@ -9,6 +11,127 @@ impl App {
         }
     }
 
+    /// Open the detail overlay (command line, paths, environment) for the selected process.
+    pub fn open_process_detail(&mut self) {
+        let Some(pid) = self.selected_row().map(|row| row.pid) else {
+            return;
+        };
+        // Command line, paths and environment are not part of the periodic refresh
+        let detail_refresh = ProcessRefreshKind::nothing()
+            .with_cmd(UpdateKind::Always)
+            .with_exe(UpdateKind::Always)
+            .with_cwd(UpdateKind::Always)
+            .with_environ(UpdateKind::Always);
+        self.system.refresh_processes_specifics(
+            ProcessesToUpdate::Some(&[Pid::from_u32(pid)]),
+            false,
+            detail_refresh,
+        );
+        let Some(process) = self.system.process(Pid::from_u32(pid)) else {
+            self.set_status(StatusLevel::Warn, format!("Process PID {pid} not found"));
+            return;
+        };
+
+        let user = process
+            .user_id()
+            .and_then(|id| self.users.get_user_by_id(id))
+            .map(|user| user.name().to_string())
+            .unwrap_or_else(|| "-".to_string());
+        let path = |path: Option<&std::path::Path>| {
+            path.map(|path| path.display().to_string())
+                .unwrap_or_else(|| "-".to_string())
+        };
+        let command = process
+            .cmd()
+            .iter()
+            .map(|arg| arg.to_string_lossy())
+            .collect::<Vec<_>>()
+            .join(" ");
+        let mut lines = vec![
+            (DetailField::Pid, pid.to_string()),
+            (DetailField::Name, display_name(process.name()).0),
+            (DetailField::User, user),
+            (DetailField::Exe, path(process.exe())),
+            (DetailField::Cwd, path(process.cwd())),
+            (
+                DetailField::Cgroup,
+                cgroup_path_for_pid(pid).unwrap_or_else(|| "-".to_string()),
+            ),
+            (DetailField::Affinity, self.affinity_label(pid)),
+            (DetailField::Command, command),
+        ];
+        let mut environ = process
+            .environ()
+            .iter()
+            .map(|entry| entry.to_string_lossy().into_owned())
+            .collect::<Vec<_>>();
+        environ.sort();
+        lines.extend(environ.into_iter().map(|entry| (DetailField::Env, entry)));
+
+        self.detail = Some(ProcessDetail {
+            pid,
+            lines,
+            cursor: 0,
+        });
+    }
+
+    /// Open the selected process's working directory with `xdg-open`.
+    pub fn open_selected_cwd(&mut self) {
+        if !self.open_cwd {
+            self.set_status(
+                StatusLevel::Warn,
+                "Opening directories is disabled (set [general] open_cwd = true)".to_string(),
+            );
+            return;
+        }
+        if exec_disabled() {
+            self.set_status(
+                StatusLevel::Warn,
+                "External commands are disabled (--no-exec)".to_string(),
+            );
+            return;
+        }
+        let Some(pid) = self.selected_row().map(|row| row.pid) else {
+            return;
+        };
+        self.system.refresh_processes_specifics(
+            ProcessesToUpdate::Some(&[Pid::from_u32(pid)]),
+            false,
+            ProcessRefreshKind::nothing().with_cwd(UpdateKind::Always),
+        );
+        let Some(cwd) = self
+            .system
+            .process(Pid::from_u32(pid))
+            .and_then(|process| process.cwd())
+            .map(|cwd| cwd.to_path_buf())
+        else {
+            self.set_status(
+                StatusLevel::Warn,
+                format!("Working directory of PID {pid} is not readable"),
+            );
+            return;
+        };
+        // The child's stdio is detached, so the terminal can stay in raw mode
+        match spawn_detached("xdg-open", &[cwd.as_os_str()]) {
+            Ok(()) => self.set_status(StatusLevel::Info, format!("Opened {}", cwd.display())),
+            Err(err) => self.set_status(
+                StatusLevel::Warn,
+                format!("xdg-open {} failed: {err}", cwd.display()),
+            ),
+        }
+    }
+
+    /// "0-3,8", or "all (0-15)" when the process is not pinned.
+    fn affinity_label(&self, pid: u32) -> String {
+        match read_cpu_affinity(pid) {
+            Some(list) if cpu_list_len(&list) == Some(self.system.cpus().len()) => {
+                format!("all ({list})")
+            }
+            Some(list) => list,
+            None => "n/a".to_string(),
+        }
+    }
+
     pub fn open_confirm_for_pid(&mut self, pid: u32) {
         if let Some(row) = self.rows.iter().find(|row| row.pid == pid) {
             self.confirm = Some(ConfirmKill {
@@ -18,6 +141,8 @@ impl App {
                 mem_bytes: row.mem_bytes,
                 status: row.status.clone(),
                 start_time: row.start_time,
+                members: self.group_members.get(&pid).cloned().unwrap_or_default(),
+                marked: false,
             });
             return;
         }
@@ -33,11 +158,63 @@ impl App {
 
         self.confirm = Some(ConfirmKill {
             pid: pid.as_u32(),
-            name: process.name().to_string_lossy().into_owned(),
+            name: display_name(process.name()).0,
             cpu: process.cpu_usage(),
             mem_bytes: process.memory(),
             status: format!("{:?}", process.status()),
             start_time: process.start_time(),
+            members: Vec::new(),
+            marked: false,
+        });
+    }
+
+    /// Mark or unmark the selected process for a batch kill and move to the next row.
+    pub fn toggle_mark(&mut self) {
+        let Some((pid, start_time)) = self.selected_row().map(|row| (row.pid, row.start_time))
+        else {
+            return;
+        };
+        if self.marked.remove(&pid).is_none() {
+            self.marked.insert(pid, start_time);
+        }
+        self.move_selection(1);
+    }
+
+    pub fn clear_marks(&mut self) {
+        if !self.marked.is_empty() {
+            self.marked.clear();
+            self.set_status(StatusLevel::Info, "Marks cleared".to_string());
+        }
+    }
+
+    /// Ask for confirmation before terminating every marked process.
+    pub fn open_confirm_marked(&mut self) {
+        if self.marked.is_empty() {
+            self.set_status(StatusLevel::Warn, "No processes marked".to_string());
+            return;
+        }
+        let mut members = self
+            .marked
+            .iter()
+            .map(|(pid, start_time)| (*pid, *start_time))
+            .collect::<Vec<_>>();
+        members.sort_unstable();
+        let marked_rows = self
+            .rows
+            .iter()
+            .filter(|row| self.marked.contains_key(&row.pid));
+        let (cpu, mem_bytes) = marked_rows.fold((0.0, 0), |(cpu, mem), row| {
+            (cpu + row.cpu, mem + row.mem_bytes)
+        });
+        self.confirm = Some(ConfirmKill {
+            pid: members[0].0,
+            name: "marked".to_string(),
+            cpu,
+            mem_bytes,
+            status: "-".to_string(),
+            start_time: members[0].1,
+            members,
+            marked: true,
         });
     }
 
@@ -46,6 +223,12 @@ impl App {
     }
 
     pub fn confirm_kill(&mut self) {
+        if let Some(confirm) = self.confirm.as_ref()
+            && !confirm.members.is_empty()
+        {
+            self.confirm_kill_group();
+            return;
+        }
         if let Some(confirm) = self.confirm.take() {
             let pid = Pid::from_u32(confirm.pid);
             self.system
@@ -81,4 +264,45 @@ impl App {
             self.refresh();
         }
     }
+
+    fn confirm_kill_group(&mut self) {
+        let Some(confirm) = self.confirm.take() else {
+            return;
+        };
+        let pids = confirm
+            .members
+            .iter()
+            .map(|(pid, _)| Pid::from_u32(*pid))
+            .collect::<Vec<_>>();
+        self.system
+            .refresh_processes(ProcessesToUpdate::Some(&pids), false);
+        let mut sent = 0;
+        for (pid, start_time) in &confirm.members {
+            // Skip members that exited or whose PID was reused
+            let Some(process) = self.system.process(Pid::from_u32(*pid)) else {
+                continue;
+            };
+            if process.start_time() == *start_time && process.kill_with(Signal::Term) == Some(true)
+            {
+                sent += 1;
+            }
+        }
+        if confirm.marked {
+            self.marked.clear();
+        }
+        let level = if sent == confirm.members.len() {
+            StatusLevel::Info
+        } else {
+            StatusLevel::Warn
+        };
+        self.set_status(
+            level,
+            format!(
+                "Sent SIGTERM to {sent} of {} {} processes",
+                confirm.members.len(),
+                confirm.name
+            ),
+        );
+        self.refresh();
+    }
 }
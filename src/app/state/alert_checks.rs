@@ -0,0 +1,140 @@
+use std::thread;
+use std::time::{Duration, Instant};
+
+use super::{App, StatusLevel};
+use crate::app::AlertMetric;
+use crate::data::display_name;
+use crate::data::sensors::is_cpu_sensor;
+use crate::utils::{format_bytes_with, percent, run_command_with_timeout};
+
+const ALERT_COMMAND_TIMEOUT: Duration = Duration::from_secs(10);
+/// Alert source for the CPU temperature, shared by every CPU sensor
+const CPU_TEMP_SOURCE: &str = "cpu";
+
+impl App {
+    /// Checks CPU temperatures and memory usage after a refresh.
+    pub(super) fn observe_system_alerts(&mut self, now: Instant) {
+        // One alert for the hottest sensor, not one per core
+        let hottest = self
+            .components
+            .iter()
+            .filter(|component| is_cpu_sensor(component.label()))
+            .filter_map(|component| component.temperature())
+            .reduce(f32::max);
+        if let Some(temp) = hottest {
+            self.check_alert(AlertMetric::CpuTemp, CPU_TEMP_SOURCE, temp, now);
+        }
+        let mem_pct = percent(self.system.used_memory(), self.system.total_memory());
+        self.check_alert(AlertMetric::MemPct, "memory", mem_pct, now);
+        let available = self.system.available_memory();
+        if self
+            .alerts
+            .observe_low_mem(available, self.system.total_memory(), now)
+        {
+            self.warn_low_memory(available);
+        }
+    }
+
+    /// Names the biggest memory consumer while available memory is low.
+    fn warn_low_memory(&mut self, available: u64) {
+        let top = self
+            .system
+            .processes()
+            .iter()
+            .max_by_key(|(_, process)| process.memory())
+            .map(|(pid, process)| {
+                let (name, _) = display_name(process.name());
+                (format!("{name} ({pid})"), process.memory())
+            });
+        let available_text = format_bytes_with(available, self.byte_unit);
+        let message = match top.as_ref() {
+            Some((name, mem)) => format!(
+                "Low memory: {available_text} available; top consumer {name} uses {}",
+                format_bytes_with(*mem, self.byte_unit)
+            ),
+            None => format!("Low memory: {available_text} available"),
+        };
+        self.set_status(StatusLevel::Warn, message);
+        let source = top.map(|(name, _)| name).unwrap_or_default();
+        self.run_alert_actions("low_mem", &source, available.to_string());
+    }
+
+    /// Checks GPU temperatures and utilization after a GPU snapshot.
+    pub(super) fn observe_gpu_alerts(&mut self) {
+        let now = Instant::now();
+        let readings = self
+            .gpu_list
+            .iter()
+            .map(|gpu| {
+                (
+                    gpu.id.clone(),
+                    gpu.telemetry.temperature_c,
+                    gpu.telemetry.utilization_gpu_pct,
+                )
+            })
+            .collect::<Vec<_>>();
+        for (id, temp, util) in readings {
+            if let Some(temp) = temp {
+                self.check_alert(AlertMetric::GpuTemp, &id, temp, now);
+            }
+            if let Some(util) = util {
+                self.check_alert(AlertMetric::GpuUtil, &id, util, now);
+            }
+        }
+    }
+
+    /// Whether `source` should flash for `metric`; never with `reduce_motion`.
+    pub fn alert_flashing(&self, metric: AlertMetric, source: &str, now: Instant) -> bool {
+        !self.reduce_motion && self.alerts.is_flashing(metric, source, now)
+    }
+
+    /// Whether a Temps row should flash: a CPU sensor at or above the limit
+    /// while the (single) CPU temperature alert is flashing.
+    pub fn cpu_temp_flashing(&self, label: &str, temp: Option<f32>, now: Instant) -> bool {
+        let over = match (temp, self.alerts.threshold(AlertMetric::CpuTemp)) {
+            (Some(temp), Some(limit)) => temp >= limit,
+            _ => false,
+        };
+        over && is_cpu_sensor(label)
+            && self.alert_flashing(AlertMetric::CpuTemp, CPU_TEMP_SOURCE, now)
+    }
+
+    fn check_alert(&mut self, metric: AlertMetric, source: &str, value: f32, now: Instant) {
+        if !self.alerts.observe(metric, source, value, now) {
+            return;
+        }
+        let threshold = self.alerts.threshold(metric).unwrap_or_default();
+        let unit = metric.unit();
+        self.set_status(
+            StatusLevel::Warn,
+            format!(
+                "Alert: {} {source} at {value:.0}{unit} (limit {threshold:.0}{unit})",
+                metric.code()
+            ),
+        );
+        self.run_alert_actions(metric.code(), source, format!("{value:.1}"));
+    }
+
+    fn run_alert_actions(&self, code: &str, source: &str, value: String) {
+        if self.alert_actions.bell {
+            ring_bell();
+        }
+        if let Some(command) = self.alert_actions.command.clone() {
+            // The command gets the metric, source and value as $1..$3
+            let args = [code.to_string(), source.to_string(), value];
+            thread::spawn(move || {
+                let mut argv = vec!["-c", command.as_str(), "rtop"];
+                argv.extend(args.iter().map(String::as_str));
+                run_command_with_timeout("sh", &argv, ALERT_COMMAND_TIMEOUT);
+            });
+        }
+    }
+}
+
+fn ring_bell() {
+    use std::io::Write;
+
+    let mut stdout = std::io::stdout();
+    let _ = stdout.write_all(b"\x07");
+    let _ = stdout.flush();
+}
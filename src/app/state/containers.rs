@@ -1,21 +1,56 @@
 use std::collections::{HashMap, HashSet};
 use std::time::{Duration, Instant};
 
-use super::{App, NetSampleEntry};
+use sysinfo::System;
+
+use super::{App, ContainerSort, NetSampleEntry};
 use crate::data::{
-    ContainerKey, ContainerRow, container_key_for_pid, net_sample_for_pid, netns_id_for_pid,
+    ContainerKey, ContainerRow, ContainerState, ContainerTotals, cgroup_cpu_limit, cgroup_memory,
+    container_cgroup_dir, container_key_for_pid, container_name, net_sample_for_pid,
+    netns_id_for_pid, start_runtime_monitor,
 };
 
+/// A process attributed to a container by its cgroup
+pub(super) struct ContainerProcess {
+    pub pid: u32,
+    pub key: ContainerKey,
+    pub cpu: f32,
+    pub mem_bytes: u64,
+    pub run_time: u64,
+}
+
+/// Processes of `system` that run inside a container; the default
+/// `App::container_process_source`.
+pub(super) fn container_processes(system: &System) -> Vec<ContainerProcess> {
+    system
+        .processes()
+        .iter()
+        .filter_map(|(pid, process)| {
+            let pid = pid.as_u32();
+            Some(ContainerProcess {
+                pid,
+                key: container_key_for_pid(pid)?,
+                cpu: process.cpu_usage(),
+                mem_bytes: process.memory(),
+                run_time: process.run_time(),
+            })
+        })
+        .collect()
+}
+
 impl App {
     pub fn update_containers(&mut self) {
         const NET_SAMPLE_INTERVAL: Duration = Duration::from_millis(1000);
+        const RUNTIME_QUERY_INTERVAL: Duration = Duration::from_secs(5);
 
         #[derive(Default)]
         struct ContainerUsage {
             cpu: f32,
             mem_bytes: u64,
             proc_count: usize,
+            uptime_secs: u64,
             netns_id: Option<u64>,
+            cgroup_dir: Option<String>,
         }
 
         let now = Instant::now();
@@ -29,27 +64,29 @@ impl App {
         let mut netns_pids: HashMap<u64, u32> = HashMap::new();
         let mut netns_container_counts: HashMap<u64, usize> = HashMap::new();
         let mut active_keys: HashSet<ContainerKey> = HashSet::new();
-        for (pid, process) in self.system.processes() {
-            let pid = pid.as_u32();
-            if let Some(key) = container_key_for_pid(pid) {
-                active_keys.insert(key.clone());
-                pid_map.insert(pid, key.clone());
-                let entry = map.entry(key.clone()).or_default();
-                entry.cpu += process.cpu_usage();
-                entry.mem_bytes = entry.mem_bytes.saturating_add(process.memory());
-                entry.proc_count += 1;
-                if entry.netns_id.is_none() {
-                    if let Some(netns_id) = self.container_netns_cache.get(&key).copied() {
-                        entry.netns_id = Some(netns_id);
-                    } else if needs_net_sample && let Some(netns_id) = netns_id_for_pid(pid) {
-                        entry.netns_id = Some(netns_id);
-                        self.container_netns_cache.insert(key.clone(), netns_id);
-                    }
-                    if let Some(netns_id) = entry.netns_id {
-                        *netns_container_counts.entry(netns_id).or_insert(0) += 1;
-                        if needs_net_sample {
-                            netns_pids.entry(netns_id).or_insert(pid);
-                        }
+        for process in (self.container_process_source)(&self.system) {
+            let ContainerProcess { pid, key, .. } = process;
+            active_keys.insert(key.clone());
+            pid_map.insert(pid, key.clone());
+            let entry = map.entry(key.clone()).or_default();
+            if entry.proc_count == 0 {
+                entry.cgroup_dir = container_cgroup_dir(pid, &key);
+            }
+            entry.cpu += process.cpu;
+            entry.mem_bytes = entry.mem_bytes.saturating_add(process.mem_bytes);
+            entry.proc_count += 1;
+            entry.uptime_secs = entry.uptime_secs.max(process.run_time);
+            if entry.netns_id.is_none() {
+                if let Some(netns_id) = self.container_netns_cache.get(&key).copied() {
+                    entry.netns_id = Some(netns_id);
+                } else if needs_net_sample && let Some(netns_id) = netns_id_for_pid(pid) {
+                    entry.netns_id = Some(netns_id);
+                    self.container_netns_cache.insert(key.clone(), netns_id);
+                }
+                if let Some(netns_id) = entry.netns_id {
+                    *netns_container_counts.entry(netns_id).or_insert(0) += 1;
+                    if needs_net_sample {
+                        netns_pids.entry(netns_id).or_insert(pid);
                     }
                 }
             }
@@ -58,6 +95,31 @@ impl App {
         self.container_netns_cache
             .retain(|key, _| active_keys.contains(key));
 
+        // Paused/exited containers are only known to the runtime API
+        let runtime_rx = self
+            .container_runtime_rx
+            .get_or_insert_with(|| start_runtime_monitor(RUNTIME_QUERY_INTERVAL));
+        if let Some(list) = runtime_rx.try_iter().last() {
+            self.container_runtime_list = list;
+            // Names the state files did not know may be in the new listing
+            self.container_name_cache.retain(|_, name| name.is_some());
+        }
+        let runtime_list = &self.container_runtime_list;
+
+        self.container_name_cache.retain(|key, _| {
+            active_keys.contains(key) || runtime_list.iter().any(|entry| &entry.key == key)
+        });
+        for key in &active_keys {
+            if !self.container_name_cache.contains_key(key) {
+                let name = runtime_list
+                    .iter()
+                    .find(|entry| &entry.key == key)
+                    .and_then(|entry| entry.name.clone())
+                    .or_else(|| container_name(key));
+                self.container_name_cache.insert(key.clone(), name);
+            }
+        }
+
         if needs_net_sample {
             let mut net_rates: HashMap<u64, u64> = HashMap::new();
             let mut next_net_prev: HashMap<u64, NetSampleEntry> = HashMap::new();
@@ -88,6 +150,15 @@ impl App {
         }
 
         let net_rates = &self.container_net_rates;
+        let names = &self.container_name_cache;
+        let runtime_list = &self.container_runtime_list;
+        let runtime_state = |key: &ContainerKey| {
+            runtime_list
+                .iter()
+                .find(|entry| &entry.key == key)
+                .map(|entry| entry.state)
+                .unwrap_or_default()
+        };
 
         let mut rows = map
             .into_iter()
@@ -100,23 +171,60 @@ impl App {
                         net_rates.get(&netns_id).copied()
                     }
                 });
-                ContainerRow::new(
+                // Prefer cgroup accounting (includes page cache) over the process sum
+                let (mem_bytes, mem_limit) = usage
+                    .cgroup_dir
+                    .as_deref()
+                    .and_then(cgroup_memory)
+                    .unwrap_or((usage.mem_bytes, None));
+                let cpu_limit = usage.cgroup_dir.as_deref().and_then(cgroup_cpu_limit);
+                let name = names.get(&key).cloned().flatten();
+                let mut row = ContainerRow::new(
                     key,
                     usage.cpu,
-                    usage.mem_bytes,
+                    cpu_limit,
+                    mem_bytes,
+                    mem_limit,
                     usage.proc_count,
                     net_bytes_per_sec,
-                )
+                );
+                row.label = row.key.named_label(name.as_deref());
+                row.uptime_secs = usage.uptime_secs;
+                row.state = runtime_state(&row.key);
+                row
             })
             .collect::<Vec<_>>();
-        rows.sort_by(|a, b| {
+        // Stopped containers have no processes left to find in /proc
+        rows.extend(
+            runtime_list
+                .iter()
+                .filter(|entry| {
+                    entry.state != ContainerState::Running && !active_keys.contains(&entry.key)
+                })
+                .map(|entry| {
+                    let mut row = ContainerRow::new(entry.key.clone(), 0.0, None, 0, None, 0, None);
+                    row.label = row.key.named_label(entry.name.as_deref());
+                    row.state = entry.state;
+                    row
+                }),
+        );
+        let by_cpu = |a: &ContainerRow, b: &ContainerRow| {
             b.cpu
                 .partial_cmp(&a.cpu)
                 .unwrap_or(std::cmp::Ordering::Equal)
                 .then_with(|| b.mem_bytes.cmp(&a.mem_bytes))
                 .then_with(|| a.label.cmp(&b.label))
-        });
+        };
+        match self.container_sort {
+            ContainerSort::Cpu => rows.sort_by(by_cpu),
+            ContainerSort::Uptime => {
+                rows.sort_by(|a, b| a.uptime_secs.cmp(&b.uptime_secs).then_with(|| by_cpu(a, b)))
+            }
+        }
+        // Paused and exited containers go to the bottom
+        rows.sort_by_key(|row| row.state != ContainerState::Running);
 
+        self.container_totals = ContainerTotals::from_rows(&rows);
         self.container_rows = rows;
         self.container_pid_map = pid_map;
         self.sync_container_selection();
@@ -1,5 +1,14 @@
-use super::App;
-use crate::data::gpu::{GpuInfo, default_gpu_index};
+use std::collections::HashMap;
+use std::time::Duration;
+
+use super::{App, StatusLevel};
+use crate::data::gpu::{GpuInfo, GpuPreference, GpuProcessUsage, default_gpu_index};
+
+pub(super) const GPU_POLL_STEP_MS: u64 = 250;
+const MIN_GPU_POLL_MS: u64 = 250;
+const MAX_GPU_POLL_MS: u64 = 10_000;
+/// Utilization samples kept per GPU for the history graph
+const GPU_HISTORY_LEN: usize = 300;
 
 impl App {
     pub fn poll_gpu_updates(&mut self) {
@@ -12,30 +21,77 @@ impl App {
         }
         if let Some(snapshot) = latest {
             self.update_gpu_list(snapshot.gpus);
-            self.gpu_processes = snapshot.processes;
+            self.set_gpu_processes(snapshot.processes);
+            if self.gpu_probe_pending {
+                self.gpu_probe_pending = false;
+                // The overview cached its GPU lines while probing
+                self.system_overview_snapshot = None;
+            }
+        }
+    }
+
+    /// True until the GPU monitor delivers its first snapshot; startup never waits for it.
+    pub fn gpu_probing(&self) -> bool {
+        self.gpu_probe_pending
+    }
+
+    pub(super) fn set_gpu_processes(&mut self, mut processes: Vec<GpuProcessUsage>) {
+        // Drop entries for GPUs that vanished from the snapshot (hot-unplug)
+        processes.retain(|entry| self.gpu_list.iter().any(|gpu| gpu.id == entry.gpu_id));
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        for entry in &processes {
+            *counts.entry(entry.gpu_id.clone()).or_insert(0) += 1;
         }
+        self.gpu_process_counts = counts;
+        self.gpu_processes_generation = self.gpu_processes_generation.wrapping_add(1);
+        self.gpu_processes = processes;
+    }
+
+    pub fn gpu_process_count(&self, gpu_id: &str) -> usize {
+        self.gpu_process_counts.get(gpu_id).copied().unwrap_or(0)
     }
 
     pub(super) fn update_gpu_list(&mut self, mut gpus: Vec<GpuInfo>) {
         gpus.sort_by_key(|gpu| gpu.kind.sort_rank());
         self.gpu_list = gpus;
+        self.record_gpu_history();
+        self.observe_gpu_alerts();
         self.sync_gpu_selection();
     }
 
-    fn sync_gpu_selection(&mut self) {
-        if self.gpu_list.is_empty() {
-            self.gpu_selected = None;
-            return;
+    fn record_gpu_history(&mut self) {
+        let gpu_list = &self.gpu_list;
+        self.gpu_util_history
+            .retain(|id, _| gpu_list.iter().any(|gpu| &gpu.id == id));
+        for gpu in gpu_list {
+            let history = self.gpu_util_history.entry(gpu.id.clone()).or_default();
+            if history.len() == GPU_HISTORY_LEN {
+                history.pop_front();
+            }
+            let util = gpu.telemetry.utilization_gpu_pct.unwrap_or(0.0);
+            history.push_back(util.clamp(0.0, 100.0).round() as u64);
         }
+    }
+
+    fn sync_gpu_selection(&mut self) {
+        self.gpu_selected = resolve_gpu_selection(
+            &self.gpu_list,
+            self.gpu_selected.take(),
+            self.gpu_pin.as_deref(),
+            self.gpu_pref,
+        );
+    }
 
-        if let Some(selected) = self.gpu_selected.as_ref()
-            && self.gpu_list.iter().any(|gpu| &gpu.id == selected)
-        {
+    /// Changes the GPU poll interval and forwards it to the running monitor thread.
+    pub fn adjust_gpu_poll_rate(&mut self, delta_ms: i64) {
+        let current = self.gpu_poll_rate.as_millis() as i64;
+        let next = (current + delta_ms).clamp(MIN_GPU_POLL_MS as i64, MAX_GPU_POLL_MS as i64);
+        if next == current {
             return;
         }
-
-        if let Some(idx) = default_gpu_index(&self.gpu_list, self.gpu_pref) {
-            self.gpu_selected = Some(self.gpu_list[idx].id.clone());
+        self.gpu_poll_rate = Duration::from_millis(next as u64);
+        if let Some(tx) = self.gpu_ctrl_tx.as_ref() {
+            let _ = tx.send(self.gpu_poll_rate);
         }
     }
 
@@ -45,7 +101,7 @@ impl App {
         }
         let current = self.selected_gpu_index().unwrap_or(0);
         let next = (current + 1) % self.gpu_list.len();
-        self.gpu_selected = Some(self.gpu_list[next].id.clone());
+        self.set_selected_gpu(next);
     }
 
     pub fn select_prev_gpu(&mut self) {
@@ -58,7 +114,27 @@ impl App {
         } else {
             current - 1
         };
-        self.gpu_selected = Some(self.gpu_list[next].id.clone());
+        self.set_selected_gpu(next);
+    }
+
+    /// Selects the GPU at `idx` for this session; the pin is left alone.
+    fn set_selected_gpu(&mut self, idx: usize) {
+        self.gpu_selected = Some(self.gpu_list[idx].id.clone());
+    }
+
+    /// Saves the selected GPU as `[display] gpu_select` so it is picked at startup.
+    pub fn pin_selected_gpu(&mut self) {
+        let Some(id) = self.gpu_selected.clone() else {
+            return;
+        };
+        match super::super::config::save_gpu_select(&id) {
+            Ok(()) => self.set_status(StatusLevel::Info, format!("Pinned GPU {id}")),
+            Err(err) => self.set_status(
+                StatusLevel::Warn,
+                format!("Failed to save GPU selection: {err}"),
+            ),
+        }
+        self.gpu_pin = Some(id);
     }
 
     pub fn selected_gpu(&self) -> Option<(usize, &GpuInfo)> {
@@ -71,3 +147,125 @@ impl App {
         self.gpu_list.iter().position(|gpu| &gpu.id == selected)
     }
 }
+
+/// Keeps the current selection if that GPU is still present, otherwise falls
+/// back to the pinned GPU and then the preferred default (e.g. after the
+/// selected GPU was unplugged).
+fn resolve_gpu_selection(
+    gpus: &[GpuInfo],
+    selected: Option<String>,
+    pin: Option<&str>,
+    pref: GpuPreference,
+) -> Option<String> {
+    if gpus.is_empty() {
+        return None;
+    }
+
+    if let Some(selected) = selected
+        && gpus.iter().any(|gpu| gpu.id == selected)
+    {
+        return Some(selected);
+    }
+
+    pin.and_then(|pin| pinned_gpu_index(gpus, pin))
+        .or_else(|| default_gpu_index(gpus, pref))
+        .map(|idx| gpus[idx].id.clone())
+}
+
+/// Matches `pin` against GPU ids first, then as a 1-based index in list order.
+fn pinned_gpu_index(gpus: &[GpuInfo], pin: &str) -> Option<usize> {
+    gpus.iter().position(|gpu| gpu.id == pin).or_else(|| {
+        pin.parse::<usize>()
+            .ok()
+            .and_then(|number| number.checked_sub(1))
+            .filter(|idx| *idx < gpus.len())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::gpu::{GpuKind, GpuTelemetry};
+
+    fn gpu(id: &str, kind: GpuKind) -> GpuInfo {
+        GpuInfo {
+            id: id.to_string(),
+            name: id.to_string(),
+            vendor: None,
+            device: None,
+            driver: None,
+            driver_version: None,
+            kind,
+            memory: None,
+            telemetry: GpuTelemetry::default(),
+        }
+    }
+
+    #[test]
+    fn selection_kept_while_gpu_present() {
+        let gpus = vec![
+            gpu("nvidia:0", GpuKind::Discrete),
+            gpu("pci:00:02.0", GpuKind::Integrated),
+        ];
+        let selected = resolve_gpu_selection(
+            &gpus,
+            Some("pci:00:02.0".to_string()),
+            None,
+            GpuPreference::Auto,
+        );
+        assert_eq!(selected.as_deref(), Some("pci:00:02.0"));
+    }
+
+    #[test]
+    fn switching_gpus_keeps_the_pin() {
+        let mut app = App::new(crate::app::Config {
+            vram_enabled: false,
+            ..crate::app::Config::default()
+        });
+        app.gpu_list = vec![
+            gpu("nvidia:0", GpuKind::Discrete),
+            gpu("nvidia:1", GpuKind::Discrete),
+        ];
+        app.gpu_pin = Some("nvidia:0".to_string());
+        app.gpu_selected = Some("nvidia:0".to_string());
+        app.select_next_gpu();
+        assert_eq!(app.gpu_selected.as_deref(), Some("nvidia:1"));
+        assert_eq!(app.gpu_pin.as_deref(), Some("nvidia:0"));
+        app.select_prev_gpu();
+        assert_eq!(app.gpu_selected.as_deref(), Some("nvidia:0"));
+    }
+
+    #[test]
+    fn selection_falls_back_when_selected_gpu_removed() {
+        // Snapshot after "nvidia:0" was unplugged
+        let gpus = vec![gpu("pci:00:02.0", GpuKind::Integrated)];
+        let selected = resolve_gpu_selection(
+            &gpus,
+            Some("nvidia:0".to_string()),
+            None,
+            GpuPreference::Auto,
+        );
+        assert_eq!(selected.as_deref(), Some("pci:00:02.0"));
+    }
+
+    #[test]
+    fn selection_cleared_when_no_gpus_left() {
+        let selected =
+            resolve_gpu_selection(&[], Some("nvidia:0".to_string()), None, GpuPreference::Auto);
+        assert_eq!(selected, None);
+    }
+
+    #[test]
+    fn pinned_gpu_used_when_nothing_selected() {
+        let gpus = vec![
+            gpu("nvidia:0", GpuKind::Discrete),
+            gpu("nvidia:1", GpuKind::Discrete),
+        ];
+        let by_id = resolve_gpu_selection(&gpus, None, Some("nvidia:1"), GpuPreference::Auto);
+        assert_eq!(by_id.as_deref(), Some("nvidia:1"));
+        let by_index = resolve_gpu_selection(&gpus, None, Some("2"), GpuPreference::Auto);
+        assert_eq!(by_index.as_deref(), Some("nvidia:1"));
+        let missing = resolve_gpu_selection(&gpus, None, Some("amd:0"), GpuPreference::Auto);
+        assert_eq!(missing.as_deref(), Some("nvidia:0"));
+    }
+}
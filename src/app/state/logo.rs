@@ -1,3 +1,6 @@
+use std::path::PathBuf;
+use std::time::{Instant, SystemTime};
+
 use resvg::usvg;
 
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
@@ -36,6 +39,7 @@ pub enum LogoMode {
     #[default]
     Ascii,
     Svg,
+    None,
 }
 
 impl LogoMode {
@@ -43,6 +47,7 @@ impl LogoMode {
         match value.trim().to_ascii_lowercase().as_str() {
             "ascii" => Some(LogoMode::Ascii),
             "svg" => Some(LogoMode::Svg),
+            "none" | "off" => Some(LogoMode::None),
             _ => None,
         }
     }
@@ -51,13 +56,15 @@ impl LogoMode {
         match self {
             LogoMode::Ascii => "ascii",
             LogoMode::Svg => "svg",
+            LogoMode::None => "none",
         }
     }
 
     pub fn toggle(self) -> Self {
         match self {
             LogoMode::Ascii => LogoMode::Svg,
-            LogoMode::Svg => LogoMode::Ascii,
+            LogoMode::Svg => LogoMode::None,
+            LogoMode::None => LogoMode::Ascii,
         }
     }
 }
@@ -137,11 +144,8 @@ pub struct LogoPalette {
 impl Default for LogoPalette {
     fn default() -> Self {
         Self {
-            default: Some(RgbColor {
-                r: 255,
-                g: 255,
-                b: 255,
-            }),
+            // Falls back to the theme accent, see `default_color`
+            default: None,
             colors: vec![
                 RgbColor {
                     r: 78,
@@ -194,12 +198,20 @@ impl Default for LogoPalette {
 }
 
 impl LogoPalette {
-    pub fn color_for_index(&self, index: u8) -> Option<RgbColor> {
+    /// Color for uncolored cells and `$0`: the palette default, else the theme accent.
+    pub fn default_color(&self, accent: RgbColor) -> RgbColor {
+        self.default.unwrap_or(accent)
+    }
+
+    pub fn color_for_index(&self, index: u8, accent: RgbColor) -> RgbColor {
         if index == 0 {
-            return self.default;
+            return self.default_color(accent);
         }
         let idx = index.saturating_sub(1) as usize;
-        self.colors.get(idx).copied().or(self.default)
+        self.colors
+            .get(idx)
+            .copied()
+            .unwrap_or_else(|| self.default_color(accent))
     }
 }
 
@@ -273,6 +285,39 @@ impl RenderedLogo {
 pub struct LogoCache {
     pub ascii: Option<AsciiLogo>,
     pub svg: Option<SvgLogo>,
+    /// Why the SVG logo could not be loaded; kept until its files change
+    pub svg_error: Option<String>,
     pub palette: LogoPalette,
     pub rendered: Option<RenderedLogo>,
+    /// Watched logo files and directories with the mtimes seen at load time
+    pub sources: Vec<(PathBuf, Option<SystemTime>)>,
+    /// Last time `sources` were compared against the filesystem
+    pub checked_at: Option<Instant>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ACCENT: RgbColor = RgbColor { r: 1, g: 2, b: 3 };
+
+    #[test]
+    fn palette_falls_back_to_accent() {
+        let palette = LogoPalette::default();
+        assert_eq!(palette.color_for_index(0, ACCENT), ACCENT);
+        assert_eq!(palette.color_for_index(200, ACCENT), ACCENT);
+        assert_eq!(palette.color_for_index(1, ACCENT), palette.colors[0]);
+
+        let white = RgbColor {
+            r: 255,
+            g: 255,
+            b: 255,
+        };
+        let palette = LogoPalette {
+            default: Some(white),
+            colors: Vec::new(),
+        };
+        assert_eq!(palette.color_for_index(0, ACCENT), white);
+        assert_eq!(palette.default_color(ACCENT), white);
+    }
 }
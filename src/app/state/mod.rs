@@ -1,4 +1,5 @@
 mod actions;
+mod alert_checks;
 mod containers;
 mod gpu;
 pub(crate) mod logo;
@@ -7,28 +8,42 @@ mod selection;
 mod tree;
 mod types;
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::PathBuf;
 use std::sync::mpsc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use ratatui::prelude::Rect;
 use ratatui::widgets::TableState;
 use sysinfo::{
-    Components, Disks, Networks, Pid, ProcessRefreshKind, RefreshKind, System, Uid, UpdateKind,
-    Users,
+    Components, CpuRefreshKind, Disks, LoadAvg, MemoryRefreshKind, Networks, Pid,
+    ProcessRefreshKind, RefreshKind, System, Uid, UpdateKind, Users,
 };
 
+use super::alerts::{AlertActions, AlertState};
 use super::config::Config;
 use super::highlight::HighlightMode;
 use super::status::{StatusLevel, StatusMessage};
 use super::view_mode::{GpuFocusPanel, ViewMode};
+use crate::data::cpu::{PressureStall, TaskCounts};
 use crate::data::gpu::{GpuInfo, GpuPreference, GpuProcessUsage, GpuSnapshot, start_gpu_monitor};
-use crate::data::{ContainerKey, ContainerRow, NetSample, ProcessRow, SortDir, SortKey};
+use crate::data::memory::{MemInfoDetails, read_meminfo};
+use crate::data::mounts::{MountInfo, read_mount_table};
+use crate::data::sensors::{HwmonChip, disk_temperatures, read_hwmon_chips};
+use crate::data::{
+    ContainerKey, ContainerRow, ContainerTotals, NetSample, ProcessRow, ProcessStateCounts,
+    RuntimeContainer, SortDir, SortKey,
+};
+use crate::ui::theme::detect_truecolor;
+use crate::utils::{ByteUnit, TempUnit, TimeFormat, glob_match, percent};
+use gpu::GPU_POLL_STEP_MS;
 use logo::{IconMode, LogoCache, LogoMode, LogoQuality};
 
 pub use types::{
-    ConfirmKill, GpuProcessHeaderRegion, GpuProcessSortKey, HeaderRegion, Language,
-    ProcessFilterType, SetupField, SystemOverviewSnapshot, SystemTab, SystemTabRegion,
+    ConfirmKill, ContainerSort, DetailField, DiskLabelMode, GpuProcessHeaderRegion,
+    GpuProcessSortKey, HeaderRegion, Language, PageMode, PerfStats, ProcessDetail,
+    ProcessFilterType, ProcessGrouping, RefreshStats, SetupField, SetupToggle,
+    SystemOverviewSnapshot, SystemTab, SystemTabRegion, TempsSort, UpdateStrategy,
 };
 
 #[derive(Default, Clone, Copy)]
@@ -68,44 +83,125 @@ impl ProcessGpuUsage {
     }
 }
 
+const QUIT_CONFIRM_WINDOW: Duration = Duration::from_secs(2);
+const DEFAULT_GPU_PROCESS_SORT: GpuProcessSortKey = GpuProcessSortKey::Sm;
+/// Quiet time after the last resize before the logo is re-rasterized
+const RESIZE_DEBOUNCE: Duration = Duration::from_millis(150);
+/// How many ticks a threshold crossing stays highlighted
+const ALERT_FLASH_TICKS: u32 = 2;
+
 pub struct App {
     // Core system data
     pub system: System,
+    /// /proc/meminfo breakdown, re-read with the memory counters
+    pub meminfo: Option<MemInfoDetails>,
+    /// Pressure stall averages, re-read with the CPU and memory counters
+    pub pressure: PressureStall,
+    /// Load averages and scheduler task counts, re-read together on refresh
+    pub load_average: LoadAvg,
+    pub task_counts: Option<TaskCounts>,
     pub disks: Disks,
+    /// /proc/self/mountinfo, re-read whenever `disks` refreshes
+    pub mount_table: HashMap<PathBuf, MountInfo>,
+    /// Drive temperatures by sysinfo disk name, re-read whenever `disks` refreshes
+    pub disk_temps: HashMap<String, f32>,
     pub networks: Networks,
     pub components: Components,
+    /// Fan and power readings from /sys/class/hwmon, re-read with `components`
+    pub hwmon_chips: Vec<HwmonChip>,
     pub network_refresh_secs: Option<f64>,
+    /// Interval covered by the per-process disk I/O deltas (None before the first refresh)
+    process_io_secs: Option<f64>,
+    pub tick_rate: Duration,
+    pub last_refresh: Instant,
+    /// Start of the current tick interval, maintained by the event loop
+    pub last_tick: Instant,
+    pub last_input: Instant,
+    idle_timeout: Option<Duration>,
+    idle_slowdown: u32,
+    pub peak_cpu: f32,
+    pub peak_mem_pct: f32,
     users: Users,
     current_user_id: Option<Uid>,
 
     // Process data
     pub sort_key: SortKey,
     pub sort_dir: SortDir,
+    /// Startup sort from the config/CLI, restored by `reset_sort`
+    default_sort: (SortKey, SortDir),
+    /// Tiebreaker column from `[display] secondary_sort`
+    sort_secondary: Option<SortKey>,
     pub tree_view: bool,
     pub rows: Vec<ProcessRow>,
     pub process_filter: String,
     pub process_filter_type: ProcessFilterType,
+    /// Highlight search matches instead of hiding the other rows
+    pub search_highlight: bool,
     pub selected_pid: Option<u32>,
     pub tree_labels: HashMap<u32, String>,
+    tree_collapsed: HashSet<u32>,
+    tree_branches: HashSet<u32>,
+    /// Child -> parent edges of the current tree layout
+    tree_parents: HashMap<u32, u32>,
+    pub process_grouping: ProcessGrouping,
+    update_strategy: UpdateStrategy,
+    /// Bumped each time a GPU snapshot replaces `gpu_processes`
+    gpu_processes_generation: u64,
+    /// GPU usage map and the `gpu_processes_generation` it was built from
+    gpu_usage_cache: Option<(u64, HashMap<u32, ProcessGpuUsage>)>,
+    /// Members (pid, start_time) of each grouped row, keyed by the row's PID
+    pub group_members: HashMap<u32, Vec<(u32, u64)>>,
+    expanded_groups: HashSet<String>,
+    /// Processes marked for a batch kill: pid -> start_time at marking
+    pub marked: HashMap<u32, u64>,
     gui_process_cache: HashMap<u32, bool>,
+    /// systemd unit per PID; a process keeps its unit, so it is read once
+    unit_cache: HashMap<u32, Option<String>>,
+    /// Recent CPU% samples of selected processes, oldest first
+    pub process_cpu_history: HashMap<u32, VecDeque<f32>>,
+    /// `last_refresh` of the newest CPU history sample; re-sorting adds none
+    cpu_history_sampled_at: Option<Instant>,
+    /// PIDs seen by the previous `update_rows`, used to flag new processes
+    previous_pids: HashSet<u32>,
 
     // GPU data
     pub vram_enabled: bool,
     pub gpu_pref: GpuPreference,
     pub gpu_list: Vec<GpuInfo>,
     pub gpu_selected: Option<String>,
+    /// GPU id or 1-based index from `[display] gpu_select`
+    gpu_pin: Option<String>,
     pub gpu_processes: Vec<GpuProcessUsage>,
+    gpu_process_counts: HashMap<String, usize>,
     pub gpu_process_order: Vec<u32>,
+    /// Recent utilization samples (percent) per GPU id, oldest first
+    pub gpu_util_history: HashMap<String, VecDeque<u64>>,
+    pub gpu_poll_rate: Duration,
     gpu_rx: Option<mpsc::Receiver<GpuSnapshot>>,
+    /// The monitor runs but has not delivered its first snapshot yet
+    gpu_probe_pending: bool,
+    gpu_ctrl_tx: Option<mpsc::Sender<Duration>>,
 
     // Container data
     pub container_rows: Vec<ContainerRow>,
+    pub container_uptime: bool,
+    container_sort: ContainerSort,
+    /// Totals over `container_rows`, refreshed with them
+    pub container_totals: ContainerTotals,
     pub container_selected: Option<ContainerKey>,
     pub container_pid_map: HashMap<u32, ContainerKey>,
     pub container_filter: Option<ContainerKey>,
     container_net_prev: HashMap<u64, NetSampleEntry>,
     container_net_rates: HashMap<u64, u64>,
     container_netns_cache: HashMap<ContainerKey, u64>,
+    /// Resolved Docker/Podman names; `None` once lookup failed
+    container_name_cache: HashMap<ContainerKey, Option<String>>,
+    /// Containers listed by the runtime API (includes paused/exited ones)
+    container_runtime_list: Vec<RuntimeContainer>,
+    /// Runtime listings from the background thread, started on first use
+    container_runtime_rx: Option<mpsc::Receiver<Vec<RuntimeContainer>>>,
+    /// Finds container processes; swapped out in tests
+    container_process_source: fn(&System) -> Vec<containers::ContainerProcess>,
     container_net_last_sample: Option<Instant>,
     network_last_refresh: Option<Instant>,
 
@@ -118,6 +214,10 @@ pub struct App {
     pub logo_quality: LogoQuality,
     pub logo_cache: Option<LogoCache>,
     pub language: Language,
+    pub truecolor: bool,
+    /// Timing overlay from `--debug` / F3
+    pub debug_overlay: bool,
+    pub perf: PerfStats,
 
     // View state
     pub view_mode: ViewMode,
@@ -127,17 +227,87 @@ pub struct App {
     pub processes_expanded: bool,
     pub process_filter_active: bool,
     pub highlight_mode: HighlightMode,
+    pub show_start_time: bool,
+    /// Number of leading process table columns scrolled out of view
+    pub process_hscroll: usize,
+    /// NAME keeps at least this width; optional columns are dropped first
+    pub name_min_width: u16,
+    /// Show the script/jar name instead of the interpreter name
+    pub script_names: bool,
+    /// Read and show the PRI/NI columns
+    pub show_priority: bool,
+    /// Read and show the systemd UNIT column
+    pub show_unit: bool,
+    /// Read and show the SWAP column
+    pub show_swap: bool,
+    /// Show GPU%/VRAM columns from the per-process GPU usage
+    pub show_gpu: bool,
+    /// Process name patterns from `[processes] ignore`
+    ignore_patterns: Vec<String>,
+    /// Temporarily list ignored processes too
+    pub show_ignored: bool,
+    /// Processes hidden by the ignore list on the last `update_rows`
+    pub ignored_count: usize,
+    /// Only this process and its descendants are listed
+    pub follow_pid: Option<u32>,
+    /// Name of `follow_pid` for the panel title
+    pub follow_name: String,
+    /// R/S/D/Z/T breakdown of the listed processes for the header
+    pub state_counts: ProcessStateCounts,
+    /// `[processes] max_rows`: flat lists keep only the top N sorted rows
+    max_rows: Option<usize>,
+    /// Rows before the `max_rows` cap when it cut the list, for the panel title
+    pub truncated_from: Option<usize>,
+    /// Extra filesystem types to hide / always show in disk listings
+    pub hide_fs: Vec<String>,
+    pub show_fs: Vec<String>,
+    /// Temporarily list every filesystem, pseudo ones included
+    pub show_all_fs: bool,
+    pub disk_label_mode: DiskLabelMode,
+    pub temps_sort: TempsSort,
+    pub show_top_cpu: bool,
+    pub show_countdown: bool,
+    pub time_format: TimeFormat,
+    pub byte_unit: ByteUnit,
+    pub temp_unit: TempUnit,
+    pub gpu_compact: bool,
+    pub overview_all_gpus: bool,
+    /// Accessibility: no flashes, blinking or rotating hints
+    pub reduce_motion: bool,
+    /// Render without colors, using bold/underline/reverse instead
+    pub monochrome: bool,
+    pub page_mode: PageMode,
+    /// Overview search panel width in percent
+    pub search_panel_pct: u16,
+    /// Hide the search panel while the filter is not in use
+    pub search_panel_collapsed: bool,
 
     // Dialogs
     pub confirm: Option<ConfirmKill>,
+    pub detail: Option<ProcessDetail>,
+    /// Detail overlay shows the full executable path rather than its file name
+    pub detail_full_path: bool,
+    /// Require a second `q` within `QUIT_CONFIRM_WINDOW` to exit
+    confirm_quit: bool,
+    quit_pending_until: Option<Instant>,
+    /// `[general] open_cwd`: launching `xdg-open` is opt-in
+    open_cwd: bool,
+    /// rtop's own CPU% and resident memory from the last refresh
+    pub self_usage: Option<(f32, u64)>,
 
     // Status
     pub status: Option<StatusMessage>,
+    /// Threshold crossings from `[alerts]`, flashed for a couple of ticks
+    pub alerts: AlertState,
+    alert_actions: AlertActions,
 
     // UI state (layout, scroll, table states)
     pub table_state: TableState,
     pub scroll: usize,
     pub process_body: Option<Rect>,
+    /// Time and PID of the last left click on a process row
+    last_row_click: Option<(Instant, u32)>,
+    double_click: Option<Duration>,
     pub process_header_regions: Vec<HeaderRegion>,
     pub gpu_process_header_regions: Vec<GpuProcessHeaderRegion>,
     pub gpu_process_body: Option<Rect>,
@@ -147,11 +317,19 @@ pub struct App {
     pub container_table_state: TableState,
     pub container_scroll: usize,
     pub system_tab: SystemTab,
+    /// Time of the last terminal resize while the logo rebuild is deferred
+    pub pending_resize: Option<Instant>,
     pub system_tab_regions: Vec<SystemTabRegion>,
     pub system_update_region: Option<Rect>,
     pub show_setup: bool,
     pub show_help: bool,
     pub setup_field: SetupField,
+    /// First visible row of the setup popup
+    pub setup_scroll: usize,
+    /// First visible row of the help overlay
+    pub help_scroll: usize,
+    /// Body rows of the help overlay in the last frame, used for paging
+    pub help_page: usize,
 }
 
 impl App {
@@ -160,55 +338,103 @@ impl App {
         system.refresh_all();
         let users = Users::new_with_refreshed_list();
         let disks = Disks::new_with_refreshed_list();
+        let disk_temps = disk_temperatures(disks.iter().filter_map(|disk| disk.name().to_str()));
         let networks = Networks::new_with_refreshed_list();
         let components = Components::new_with_refreshed_list();
         let current_user_id = system
             .process(Pid::from_u32(std::process::id()))
             .and_then(|process| process.user_id())
             .cloned();
-        let gpu_rx = if config.vram_enabled {
-            Some(start_gpu_monitor(config.gpu_poll_rate))
+        let (gpu_rx, gpu_ctrl_tx) = if config.vram_enabled {
+            let (rx, ctrl_tx) = start_gpu_monitor(config.gpu_poll_rate);
+            (Some(rx), Some(ctrl_tx))
         } else {
-            None
+            (None, None)
         };
         let mut app = Self {
             // Core system data
             system,
+            meminfo: read_meminfo(),
+            pressure: PressureStall::read(),
+            load_average: System::load_average(),
+            task_counts: TaskCounts::read(),
             disks,
+            mount_table: read_mount_table(),
+            disk_temps,
             networks,
             components,
+            hwmon_chips: read_hwmon_chips(),
             network_refresh_secs: None,
+            process_io_secs: None,
+            tick_rate: config.tick_rate,
+            last_refresh: Instant::now(),
+            last_tick: Instant::now(),
+            last_input: Instant::now(),
+            idle_timeout: config.idle_timeout,
+            idle_slowdown: config.idle_slowdown,
+            peak_cpu: 0.0,
+            peak_mem_pct: 0.0,
             users,
             current_user_id,
 
             // Process data
             sort_key: config.sort_key,
             sort_dir: config.sort_dir,
+            default_sort: (config.sort_key, config.sort_dir),
+            sort_secondary: config.sort_secondary,
             tree_view: false,
             rows: Vec::new(),
             process_filter: String::new(),
             process_filter_type: ProcessFilterType::default(),
+            search_highlight: false,
             selected_pid: None,
             tree_labels: HashMap::new(),
+            tree_collapsed: HashSet::new(),
+            tree_branches: HashSet::new(),
+            tree_parents: HashMap::new(),
+            process_grouping: ProcessGrouping::default(),
+            update_strategy: config.update_strategy,
+            gpu_processes_generation: 0,
+            gpu_usage_cache: None,
+            group_members: HashMap::new(),
+            expanded_groups: HashSet::new(),
+            marked: HashMap::new(),
             gui_process_cache: HashMap::new(),
+            unit_cache: HashMap::new(),
+            process_cpu_history: HashMap::new(),
+            cpu_history_sampled_at: None,
+            previous_pids: HashSet::new(),
 
             // GPU data
             vram_enabled: config.vram_enabled,
             gpu_pref: config.gpu_pref,
             gpu_list: Vec::new(),
             gpu_selected: None,
+            gpu_pin: config.gpu_select,
             gpu_processes: Vec::new(),
+            gpu_process_counts: HashMap::new(),
             gpu_process_order: Vec::new(),
+            gpu_util_history: HashMap::new(),
+            gpu_poll_rate: config.gpu_poll_rate,
+            gpu_probe_pending: gpu_rx.is_some(),
             gpu_rx,
+            gpu_ctrl_tx,
 
             // Container data
             container_rows: Vec::new(),
+            container_uptime: config.container_uptime,
+            container_sort: config.container_sort,
+            container_totals: ContainerTotals::default(),
             container_selected: None,
             container_pid_map: HashMap::new(),
             container_filter: None,
             container_net_prev: HashMap::new(),
             container_net_rates: HashMap::new(),
             container_netns_cache: HashMap::new(),
+            container_name_cache: HashMap::new(),
+            container_runtime_list: Vec::new(),
+            container_runtime_rx: None,
+            container_process_source: containers::container_processes,
             container_net_last_sample: None,
             network_last_refresh: Some(Instant::now()),
 
@@ -221,40 +447,90 @@ impl App {
             logo_quality: config.logo_quality,
             logo_cache: None,
             language: config.language,
+            truecolor: detect_truecolor(),
+            debug_overlay: config.debug_overlay,
+            perf: PerfStats::default(),
 
             // View state
-            view_mode: ViewMode::default(),
+            view_mode: config.default_view,
             gpu_focus_panel: GpuFocusPanel::default(),
             gpu_panel_expanded: false,
             processes_focused: false,
             processes_expanded: false,
             process_filter_active: false,
             highlight_mode: HighlightMode::default(),
+            show_start_time: false,
+            process_hscroll: 0,
+            name_min_width: config.name_min_width,
+            script_names: config.script_names,
+            show_priority: config.show_priority,
+            show_unit: config.show_unit,
+            show_swap: config.show_swap,
+            show_gpu: config.show_gpu,
+            ignore_patterns: config.ignore,
+            show_ignored: false,
+            ignored_count: 0,
+            state_counts: ProcessStateCounts::default(),
+            follow_pid: None,
+            follow_name: String::new(),
+            max_rows: config.max_rows,
+            truncated_from: None,
+            hide_fs: config.hide_fs,
+            show_fs: config.show_fs,
+            show_all_fs: false,
+            disk_label_mode: DiskLabelMode::default(),
+            temps_sort: TempsSort::default(),
+            show_top_cpu: config.show_top_cpu,
+            show_countdown: config.show_countdown,
+            time_format: config.time_format,
+            byte_unit: config.byte_unit,
+            temp_unit: config.temp_unit,
+            gpu_compact: config.gpu_compact,
+            overview_all_gpus: config.overview_all_gpus,
+            reduce_motion: config.reduce_motion,
+            monochrome: config.monochrome,
+            page_mode: config.page_mode,
+            search_panel_pct: config.search_panel_pct,
+            search_panel_collapsed: config.search_panel_collapsed,
 
             // Dialogs
             confirm: None,
+            detail: None,
+            detail_full_path: true,
+            confirm_quit: config.confirm_quit,
+            quit_pending_until: None,
+            open_cwd: config.open_cwd,
 
             // Status
+            self_usage: None,
             status: None,
+            alerts: AlertState::new(config.alerts, config.tick_rate * ALERT_FLASH_TICKS),
+            alert_actions: config.alert_actions,
 
             // UI state
             table_state: TableState::default(),
             scroll: 0,
             process_body: None,
+            last_row_click: None,
+            double_click: config.double_click,
             process_header_regions: Vec::new(),
             gpu_process_header_regions: Vec::new(),
             gpu_process_body: None,
             gpu_process_scroll: 0,
-            gpu_process_sort_key: GpuProcessSortKey::Sm,
-            gpu_process_sort_dir: GpuProcessSortKey::Sm.default_dir(),
+            gpu_process_sort_key: DEFAULT_GPU_PROCESS_SORT,
+            gpu_process_sort_dir: DEFAULT_GPU_PROCESS_SORT.default_dir(),
             container_table_state: TableState::default(),
             container_scroll: 0,
             system_tab: SystemTab::default(),
+            pending_resize: None,
             system_tab_regions: Vec::new(),
             system_update_region: None,
             show_setup: false,
             show_help: false,
             setup_field: SetupField::default(),
+            setup_scroll: 0,
+            help_scroll: 0,
+            help_page: 0,
         };
         app.update_rows();
         app.poll_gpu_updates();
@@ -266,12 +542,35 @@ impl App {
         let process_refresh = ProcessRefreshKind::nothing()
             .with_cpu()
             .with_memory()
+            .with_disk_usage()
             .with_user(UpdateKind::OnlyIfNotSet)
-            .with_environ(UpdateKind::OnlyIfNotSet);
-        let refresh_kind = RefreshKind::nothing().with_processes(process_refresh);
+            .with_environ(UpdateKind::OnlyIfNotSet)
+            .with_cmd(if self.script_names {
+                UpdateKind::OnlyIfNotSet
+            } else {
+                UpdateKind::Never
+            });
+        let refresh_kind = RefreshKind::nothing()
+            .with_processes(process_refresh)
+            .with_cpu(CpuRefreshKind::nothing().with_cpu_usage())
+            .with_memory(MemoryRefreshKind::everything());
         self.system.refresh_specifics(refresh_kind);
+        self.meminfo = read_meminfo();
+        self.pressure = PressureStall::read();
+        self.load_average = System::load_average();
+        self.task_counts = TaskCounts::read();
+        self.self_usage = self
+            .system
+            .process(Pid::from_u32(std::process::id()))
+            .map(|process| (process.cpu_usage(), process.memory()));
         self.users.refresh();
         let now = Instant::now();
+        self.process_io_secs = Some(
+            now.saturating_duration_since(self.last_refresh)
+                .as_secs_f64(),
+        )
+        .filter(|value| *value > 0.0);
+        self.last_refresh = now;
         self.network_refresh_secs = self
             .network_last_refresh
             .map(|previous| now.saturating_duration_since(previous).as_secs_f64())
@@ -279,23 +578,41 @@ impl App {
         self.networks.refresh(true);
         self.network_last_refresh = Some(now);
         self.disks.refresh(true);
+        self.mount_table = read_mount_table();
+        self.disk_temps =
+            disk_temperatures(self.disks.iter().filter_map(|disk| disk.name().to_str()));
         self.components.refresh(true);
-        self.update_rows();
+        self.hwmon_chips = read_hwmon_chips();
+        self.observe_system_alerts(now);
+        self.update_peaks();
+        // Containers first: update_rows filters by the drilled-in container's PIDs
         let needs_containers =
             matches!(self.view_mode, ViewMode::Container) || self.container_filter.is_some();
         if needs_containers {
             self.update_containers();
-            if let Some(filter) = self.container_filter.as_ref() {
-                self.rows
-                    .retain(|row| self.container_pid_map.get(&row.pid) == Some(filter));
-                self.sync_selection();
-            }
+        }
+        self.update_rows();
+    }
+
+    /// Run one [`refresh`](Self::refresh) cycle and report how long it took.
+    ///
+    /// Needs no terminal, so the data layer can be profiled from tests and
+    /// benchmarks with an `App` built from [`Config::default`].
+    pub fn refresh_timed(&mut self) -> RefreshStats {
+        let start = Instant::now();
+        self.refresh();
+        RefreshStats {
+            elapsed: start.elapsed(),
+            processes: self.system.processes().len(),
+            rows: self.rows.len(),
+            containers: self.container_rows.len(),
         }
     }
 
     pub fn tick(&mut self) {
         self.poll_gpu_updates();
         self.clear_expired_status();
+        self.alerts.clear_expired(Instant::now());
     }
 
     pub fn set_sort_key(&mut self, key: SortKey) {
@@ -307,6 +624,41 @@ impl App {
         self.update_rows();
     }
 
+    /// Restore the startup sort column and direction.
+    pub fn reset_sort(&mut self) {
+        let (key, dir) = self.default_sort;
+        // Tree view stays sorted by PID
+        if self.tree_view && key != SortKey::Pid {
+            return;
+        }
+        self.sort_key = key;
+        self.sort_dir = dir;
+        self.update_rows();
+    }
+
+    /// Move the sort to the next/previous column, skipping hidden optional ones.
+    pub fn cycle_sort_key(&mut self, forward: bool) {
+        let mut key = self.sort_key;
+        loop {
+            key = if forward { key.next() } else { key.prev() };
+            if self.sort_column_shown(key) {
+                break;
+            }
+        }
+        self.set_sort_key(key);
+    }
+
+    /// Whether the process table shows the column for `key`.
+    pub fn sort_column_shown(&self, key: SortKey) -> bool {
+        match key {
+            SortKey::Priority | SortKey::Nice => self.show_priority,
+            SortKey::Swap => self.show_swap,
+            SortKey::GpuSm | SortKey::GpuVram => self.show_gpu,
+            SortKey::Unit => self.show_unit,
+            _ => true,
+        }
+    }
+
     pub fn toggle_sort_dir(&mut self) {
         if self.tree_view {
             return;
@@ -324,10 +676,136 @@ impl App {
         self.users.get_user_by_id(user_id).map(|user| user.name())
     }
 
+    pub fn toggle_search_highlight(&mut self) {
+        self.search_highlight = !self.search_highlight;
+        self.update_rows();
+        if self.search_highlight {
+            self.select_search_match(true, true);
+        }
+    }
+
+    /// Collapse or restore the Overview search panel.
+    pub fn toggle_search_panel(&mut self) {
+        self.search_panel_collapsed = !self.search_panel_collapsed;
+        let message = if self.search_panel_collapsed {
+            "Search panel hidden until the filter is used"
+        } else {
+            "Search panel shown"
+        };
+        self.set_status(StatusLevel::Info, message.to_string());
+    }
+
+    /// Whether the Overview search panel takes up room next to the table.
+    pub fn search_panel_visible(&self) -> bool {
+        !self.search_panel_collapsed
+            || self.process_filter_active
+            || !self.process_filter.is_empty()
+    }
+
+    /// Re-applies the process search after its text changed.
+    pub fn process_filter_changed(&mut self) {
+        self.update_rows();
+        if self.search_highlight {
+            self.select_search_match(true, true);
+        }
+    }
+
+    pub fn toggle_disk_label_mode(&mut self) {
+        self.disk_label_mode = self.disk_label_mode.toggle();
+    }
+
+    pub fn toggle_temps_sort(&mut self) {
+        self.temps_sort = self.temps_sort.toggle();
+    }
+
+    pub fn toggle_show_ignored(&mut self) {
+        self.show_ignored = !self.show_ignored;
+        self.update_rows();
+    }
+
+    /// True when `name` matches one of the `[processes] ignore` patterns.
+    fn is_ignored(&self, name: &str) -> bool {
+        self.ignore_patterns
+            .iter()
+            .any(|pattern| glob_match(pattern, name))
+    }
+
+    pub fn toggle_show_all_fs(&mut self) {
+        self.show_all_fs = !self.show_all_fs;
+        // The overview caches its disk lines
+        self.system_overview_snapshot = None;
+    }
+
+    /// Handle a quit key; returns true when the app should exit.
+    pub fn request_quit(&mut self) -> bool {
+        if !self.confirm_quit {
+            return true;
+        }
+        let now = Instant::now();
+        if self.quit_pending_until.is_some_and(|until| now < until) {
+            return true;
+        }
+        self.quit_pending_until = Some(now + QUIT_CONFIRM_WINDOW);
+        self.set_status(StatusLevel::Info, "Press q again to quit".to_string());
+        false
+    }
+
     pub fn set_status(&mut self, level: StatusLevel, message: String) {
         self.status = Some(StatusMessage::new(level, message));
     }
 
+    fn update_peaks(&mut self) {
+        let cpu = self.system.global_cpu_usage();
+        let mem_pct = percent(self.system.used_memory(), self.system.total_memory());
+        self.peak_cpu = self.peak_cpu.max(cpu);
+        self.peak_mem_pct = self.peak_mem_pct.max(mem_pct);
+    }
+
+    /// Reset the session CPU/memory watermarks.
+    pub fn reset_peaks(&mut self) {
+        self.peak_cpu = 0.0;
+        self.peak_mem_pct = 0.0;
+        self.update_peaks();
+        self.set_status(StatusLevel::Info, "Peak watermarks reset".to_string());
+    }
+
+    /// True when the last refresh is older than two ticks (frozen or stalled monitor).
+    pub fn is_stale(&self) -> bool {
+        self.last_refresh.elapsed() > self.effective_tick_rate() * 2
+    }
+
+    /// Refresh interval, slowed down once the user has been idle for `idle_timeout`.
+    pub fn effective_tick_rate(&self) -> Duration {
+        match self.idle_timeout {
+            Some(timeout) if self.last_input.elapsed() >= timeout => {
+                self.tick_rate * self.idle_slowdown
+            }
+            _ => self.tick_rate,
+        }
+    }
+
+    /// Time left until the next scheduled refresh tick.
+    pub fn next_refresh_in(&self) -> Duration {
+        self.effective_tick_rate()
+            .saturating_sub(self.last_tick.elapsed())
+    }
+
+    /// Time left until a drag-resize counts as finished, if one is in progress.
+    pub fn resize_settles_in(&self) -> Option<Duration> {
+        self.pending_resize
+            .map(|at| RESIZE_DEBOUNCE.saturating_sub(at.elapsed()))
+            .filter(|left| !left.is_zero())
+    }
+
+    /// Whether expensive size-dependent work may run; clears a settled resize.
+    pub fn resize_settled(&mut self) -> bool {
+        if self.resize_settles_in().is_some() {
+            return false;
+        }
+        self.pending_resize = None;
+        true
+    }
+
     pub fn set_view_mode(&mut self, mode: ViewMode) {
         if mode != ViewMode::Processes && mode != ViewMode::Overview {
             self.container_filter = None;
@@ -402,21 +880,58 @@ impl App {
         self.update_rows();
     }
 
+    pub fn toggle_process_grouping(&mut self) {
+        self.process_grouping = self.process_grouping.toggle();
+        self.expanded_groups.clear();
+        self.update_rows();
+    }
+
+    /// Reveal (or merge back) the individual processes of the selected group.
+    pub fn toggle_group_expand(&mut self) {
+        if self.process_grouping != ProcessGrouping::ByName || self.tree_view {
+            return;
+        }
+        let Some(name) = self.selected_row().map(|row| row.name.clone()) else {
+            return;
+        };
+        if !self.expanded_groups.remove(&name) {
+            self.expanded_groups.insert(name);
+        }
+        self.update_rows();
+    }
+
     pub fn toggle_setup(&mut self) {
         self.show_setup = !self.show_setup;
         if self.show_setup {
             self.show_help = false;
             self.setup_field = SetupField::Language;
+            self.setup_scroll = 0;
         }
     }
 
+    pub fn toggle_debug_overlay(&mut self) {
+        self.debug_overlay = !self.debug_overlay;
+    }
+
     pub fn toggle_help(&mut self) {
         self.show_help = !self.show_help;
         if self.show_help {
             self.show_setup = false;
+            self.help_scroll = 0;
         }
     }
 
+    /// Scroll the help overlay; the next render clamps it to the content.
+    pub fn scroll_help(&mut self, delta: isize) {
+        self.help_scroll = self.help_scroll.saturating_add_signed(delta);
+    }
+
+    /// Keep the help overlay from scrolling past its last row.
+    pub fn clamp_help_scroll(&mut self, total_rows: usize, max_rows: usize) {
+        self.help_page = max_rows;
+        self.help_scroll = self.help_scroll.min(total_rows.saturating_sub(max_rows));
+    }
+
     pub fn next_setup_field(&mut self) {
         self.setup_field = self.setup_field.next();
     }
@@ -425,6 +940,22 @@ impl App {
         self.setup_field = self.setup_field.prev();
     }
 
+    /// Scroll the setup popup so `row` (the focused field) is visible.
+    pub fn ensure_setup_visible(&mut self, row: usize, total_rows: usize, max_rows: usize) {
+        if max_rows == 0 {
+            return;
+        }
+        if row < self.setup_scroll {
+            self.setup_scroll = row;
+        } else if row >= self.setup_scroll + max_rows {
+            self.setup_scroll = row + 1 - max_rows;
+        }
+        let max_scroll = total_rows.saturating_sub(max_rows);
+        if self.setup_scroll > max_scroll {
+            self.setup_scroll = max_scroll;
+        }
+    }
+
     pub fn toggle_setup_field(&mut self) {
         self.next_setup_value();
     }
@@ -435,6 +966,10 @@ impl App {
             SetupField::IconMode => self.toggle_icon_mode(),
             SetupField::LogoMode => self.toggle_logo_mode(),
             SetupField::LogoQuality => self.next_logo_quality(),
+            SetupField::ByteUnit => self.set_byte_unit(self.byte_unit.toggle(), true),
+            SetupField::TempUnit => self.set_temp_unit(self.temp_unit.toggle(), true),
+            SetupField::GpuPollRate => self.adjust_gpu_poll_rate(GPU_POLL_STEP_MS as i64),
+            SetupField::Toggle(toggle) => self.set_setup_toggle(toggle, !self.setup_toggle(toggle)),
         }
     }
 
@@ -444,6 +979,65 @@ impl App {
             SetupField::IconMode => self.toggle_icon_mode(),
             SetupField::LogoMode => self.toggle_logo_mode(),
             SetupField::LogoQuality => self.prev_logo_quality(),
+            SetupField::ByteUnit => self.set_byte_unit(self.byte_unit.toggle(), true),
+            SetupField::TempUnit => self.set_temp_unit(self.temp_unit.toggle(), true),
+            SetupField::GpuPollRate => self.adjust_gpu_poll_rate(-(GPU_POLL_STEP_MS as i64)),
+            SetupField::Toggle(toggle) => self.set_setup_toggle(toggle, !self.setup_toggle(toggle)),
+        }
+    }
+
+    pub fn setup_toggle(&self, toggle: SetupToggle) -> bool {
+        match toggle {
+            SetupToggle::ShowTopCpu => self.show_top_cpu,
+            SetupToggle::ShowCountdown => self.show_countdown,
+            SetupToggle::GpuCompact => self.gpu_compact,
+            SetupToggle::OverviewAllGpus => self.overview_all_gpus,
+            SetupToggle::ReduceMotion => self.reduce_motion,
+            SetupToggle::Monochrome => self.monochrome,
+            SetupToggle::ScriptNames => self.script_names,
+            SetupToggle::ShowPriority => self.show_priority,
+            SetupToggle::ShowUnit => self.show_unit,
+            SetupToggle::ShowSwap => self.show_swap,
+            SetupToggle::ShowGpu => self.show_gpu,
+            SetupToggle::ConfirmQuit => self.confirm_quit,
+        }
+    }
+
+    fn set_setup_toggle(&mut self, toggle: SetupToggle, value: bool) {
+        match toggle {
+            SetupToggle::ShowTopCpu => self.show_top_cpu = value,
+            SetupToggle::ShowCountdown => self.show_countdown = value,
+            SetupToggle::GpuCompact => self.gpu_compact = value,
+            SetupToggle::OverviewAllGpus => {
+                self.overview_all_gpus = value;
+                self.system_overview_snapshot = None;
+            }
+            SetupToggle::ReduceMotion => self.reduce_motion = value,
+            SetupToggle::Monochrome => self.monochrome = value,
+            SetupToggle::ScriptNames => self.script_names = value,
+            SetupToggle::ShowPriority => self.show_priority = value,
+            SetupToggle::ShowUnit => self.show_unit = value,
+            SetupToggle::ShowSwap => self.show_swap = value,
+            SetupToggle::ShowGpu => self.show_gpu = value,
+            SetupToggle::ConfirmQuit => self.confirm_quit = value,
+        }
+        if matches!(
+            toggle,
+            SetupToggle::ScriptNames
+                | SetupToggle::ShowPriority
+                | SetupToggle::ShowUnit
+                | SetupToggle::ShowSwap
+                | SetupToggle::ShowGpu
+        ) {
+            // Don't keep sorting by a column that just disappeared
+            if !self.sort_column_shown(self.sort_key) {
+                self.cycle_sort_key(true);
+            }
+            self.update_rows();
+        }
+        let (section, key) = toggle.config_key();
+        if let Err(err) = super::config::save_bool_option(section, key, value) {
+            self.set_status(StatusLevel::Warn, format!("Failed to save {key}: {err}"));
         }
     }
 
@@ -526,6 +1120,46 @@ impl App {
         }
     }
 
+    /// Flip IEC/SI for a quick cross-check; the config keeps its value.
+    pub fn toggle_byte_unit(&mut self) {
+        self.set_byte_unit(self.byte_unit.toggle(), false);
+        let label = match self.byte_unit {
+            ByteUnit::Iec => "IEC (KiB)",
+            ByteUnit::Si => "SI (kB)",
+        };
+        self.set_status(StatusLevel::Info, format!("Units: {label}"));
+    }
+
+    fn set_byte_unit(&mut self, unit: ByteUnit, persist: bool) {
+        self.byte_unit = unit;
+        self.system_overview_snapshot = None;
+        if persist && let Err(err) = super::config::save_byte_unit(unit) {
+            self.set_status(
+                StatusLevel::Warn,
+                format!("Failed to save display preferences: {err}"),
+            );
+        }
+    }
+
+    /// Flip °C/°F for this session; the config keeps its value.
+    pub fn toggle_temp_unit(&mut self) {
+        self.set_temp_unit(self.temp_unit.toggle(), false);
+        self.set_status(
+            StatusLevel::Info,
+            format!("Temperatures: {}", self.temp_unit.suffix()),
+        );
+    }
+
+    fn set_temp_unit(&mut self, unit: TempUnit, persist: bool) {
+        self.temp_unit = unit;
+        if persist && let Err(err) = super::config::save_temp_unit(unit) {
+            self.set_status(
+                StatusLevel::Warn,
+                format!("Failed to save display preferences: {err}"),
+            );
+        }
+    }
+
     pub fn sort_key_for_header_click(&self, column: u16, row: u16) -> Option<SortKey> {
         self.process_header_regions
             .iter()
@@ -559,6 +1193,10 @@ impl App {
         self.gpu_process_sort_dir = key.default_dir();
     }
 
+    pub fn reset_gpu_process_sort(&mut self) {
+        self.set_gpu_process_sort_key(DEFAULT_GPU_PROCESS_SORT);
+    }
+
     pub fn toggle_gpu_process_sort_dir(&mut self) {
         self.gpu_process_sort_dir = self.gpu_process_sort_dir.toggle();
     }
@@ -608,6 +1246,211 @@ impl App {
     /// Apply GPU snapshot from event system
     pub fn apply_gpu_snapshot(&mut self, snapshot: crate::data::gpu::GpuSnapshot) {
         self.update_gpu_list(snapshot.gpus);
-        self.gpu_processes = snapshot.processes;
+        self.set_gpu_processes(snapshot.processes);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::ContainerState;
+
+    fn headless_app() -> App {
+        App::new(Config {
+            vram_enabled: false,
+            ..Config::default()
+        })
+    }
+
+    #[test]
+    fn reset_sort_restores_startup_key_and_direction() {
+        let mut app = headless_app();
+        let (key, dir) = app.default_sort;
+        app.set_sort_key(SortKey::Name);
+        app.sort_dir = SortDir::Asc;
+        app.reset_sort();
+        assert!(app.sort_key == key && app.sort_dir == dir);
+    }
+
+    #[test]
+    fn followed_process_survives_the_ignore_list() {
+        let pid = std::process::id();
+        let mut app = App::new(Config {
+            vram_enabled: false,
+            ignore: vec!["*".to_string()],
+            ..Config::default()
+        });
+        app.follow_pid = Some(pid);
+        app.follow_name = "rtop-test".to_string();
+        app.update_rows();
+        assert_eq!(app.follow_pid, Some(pid));
+        assert!(app.rows.iter().any(|row| row.pid == pid));
+        assert!(app.status.is_none());
+    }
+
+    #[test]
+    fn follow_ends_when_the_process_is_gone() {
+        let mut app = headless_app();
+        app.follow_pid = Some(u32::MAX);
+        app.follow_name = "gone".to_string();
+        app.update_rows();
+        assert_eq!(app.follow_pid, None);
+        assert_eq!(
+            app.status.as_ref().map(|status| status.text.as_str()),
+            Some(format!("Followed process gone ({}) exited", u32::MAX).as_str())
+        );
+    }
+
+    fn container_key(id: &str) -> ContainerKey {
+        ContainerKey {
+            runtime: crate::data::ContainerRuntime::Docker,
+            id: id.to_string(),
+        }
+    }
+
+    /// Headless app whose only container process is the test process itself
+    /// (in "web") and whose runtime API reports `runtime_list`.
+    fn container_app(runtime_list: Vec<RuntimeContainer>) -> App {
+        let mut app = headless_app();
+        app.container_process_source = |_| {
+            vec![containers::ContainerProcess {
+                pid: std::process::id(),
+                key: container_key("web"),
+                cpu: 12.5,
+                mem_bytes: 4096,
+                run_time: 60,
+            }]
+        };
+        let (tx, rx) = mpsc::channel();
+        tx.send(runtime_list).unwrap();
+        app.container_runtime_rx = Some(rx);
+        app
+    }
+
+    fn runtime_container(id: &str, name: &str, state: ContainerState) -> RuntimeContainer {
+        RuntimeContainer {
+            key: container_key(id),
+            name: Some(name.to_string()),
+            state,
+        }
+    }
+
+    #[test]
+    fn refresh_timed_reports_counts() {
+        let mut app = container_app(vec![runtime_container("db", "db", ContainerState::Exited)]);
+        app.container_filter = Some(container_key("web"));
+        let stats = app.refresh_timed();
+        assert_eq!(stats.processes, app.system.processes().len());
+        assert_eq!(stats.containers, 2);
+        // Only the test process runs in the drilled-in container
+        assert_eq!(stats.rows, 1);
+        assert_eq!(app.rows[0].pid, std::process::id());
+    }
+
+    #[test]
+    fn incremental_strategy_keeps_gpu_usage_until_next_snapshot() {
+        let mut app = App::new(Config {
+            vram_enabled: false,
+            update_strategy: UpdateStrategy::Incremental,
+            ..Config::default()
+        });
+        let cached_generation = |app: &App| {
+            app.gpu_usage_cache
+                .as_ref()
+                .map(|(generation, _)| *generation)
+        };
+        app.update_rows();
+        assert_eq!(cached_generation(&app), Some(0));
+        app.update_rows();
+        assert_eq!(cached_generation(&app), Some(0));
+        app.set_gpu_processes(Vec::new());
+        app.update_rows();
+        assert_eq!(cached_generation(&app), Some(1));
+
+        let mut full = headless_app();
+        full.update_rows();
+        assert_eq!(cached_generation(&full), None);
+    }
+
+    #[test]
+    fn help_scroll_stops_at_last_page() {
+        let mut app = headless_app();
+        app.toggle_help();
+        app.scroll_help(-1);
+        assert_eq!(app.help_scroll, 0);
+        app.scroll_help(50);
+        app.clamp_help_scroll(40, 15);
+        assert_eq!(app.help_scroll, 25);
+        app.clamp_help_scroll(10, 15);
+        assert_eq!(app.help_scroll, 0);
+
+        app.scroll_help(3);
+        app.toggle_help();
+        app.toggle_help();
+        assert_eq!(app.help_scroll, 0);
+    }
+
+    #[test]
+    fn second_click_on_same_process_is_double() {
+        let mut app = headless_app();
+        let start = Instant::now();
+        assert!(!app.register_row_click(500, start));
+        assert!(app.register_row_click(500, start + Duration::from_millis(100)));
+        // A third click starts over instead of chaining
+        assert!(!app.register_row_click(500, start + Duration::from_millis(200)));
+        // A re-sort put another process under the cursor
+        assert!(!app.register_row_click(600, start + Duration::from_millis(300)));
+        assert!(!app.register_row_click(600, start + Duration::from_secs(2)));
+
+        app.double_click = None;
+        assert!(!app.register_row_click(600, start + Duration::from_millis(2100)));
+    }
+
+    #[test]
+    fn max_rows_caps_after_container_filter() {
+        let mut app = headless_app();
+        let key = ContainerKey {
+            runtime: crate::data::ContainerRuntime::Docker,
+            id: "abc".to_string(),
+        };
+        // The two rows sorting last would fall outside any global top 1
+        let members: Vec<u32> = app.rows.iter().rev().take(2).map(|row| row.pid).collect();
+        for pid in &members {
+            app.container_pid_map.insert(*pid, key.clone());
+        }
+        app.container_filter = Some(key);
+        app.max_rows = Some(1);
+        app.update_rows();
+        assert_eq!(app.rows.len(), 1);
+        assert!(members.contains(&app.rows[0].pid));
+        assert_eq!(app.truncated_from, Some(2));
+    }
+
+    #[test]
+    fn update_containers_merges_processes_and_runtime_list() {
+        let mut app = container_app(vec![
+            runtime_container("web", "frontend", ContainerState::Running),
+            runtime_container("db", "db", ContainerState::Exited),
+        ]);
+        app.update_containers();
+        let labels: Vec<_> = app
+            .container_rows
+            .iter()
+            .map(|row| (row.label.as_str(), row.state, row.proc_count))
+            .collect();
+        assert_eq!(
+            labels,
+            vec![
+                ("docker:frontend", ContainerState::Running, 1),
+                ("docker:db", ContainerState::Exited, 0),
+            ]
+        );
+        assert_eq!(app.container_rows[0].mem_bytes, 4096);
+        // Totals count running containers only
+        assert_eq!(app.container_totals.count, 1);
+        assert_eq!(
+            app.container_pid_map.get(&std::process::id()),
+            Some(&container_key("web"))
+        );
     }
 }
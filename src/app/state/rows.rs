@@ -1,10 +1,20 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
-use sysinfo::Uid;
+use sysinfo::{Pid, Uid};
 
-use super::{App, ProcessFilterType, ProcessGpuUsage};
+use super::{
+    App, ProcessFilterType, ProcessGpuUsage, ProcessGrouping, StatusLevel, UpdateStrategy,
+};
 use crate::data::gpu::GpuProcessUsage;
-use crate::data::{ProcessRow, sort_process_rows};
+use crate::data::{
+    ProcessRow, ProcessStateCounts, display_name, read_sched_priority, read_swap_bytes,
+    script_name, sort_process_rows, systemd_unit_for_pid,
+};
+
+/// Combined read + write rate that marks a process as busy with disk I/O
+const IO_ACTIVE_BYTES_PER_SEC: f64 = 4.0 * 1024.0 * 1024.0;
+/// CPU samples kept per process for the detail overlay sparkline
+const PROCESS_HISTORY_LEN: usize = 60;
 
 fn build_gpu_usage_map(gpu_processes: &[GpuProcessUsage]) -> HashMap<u32, ProcessGpuUsage> {
     let mut map = HashMap::with_capacity(gpu_processes.len());
@@ -34,21 +44,69 @@ pub(super) fn merge_optional_max(current: &mut Option<f32>, incoming: Option<f32
 
 impl App {
     pub fn update_rows(&mut self) {
-        let gpu_usage = build_gpu_usage_map(&self.gpu_processes);
+        let gpu_usage = match self.gpu_usage_cache.take() {
+            Some((generation, map))
+                if self.update_strategy == UpdateStrategy::Incremental
+                    && generation == self.gpu_processes_generation =>
+            {
+                map
+            }
+            _ => build_gpu_usage_map(&self.gpu_processes),
+        };
+        // The followed subtree comes from every process, so an ignored
+        // ancestor doesn't cut off its descendants
+        let followed = match self.follow_pid {
+            Some(root) if self.system.process(Pid::from_u32(root)).is_none() => {
+                self.follow_pid = None;
+                self.set_status(
+                    StatusLevel::Info,
+                    format!("Followed process {} ({root}) exited", self.follow_name),
+                );
+                None
+            }
+            Some(root) => {
+                let all_parents = self
+                    .system
+                    .processes()
+                    .iter()
+                    .map(|(pid, process)| {
+                        (pid.as_u32(), process.parent().map(|parent| parent.as_u32()))
+                    })
+                    .collect();
+                Some((root, super::tree::subtree_pids(root, &all_parents)))
+            }
+            None => None,
+        };
         let current_user_id = self.current_user_id.as_ref();
         let mut rows_map = HashMap::with_capacity(self.system.processes().len());
         let mut parents = HashMap::with_capacity(self.system.processes().len());
 
         // Collect current PIDs for cache cleanup
-        let current_pids: std::collections::HashSet<u32> = self
+        let current_pids: HashSet<u32> = self
             .system
             .processes()
             .keys()
             .map(|pid| pid.as_u32())
             .collect();
 
+        // Disk usage deltas cover the interval since the previous refresh
+        let io_threshold = self
+            .process_io_secs
+            .map(|secs| (IO_ACTIVE_BYTES_PER_SEC * secs) as u64);
+
+        // Nothing is "new" on the first pass
+        let track_new = !self.previous_pids.is_empty();
+
+        let mut ignored_count = 0;
         for (pid, process) in self.system.processes() {
             let pid = pid.as_u32();
+            // Children spawned since the last update are picked up here
+            if followed
+                .as_ref()
+                .is_some_and(|(_, subtree)| !subtree.contains(&pid))
+            {
+                continue;
+            }
             let user_id = process.user_id();
             let user = user_id
                 .and_then(|id| self.users.get_user_by_id(id))
@@ -64,22 +122,76 @@ impl App {
                 .entry(pid)
                 .or_insert_with(|| is_gui_process(process.environ()));
 
+            let (process_name, name_lossy) = display_name(process.name());
+            let mut name = process_name.clone();
+            if self.script_names {
+                let cmd = process
+                    .cmd()
+                    .iter()
+                    .map(|arg| arg.to_string_lossy().into_owned())
+                    .collect::<Vec<_>>();
+                if let Some(script) = script_name(&name, &cmd) {
+                    name = script;
+                }
+            }
+            // Ignored processes are dropped before tree building and sorting;
+            // their children become roots in the tree. The followed process
+            // itself always stays listed
+            let is_follow_root = followed.as_ref().is_some_and(|(root, _)| *root == pid);
+            if !is_follow_root && (self.is_ignored(&process_name) || self.is_ignored(&name)) {
+                ignored_count += 1;
+                if !self.show_ignored {
+                    continue;
+                }
+            }
+
             parents.insert(pid, process.parent().map(|parent| parent.as_u32()));
 
+            let disk_usage = process.disk_usage();
+            let io_bytes = disk_usage.read_bytes + disk_usage.written_bytes;
+            let io_active = io_threshold.is_some_and(|threshold| io_bytes >= threshold);
+            let unit = if self.show_unit {
+                self.unit_cache
+                    .entry(pid)
+                    .or_insert_with(|| systemd_unit_for_pid(pid))
+                    .clone()
+            } else {
+                None
+            };
+            // Unreadable status (other users' processes) leaves the column at "-"
+            let swap_bytes = if self.show_swap {
+                read_swap_bytes(pid)
+            } else {
+                None
+            };
+            let (priority, nice) = if self.show_priority {
+                read_sched_priority(pid).unwrap_or_default()
+            } else {
+                (0, 0)
+            };
+
             rows_map.insert(
                 pid,
                 ProcessRow {
                     pid,
                     user,
-                    name: process.name().to_string_lossy().into_owned(),
+                    name,
                     cpu: process.cpu_usage(),
                     mem_bytes: process.memory(),
+                    cpu_time_ms: process.accumulated_cpu_time(),
                     status: format!("{:?}", process.status()),
                     start_time: process.start_time(),
                     uptime_secs: process.run_time(),
                     is_current_user,
                     is_non_root,
                     is_gui,
+                    is_new: track_new && !self.previous_pids.contains(&pid),
+                    name_lossy,
+                    io_active,
+                    nice,
+                    priority,
+                    unit,
+                    swap_bytes,
                     gpu_sm_pct: gpu_usage.get(&pid).and_then(|usage| usage.sm_pct),
                     gpu_mem_pct: gpu_usage.get(&pid).and_then(|usage| usage.mem_pct),
                     gpu_enc_pct: gpu_usage.get(&pid).and_then(|usage| usage.enc_pct),
@@ -92,10 +204,23 @@ impl App {
             );
         }
 
+        self.ignored_count = ignored_count;
+        self.state_counts =
+            ProcessStateCounts::from_statuses(rows_map.values().map(|row| row.status.as_str()));
+        if self.update_strategy == UpdateStrategy::Incremental {
+            self.gpu_usage_cache = Some((self.gpu_processes_generation, gpu_usage));
+        }
+
         if self.tree_view {
-            let layout = super::tree::build_tree_layout(&parents, &rows_map);
+            let layout = super::tree::build_tree_layout(&parents, &rows_map, &self.tree_collapsed);
             let mut rows = Vec::with_capacity(rows_map.len());
             let mut rows_map = rows_map;
+            rows_map.retain(|pid, _| !layout.hidden.contains(pid));
+            // Forget collapsed PIDs that exited or no longer have children
+            self.tree_collapsed
+                .retain(|pid| layout.branches.contains(pid));
+            self.tree_branches = layout.branches;
+            self.tree_parents = layout.parents;
             for pid in layout.order {
                 if let Some(row) = rows_map.remove(&pid) {
                     rows.push(row);
@@ -110,46 +235,143 @@ impl App {
             self.tree_labels = layout.labels;
         } else {
             let mut rows = rows_map.into_values().collect::<Vec<_>>();
-            sort_process_rows(&mut rows, self.sort_key, self.sort_dir);
+            self.group_members.clear();
+            if self.process_grouping == ProcessGrouping::ByName {
+                rows = group_rows_by_name(rows, &self.expanded_groups, &mut self.group_members);
+            }
+            sort_process_rows(&mut rows, self.sort_key, self.sort_dir, self.sort_secondary);
             self.rows = rows;
             self.tree_labels.clear();
+            self.tree_parents.clear();
         }
 
-        let filter = self.process_filter.trim();
-        if !filter.is_empty() {
-            let needle = filter.to_lowercase();
-            match self.process_filter_type {
-                ProcessFilterType::Name => {
-                    self.rows
-                        .retain(|row| row.name.to_lowercase().contains(&needle));
-                }
-                ProcessFilterType::Pid => {
-                    if let Ok(pid) = filter.parse::<u32>() {
-                        self.rows.retain(|row| row.pid == pid);
-                    } else {
-                        // Partial PID match - filter by prefix
-                        self.rows
-                            .retain(|row| row.pid.to_string().starts_with(filter));
-                    }
-                }
-                ProcessFilterType::User => {
-                    self.rows.retain(|row| {
-                        row.user
-                            .as_ref()
-                            .is_some_and(|user| user.to_lowercase().contains(&needle))
-                    });
-                }
-            }
+        // In search-highlight mode matches are only marked, never hidden
+        let needle = self.process_filter.trim().to_lowercase();
+        if !needle.is_empty() && !self.search_highlight {
+            let filter_type = self.process_filter_type;
+            self.rows
+                .retain(|row| row_matches_filter(row, &needle, filter_type));
+        }
+        if let Some(filter) = self.container_filter.as_ref() {
+            self.rows
+                .retain(|row| self.container_pid_map.get(&row.pid) == Some(filter));
+        }
+
+        // Cap the sorted flat list after every filter so "top N of M" counts
+        // what is shown; the tree needs every row to stay connected
+        self.truncated_from = None;
+        if let Some(max_rows) = self.max_rows
+            && !self.tree_view
+            && self.rows.len() > max_rows
+        {
+            self.truncated_from = Some(self.rows.len());
+            self.rows.truncate(max_rows);
         }
 
         // Clean up GUI cache for dead processes
         self.gui_process_cache
             .retain(|pid, _| current_pids.contains(pid));
+        self.unit_cache.retain(|pid, _| current_pids.contains(pid));
+        self.record_cpu_history(&current_pids);
+        self.previous_pids = current_pids;
+        // Marks follow the PID across refreshes but drop once it exits or is reused
+        let processes = self.system.processes();
+        self.marked.retain(|pid, start_time| {
+            processes
+                .get(&sysinfo::Pid::from_u32(*pid))
+                .is_some_and(|process| process.start_time() == *start_time)
+        });
 
         self.sync_selection();
     }
 }
 
+/// Whether `row` matches the process search `needle` (trimmed, lowercased, non-empty).
+pub(super) fn row_matches_filter(
+    row: &ProcessRow,
+    needle: &str,
+    filter_type: ProcessFilterType,
+) -> bool {
+    match filter_type {
+        ProcessFilterType::Name => row.name.to_lowercase().contains(needle),
+        ProcessFilterType::Pid => match needle.parse::<u32>() {
+            Ok(pid) => row.pid == pid,
+            // Partial PID match - filter by prefix
+            Err(_) => row.pid.to_string().starts_with(needle),
+        },
+        ProcessFilterType::User => row
+            .user
+            .as_ref()
+            .is_some_and(|user| user.to_lowercase().contains(needle)),
+    }
+}
+
+impl App {
+    /// Append the selected process's CPU% once per refresh; only the selection
+    /// is sampled to keep the cost flat, and exited PIDs are forgotten.
+    fn record_cpu_history(&mut self, current_pids: &HashSet<u32>) {
+        self.process_cpu_history
+            .retain(|pid, _| current_pids.contains(pid));
+        if self.cpu_history_sampled_at == Some(self.last_refresh) {
+            return;
+        }
+        self.cpu_history_sampled_at = Some(self.last_refresh);
+        let Some(pid) = self
+            .detail
+            .as_ref()
+            .map(|detail| detail.pid)
+            .or(self.selected_pid)
+        else {
+            return;
+        };
+        let Some(process) = self.system.process(Pid::from_u32(pid)) else {
+            return;
+        };
+        let history = self.process_cpu_history.entry(pid).or_default();
+        if history.len() == PROCESS_HISTORY_LEN {
+            history.pop_front();
+        }
+        history.push_back(process.cpu_usage());
+    }
+}
+
+/// Merge rows sharing a name into one row per name (lowest PID represents the group).
+/// Names in `expanded` are kept as individual rows.
+fn group_rows_by_name(
+    rows: Vec<ProcessRow>,
+    expanded: &HashSet<String>,
+    members: &mut HashMap<u32, Vec<(u32, u64)>>,
+) -> Vec<ProcessRow> {
+    let mut groups: HashMap<String, Vec<ProcessRow>> = HashMap::new();
+    for row in rows {
+        groups.entry(row.name.clone()).or_default().push(row);
+    }
+
+    let mut output = Vec::with_capacity(groups.len());
+    for (name, mut group) in groups {
+        if group.len() == 1 || expanded.contains(&name) {
+            output.extend(group);
+            continue;
+        }
+        group.sort_by_key(|row| row.pid);
+        let cpu = group.iter().map(|row| row.cpu).sum();
+        let mem_bytes = group.iter().map(|row| row.mem_bytes).sum();
+        let cpu_time_ms = group.iter().map(|row| row.cpu_time_ms).sum();
+        let uptime_secs = group.iter().map(|row| row.uptime_secs).max().unwrap_or(0);
+        let io_active = group.iter().any(|row| row.io_active);
+        let group_members = group.iter().map(|row| (row.pid, row.start_time)).collect();
+        let mut merged = group.swap_remove(0);
+        merged.cpu = cpu;
+        merged.mem_bytes = mem_bytes;
+        merged.cpu_time_ms = cpu_time_ms;
+        merged.uptime_secs = uptime_secs;
+        merged.io_active = io_active;
+        members.insert(merged.pid, group_members);
+        output.push(merged);
+    }
+    output
+}
+
 fn is_gui_process(environ: &[std::ffi::OsString]) -> bool {
     environ.iter().any(|entry| {
         let s = entry.to_string_lossy();
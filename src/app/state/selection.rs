@@ -1,6 +1,48 @@
-use super::App;
+use std::time::Instant;
+
+use super::rows::row_matches_filter;
+use super::{App, PageMode};
+use crate::data::ProcessRow;
 
 impl App {
+    /// Lowercased filter text while search-highlight mode marks matches.
+    ///
+    /// Compute it once per frame and pass it to [`Self::is_search_match`].
+    pub fn search_highlight_needle(&self) -> Option<String> {
+        let needle = self.process_filter.trim().to_lowercase();
+        (self.search_highlight && !needle.is_empty()).then_some(needle)
+    }
+
+    /// Whether `row` matches a needle from [`Self::search_highlight_needle`].
+    pub fn is_search_match(&self, row: &ProcessRow, needle: &str) -> bool {
+        row_matches_filter(row, needle, self.process_filter_type)
+    }
+
+    /// Moves the selection to the next (or previous) search match, wrapping
+    /// around. `from_current` also accepts the selected row itself.
+    pub fn select_search_match(&mut self, forward: bool, from_current: bool) {
+        let needle = self.process_filter.trim().to_lowercase();
+        if needle.is_empty() || self.rows.is_empty() {
+            return;
+        }
+        let len = self.rows.len();
+        let current = self.table_state.selected().unwrap_or(0).min(len - 1);
+        let start = usize::from(!from_current);
+        let found = (start..len + start)
+            .map(|step| {
+                if forward {
+                    (current + step) % len
+                } else {
+                    (current + len - step % len) % len
+                }
+            })
+            .find(|&idx| row_matches_filter(&self.rows[idx], &needle, self.process_filter_type));
+        if let Some(idx) = found {
+            self.table_state.select(Some(idx));
+            self.selected_pid = Some(self.rows[idx].pid);
+        }
+    }
+
     pub(super) fn sync_selection(&mut self) {
         if self.rows.is_empty() {
             self.table_state.select(None);
@@ -39,6 +81,23 @@ impl App {
         self.selected_pid = Some(self.rows[new_index].pid);
     }
 
+    /// PageUp/PageDown in the process table, following `page_mode`.
+    pub fn page_selection(&mut self, delta: i32) {
+        let page = self
+            .process_body
+            .map(|rect| rect.height as usize)
+            .unwrap_or(0);
+        if self.page_mode == PageMode::Cursor || self.rows.is_empty() || page == 0 {
+            self.move_selection(delta);
+            return;
+        }
+        let selected = self.table_state.selected().unwrap_or(0);
+        let (scroll, selected) = viewport_page(selected, self.scroll, self.rows.len(), page, delta);
+        self.scroll = scroll;
+        self.table_state.select(Some(selected));
+        self.selected_pid = Some(self.rows[selected].pid);
+    }
+
     pub fn move_gpu_process_selection(&mut self, delta: i32) {
         let len = self.gpu_process_order.len();
         if len == 0 {
@@ -108,6 +167,21 @@ impl App {
         self.selected_pid = Some(self.rows[idx].pid);
     }
 
+    /// Record a click on the row of `pid`; true when it completes a
+    /// double-click on the same process within `double_click`.
+    ///
+    /// Matching the PID rather than the screen row keeps a re-sort between
+    /// the two clicks from targeting whatever process moved under the cursor.
+    pub fn register_row_click(&mut self, pid: u32, now: Instant) -> bool {
+        let double = self.double_click.is_some_and(|interval| {
+            self.last_row_click.is_some_and(|(at, last_pid)| {
+                last_pid == pid && now.saturating_duration_since(at) <= interval
+            })
+        });
+        self.last_row_click = if double { None } else { Some((now, pid)) };
+        double
+    }
+
     pub fn select_process_pid(&mut self, pid: u32) {
         self.selected_pid = Some(pid);
         if let Some(index) = self.rows.iter().position(|row| row.pid == pid) {
@@ -165,3 +239,49 @@ impl App {
         }
     }
 }
+
+/// Scroll by `delta` rows and keep the selection at the same offset from the
+/// top, returning (scroll, selected). Once the view cannot scroll any further
+/// the selection moves instead, so repeated paging still reaches the ends.
+fn viewport_page(
+    selected: usize,
+    scroll: usize,
+    len: usize,
+    page: usize,
+    delta: i32,
+) -> (usize, usize) {
+    let last = len.saturating_sub(1);
+    let max_scroll = len.saturating_sub(page);
+    let scroll = scroll.min(max_scroll);
+    let offset = selected.saturating_sub(scroll);
+    let steps = delta.unsigned_abs() as usize;
+    let new_scroll = if delta < 0 {
+        scroll.saturating_sub(steps)
+    } else {
+        (scroll + steps).min(max_scroll)
+    };
+    if new_scroll == scroll {
+        let edge = if delta < 0 { 0 } else { last };
+        return (scroll, edge);
+    }
+    (new_scroll, (new_scroll + offset).min(last))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn viewport_page_keeps_screen_row() {
+        // 100 rows, 10 visible, selection on the 4th visible row
+        assert_eq!(viewport_page(23, 20, 100, 10, 9), (29, 32));
+        assert_eq!(viewport_page(23, 20, 100, 10, -9), (11, 14));
+    }
+
+    #[test]
+    fn viewport_page_clamps_at_ends() {
+        assert_eq!(viewport_page(93, 88, 100, 10, 9), (90, 95));
+        assert_eq!(viewport_page(95, 90, 100, 10, 9), (90, 99));
+        assert_eq!(viewport_page(3, 0, 100, 10, -9), (0, 0));
+    }
+}
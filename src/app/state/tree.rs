@@ -1,16 +1,129 @@
 use std::collections::{HashMap, HashSet};
 
-use crate::data::ProcessRow;
+use sysinfo::Pid;
+
+use super::{App, StatusLevel};
+use crate::data::{ProcessRow, display_name};
 
 pub(super) struct TreeLayout {
     pub(super) order: Vec<u32>,
     pub(super) labels: HashMap<u32, String>,
+    /// PIDs that have at least one child (can be collapsed)
+    pub(super) branches: HashSet<u32>,
+    /// Descendants of collapsed nodes, left out of `order`
+    pub(super) hidden: HashSet<u32>,
+    /// Child -> parent for every edge drawn in the tree
+    pub(super) parents: HashMap<u32, u32>,
 }
 
-pub(super) fn build_tree_layout(
-    parents: &HashMap<u32, Option<u32>>,
-    rows: &HashMap<u32, ProcessRow>,
-) -> TreeLayout {
+impl App {
+    /// Collapse or expand the subtree of the selected process.
+    pub fn toggle_tree_collapse(&mut self) {
+        if !self.tree_view {
+            return;
+        }
+        let Some(pid) = self.selected_pid else {
+            return;
+        };
+        if !self.tree_collapsed.remove(&pid) && self.tree_branches.contains(&pid) {
+            self.tree_collapsed.insert(pid);
+        }
+        self.update_rows();
+    }
+
+    /// Collapse every non-leaf node, leaving only the roots visible.
+    pub fn collapse_all_tree(&mut self) {
+        if !self.tree_view {
+            return;
+        }
+        self.tree_collapsed = self.tree_branches.clone();
+        self.update_rows();
+    }
+
+    pub fn expand_all_tree(&mut self) {
+        if !self.tree_view {
+            return;
+        }
+        self.tree_collapsed.clear();
+        self.update_rows();
+    }
+
+    /// Pin the list to the selected process and its descendants, or unpin it.
+    pub fn toggle_follow(&mut self) {
+        if let Some(pid) = self.follow_pid.take() {
+            self.set_status(StatusLevel::Info, format!("Stopped following PID {pid}"));
+        } else {
+            let Some(pid) = self.selected_pid else {
+                return;
+            };
+            let Some(process) = self.system.process(Pid::from_u32(pid)) else {
+                return;
+            };
+            let (name, _) = display_name(process.name());
+            self.set_status(
+                StatusLevel::Info,
+                format!("Following {name} ({pid}) and its children"),
+            );
+            self.follow_pid = Some(pid);
+            self.follow_name = name;
+        }
+        self.update_rows();
+    }
+
+    /// Jump to the parent of the selected process in the tree.
+    pub fn select_parent(&mut self) {
+        if !self.tree_view {
+            return;
+        }
+        let Some(pid) = self.selected_pid else {
+            return;
+        };
+        let Some(&parent) = self.tree_parents.get(&pid) else {
+            self.set_status(StatusLevel::Info, format!("PID {pid} is a tree root"));
+            return;
+        };
+        if self.rows.iter().any(|row| row.pid == parent) {
+            self.select_process_pid(parent);
+        } else {
+            self.set_status(
+                StatusLevel::Info,
+                format!("Parent PID {parent} is hidden by the filter"),
+            );
+        }
+    }
+
+    /// Jump to the first child of the selected process, expanding it if folded.
+    pub fn select_first_child(&mut self) {
+        if !self.tree_view {
+            return;
+        }
+        let Some(pid) = self.selected_pid else {
+            return;
+        };
+        if !self.tree_branches.contains(&pid) {
+            self.set_status(StatusLevel::Info, format!("PID {pid} has no children"));
+            return;
+        }
+        if self.tree_collapsed.remove(&pid) {
+            self.update_rows();
+        }
+        // Rows follow the tree order, so the first match is the first child
+        let child = self
+            .rows
+            .iter()
+            .find(|row| self.tree_parents.get(&row.pid) == Some(&pid))
+            .map(|row| row.pid);
+        match child {
+            Some(child) => self.select_process_pid(child),
+            None => self.set_status(
+                StatusLevel::Info,
+                format!("Children of PID {pid} are hidden by the filter"),
+            ),
+        }
+    }
+}
+
+fn children_map(parents: &HashMap<u32, Option<u32>>) -> HashMap<u32, Vec<u32>> {
     let mut children: HashMap<u32, Vec<u32>> = HashMap::new();
     for (&pid, parent) in parents.iter() {
         if let Some(parent) = *parent {
@@ -20,6 +133,30 @@ pub(super) fn build_tree_layout(
     for list in children.values_mut() {
         list.sort_unstable();
     }
+    children
+}
+
+/// `root` plus every process whose parent chain reaches it.
+pub(super) fn subtree_pids(root: u32, parents: &HashMap<u32, Option<u32>>) -> HashSet<u32> {
+    let children = children_map(parents);
+    let mut subtree = HashSet::from([root]);
+    let mut stack = vec![root];
+    while let Some(pid) = stack.pop() {
+        for &child in children.get(&pid).into_iter().flatten() {
+            if subtree.insert(child) {
+                stack.push(child);
+            }
+        }
+    }
+    subtree
+}
+
+pub(super) fn build_tree_layout(
+    parents: &HashMap<u32, Option<u32>>,
+    rows: &HashMap<u32, ProcessRow>,
+    collapsed: &HashSet<u32>,
+) -> TreeLayout {
+    let children = children_map(parents);
 
     let mut roots = Vec::new();
     for (&pid, parent) in parents.iter() {
@@ -35,6 +172,9 @@ pub(super) fn build_tree_layout(
     let mut layout = TreeLayout {
         order: Vec::with_capacity(rows.len()),
         labels: HashMap::with_capacity(rows.len()),
+        branches: children.keys().copied().collect(),
+        hidden: HashSet::new(),
+        parents: HashMap::new(),
     };
     let mut visited = HashSet::with_capacity(rows.len());
 
@@ -47,6 +187,7 @@ pub(super) fn build_tree_layout(
             true,
             &children,
             rows,
+            collapsed,
             &mut layout,
             &mut visited,
         );
@@ -63,6 +204,7 @@ fn push_tree_layout(
     is_root: bool,
     children: &HashMap<u32, Vec<u32>>,
     rows: &HashMap<u32, ProcessRow>,
+    collapsed: &HashSet<u32>,
     layout: &mut TreeLayout,
     visited: &mut HashSet<u32>,
 ) {
@@ -80,10 +222,17 @@ fn push_tree_layout(
     } else {
         "|- "
     };
-    let label = format!("{prefix}{connector}{}", row.name);
+    let is_collapsed = collapsed.contains(&pid) && children.contains_key(&pid);
+    let marker = if is_collapsed { "[+] " } else { "" };
+    let label = format!("{prefix}{connector}{marker}{}", row.name);
     layout.labels.insert(pid, label);
     layout.order.push(pid);
 
+    if is_collapsed {
+        hide_descendants(pid, children, layout, visited);
+        return;
+    }
+
     let next_prefix = if is_root {
         String::new()
     } else if is_last {
@@ -95,6 +244,7 @@ fn push_tree_layout(
     if let Some(list) = children.get(&pid) {
         let last_index = list.len().saturating_sub(1);
         for (idx, child) in list.iter().enumerate() {
+            layout.parents.insert(*child, pid);
             push_tree_layout(
                 *child,
                 &next_prefix,
@@ -102,9 +252,47 @@ fn push_tree_layout(
                 false,
                 children,
                 rows,
+                collapsed,
                 layout,
                 visited,
             );
         }
     }
 }
+
+fn hide_descendants(
+    pid: u32,
+    children: &HashMap<u32, Vec<u32>>,
+    layout: &mut TreeLayout,
+    visited: &mut HashSet<u32>,
+) {
+    let mut stack = children.get(&pid).cloned().unwrap_or_default();
+    while let Some(child) = stack.pop() {
+        if !visited.insert(child) {
+            continue;
+        }
+        layout.hidden.insert(child);
+        if let Some(list) = children.get(&child) {
+            stack.extend(list.iter().copied());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn subtree_follows_parent_chain() {
+        let parents = HashMap::from([
+            (1, None),
+            (10, Some(1)),
+            (11, Some(10)),
+            (12, Some(11)),
+            (20, Some(1)),
+        ]);
+        let subtree = subtree_pids(10, &parents);
+        assert_eq!(subtree, HashSet::from([10, 11, 12]));
+        assert_eq!(subtree_pids(20, &parents), HashSet::from([20]));
+    }
+}
@@ -1,3 +1,5 @@
+use std::time::{Duration, Instant};
+
 use ratatui::prelude::Rect;
 
 use crate::data::{SortDir, SortKey};
@@ -45,6 +47,120 @@ impl ProcessFilterType {
     }
 }
 
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ProcessGrouping {
+    #[default]
+    None,
+    /// Merge processes sharing an executable name into one row
+    ByName,
+}
+
+impl ProcessGrouping {
+    pub fn toggle(self) -> Self {
+        match self {
+            ProcessGrouping::None => ProcessGrouping::ByName,
+            ProcessGrouping::ByName => ProcessGrouping::None,
+        }
+    }
+}
+
+/// How much of the process list is recomputed on each `update_rows`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum UpdateStrategy {
+    /// Rebuild everything every time
+    #[default]
+    Full,
+    /// Reuse derived data (GPU usage map) while its inputs are unchanged
+    Incremental,
+}
+
+impl UpdateStrategy {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "full" => Some(UpdateStrategy::Full),
+            "incremental" => Some(UpdateStrategy::Incremental),
+            _ => None,
+        }
+    }
+}
+
+/// What PageUp/PageDown move in the process table (`[display] page_mode`)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PageMode {
+    /// Move the selection by a page
+    #[default]
+    Cursor,
+    /// Scroll the view by a page, keeping the selection on the same screen row
+    Viewport,
+}
+
+impl PageMode {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "cursor" => Some(PageMode::Cursor),
+            "viewport" => Some(PageMode::Viewport),
+            _ => None,
+        }
+    }
+}
+
+/// Order of the Container view (`[containers] sort`)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ContainerSort {
+    /// Busiest first
+    #[default]
+    Cpu,
+    /// Most recently started first, to spot restart loops
+    Uptime,
+}
+
+impl ContainerSort {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "cpu" => Some(ContainerSort::Cpu),
+            "uptime" => Some(ContainerSort::Uptime),
+            _ => None,
+        }
+    }
+}
+
+/// How the Disks tab labels each filesystem
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DiskLabelMode {
+    #[default]
+    MountPoint,
+    /// Backing device, e.g. /dev/nvme0n1p2
+    Device,
+}
+
+impl DiskLabelMode {
+    pub fn toggle(self) -> Self {
+        match self {
+            DiskLabelMode::MountPoint => DiskLabelMode::Device,
+            DiskLabelMode::Device => DiskLabelMode::MountPoint,
+        }
+    }
+}
+
+/// Order of the Temps tab
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TempsSort {
+    /// Hottest first
+    #[default]
+    Temperature,
+    /// Alphabetical by sensor label, stable while values fluctuate
+    Label,
+}
+
+impl TempsSort {
+    pub fn toggle(self) -> Self {
+        match self {
+            TempsSort::Temperature => TempsSort::Label,
+            TempsSort::Label => TempsSort::Temperature,
+        }
+    }
+}
+
 pub struct ConfirmKill {
     pub pid: u32,
     pub name: String,
@@ -52,6 +168,45 @@ pub struct ConfirmKill {
     pub mem_bytes: u64,
     pub status: String,
     pub start_time: u64,
+    /// (pid, start_time) of every member when terminating a grouped row
+    pub members: Vec<(u32, u64)>,
+    /// Terminating the user-marked set rather than a name group
+    pub marked: bool,
+}
+
+/// What a line of the process detail overlay shows
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DetailField {
+    Pid,
+    Name,
+    User,
+    Exe,
+    Cwd,
+    Cgroup,
+    /// CPUs the process may run on
+    Affinity,
+    Command,
+    /// One environment variable as "KEY=VALUE"
+    Env,
+}
+
+/// Process detail overlay with a line cursor for copying single values
+pub struct ProcessDetail {
+    pub pid: u32,
+    pub lines: Vec<(DetailField, String)>,
+    pub cursor: usize,
+}
+
+impl ProcessDetail {
+    pub fn move_cursor(&mut self, delta: isize) {
+        let last = self.lines.len().saturating_sub(1);
+        self.cursor = self.cursor.saturating_add_signed(delta).min(last);
+    }
+
+    /// Value of the line under the cursor, as copied to the clipboard.
+    pub fn current_value(&self) -> Option<&str> {
+        self.lines.get(self.cursor).map(|(_, value)| value.as_str())
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -154,6 +309,7 @@ pub enum SystemTab {
     Disks,
     Network,
     Temps,
+    Sensors,
 }
 
 impl SystemTab {
@@ -164,18 +320,20 @@ impl SystemTab {
             SystemTab::Memory => SystemTab::Disks,
             SystemTab::Disks => SystemTab::Network,
             SystemTab::Network => SystemTab::Temps,
-            SystemTab::Temps => SystemTab::Overview,
+            SystemTab::Temps => SystemTab::Sensors,
+            SystemTab::Sensors => SystemTab::Overview,
         }
     }
 
     pub fn prev(self) -> Self {
         match self {
-            SystemTab::Overview => SystemTab::Temps,
+            SystemTab::Overview => SystemTab::Sensors,
             SystemTab::Cpu => SystemTab::Overview,
             SystemTab::Memory => SystemTab::Cpu,
             SystemTab::Disks => SystemTab::Memory,
             SystemTab::Network => SystemTab::Disks,
             SystemTab::Temps => SystemTab::Network,
+            SystemTab::Sensors => SystemTab::Temps,
         }
     }
 }
@@ -187,28 +345,130 @@ pub enum SetupField {
     IconMode,
     LogoMode,
     LogoQuality,
+    ByteUnit,
+    TempUnit,
+    GpuPollRate,
+    /// An on/off option
+    Toggle(SetupToggle),
 }
 
 impl SetupField {
+    /// Fields in the order the setup screen lists them
+    pub const ALL: [SetupField; 19] = [
+        SetupField::Language,
+        SetupField::IconMode,
+        SetupField::LogoMode,
+        SetupField::LogoQuality,
+        SetupField::ByteUnit,
+        SetupField::TempUnit,
+        SetupField::GpuPollRate,
+        SetupField::Toggle(SetupToggle::ShowTopCpu),
+        SetupField::Toggle(SetupToggle::ShowCountdown),
+        SetupField::Toggle(SetupToggle::GpuCompact),
+        SetupField::Toggle(SetupToggle::OverviewAllGpus),
+        SetupField::Toggle(SetupToggle::ReduceMotion),
+        SetupField::Toggle(SetupToggle::Monochrome),
+        SetupField::Toggle(SetupToggle::ScriptNames),
+        SetupField::Toggle(SetupToggle::ShowPriority),
+        SetupField::Toggle(SetupToggle::ShowUnit),
+        SetupField::Toggle(SetupToggle::ShowSwap),
+        SetupField::Toggle(SetupToggle::ShowGpu),
+        SetupField::Toggle(SetupToggle::ConfirmQuit),
+    ];
+
+    pub fn index(self) -> usize {
+        Self::ALL
+            .iter()
+            .position(|field| *field == self)
+            .unwrap_or(0)
+    }
+
     pub fn next(self) -> Self {
-        match self {
-            SetupField::Language => SetupField::IconMode,
-            SetupField::IconMode => SetupField::LogoMode,
-            SetupField::LogoMode => SetupField::LogoQuality,
-            SetupField::LogoQuality => SetupField::Language,
-        }
+        Self::ALL[(self.index() + 1) % Self::ALL.len()]
     }
 
     pub fn prev(self) -> Self {
+        Self::ALL[(self.index() + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+}
+
+/// Boolean options editable from the setup screen
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SetupToggle {
+    ShowTopCpu,
+    ShowCountdown,
+    GpuCompact,
+    OverviewAllGpus,
+    ReduceMotion,
+    Monochrome,
+    ScriptNames,
+    ShowPriority,
+    ShowUnit,
+    ShowSwap,
+    ShowGpu,
+    ConfirmQuit,
+}
+
+impl SetupToggle {
+    /// Config section and key the option is saved under
+    pub fn config_key(self) -> (&'static str, &'static str) {
         match self {
-            SetupField::Language => SetupField::LogoQuality,
-            SetupField::IconMode => SetupField::Language,
-            SetupField::LogoMode => SetupField::IconMode,
-            SetupField::LogoQuality => SetupField::LogoMode,
+            SetupToggle::ShowTopCpu => ("display", "show_top_cpu"),
+            SetupToggle::ShowCountdown => ("display", "show_countdown"),
+            SetupToggle::GpuCompact => ("display", "gpu_compact"),
+            SetupToggle::OverviewAllGpus => ("display", "overview_all_gpus"),
+            SetupToggle::ReduceMotion => ("display", "reduce_motion"),
+            SetupToggle::Monochrome => ("display", "monochrome"),
+            SetupToggle::ScriptNames => ("processes", "script_names"),
+            SetupToggle::ShowPriority => ("processes", "show_priority"),
+            SetupToggle::ShowUnit => ("processes", "show_unit"),
+            SetupToggle::ShowSwap => ("processes", "show_swap"),
+            SetupToggle::ShowGpu => ("processes", "show_gpu"),
+            SetupToggle::ConfirmQuit => ("general", "confirm_quit"),
         }
     }
 }
 
+/// Render-loop timings for the debug overlay, measured by the event loop
+#[derive(Default)]
+pub struct PerfStats {
+    pub draw: Duration,
+    pub refresh: Duration,
+    /// From reading an input event to the end of the next frame
+    pub latency: Duration,
+    pub fps: f32,
+    frames: u32,
+    window_start: Option<Instant>,
+}
+
+impl PerfStats {
+    /// Record one drawn frame; FPS is averaged over one-second windows.
+    pub fn record_frame(&mut self, draw: Duration) {
+        self.draw = draw;
+        self.frames += 1;
+        let now = Instant::now();
+        let window_start = *self.window_start.get_or_insert(now);
+        let elapsed = now.saturating_duration_since(window_start);
+        if elapsed >= Duration::from_secs(1) {
+            self.fps = self.frames as f32 / elapsed.as_secs_f32();
+            self.frames = 0;
+            self.window_start = Some(now);
+        }
+    }
+}
+
+/// Result of one headless [`App::refresh_timed`](super::App::refresh_timed) cycle
+#[derive(Clone, Copy, Debug)]
+pub struct RefreshStats {
+    pub elapsed: Duration,
+    /// Processes known to sysinfo after the refresh
+    pub processes: usize,
+    /// Rows left after filtering, grouping and `max_rows`
+    pub rows: usize,
+    /// Container rows; only refreshed in the Container view or while filtering by one
+    pub containers: usize,
+}
+
 #[derive(Clone, Copy)]
 pub struct HeaderRegion {
     pub key: SortKey,
@@ -236,14 +496,20 @@ pub struct SystemOverviewSnapshot {
     pub uptime_line: String,
     pub board_line: String,
     pub cpu_line: String,
-    pub gpu_line: String,
+    /// Selected GPU, or one line per GPU with `overview_all_gpus`
+    pub gpu_lines: Vec<String>,
+    pub vram_line: String,
     pub mem_line: String,
     pub disk_lines: Vec<String>,
+    /// Holds the WSL note instead of the display summary under WSL
     pub display_line: String,
-    pub mouse_line: String,
-    pub de_line: String,
+    /// `None` under WSL, where input devices belong to Windows
+    pub mouse_line: Option<String>,
+    /// `None` under WSL, where the desktop belongs to Windows
+    pub de_line: Option<String>,
     pub wm_line: String,
     pub shell_line: String,
     pub terminal_line: String,
     pub package_line: String,
+    pub package_pending: bool,
 }
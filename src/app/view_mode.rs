@@ -18,6 +18,18 @@ impl ViewMode {
             ViewMode::Container => "Containers",
         }
     }
+
+    /// Parse a view name as used by the number-key shortcuts
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "overview" => Some(ViewMode::Overview),
+            "processes" => Some(ViewMode::Processes),
+            "gpu" => Some(ViewMode::GpuFocus),
+            "systeminfo" | "system" => Some(ViewMode::SystemInfo),
+            "container" | "containers" => Some(ViewMode::Container),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
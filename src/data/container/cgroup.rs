@@ -15,6 +15,137 @@ pub fn container_key_for_pid(pid: u32) -> Option<ContainerKey> {
     }
 }
 
+/// Resolve the cgroup v2 directory of the container `key` that `pid` belongs to.
+///
+/// Processes may sit in a nested cgroup below the container (e.g. systemd inside
+/// the container), so the path is cut at the segment carrying the container id.
+pub fn container_cgroup_dir(pid: u32, key: &ContainerKey) -> Option<String> {
+    #[cfg(target_os = "linux")]
+    {
+        let path = format!("/proc/{pid}/cgroup");
+        let contents = std::fs::read_to_string(path).ok()?;
+        let unified = parse_unified_path(&contents)?;
+        let container = container_path_prefix(unified, &key.id)?;
+        Some(format!("/sys/fs/cgroup{container}"))
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    {
+        let _ = (pid, key);
+        None
+    }
+}
+
+/// Raw cgroup path of `pid`: the cgroup v2 path, or on v1 the systemd
+/// hierarchy (falling back to the first controller listed).
+pub fn cgroup_path_for_pid(pid: u32) -> Option<String> {
+    #[cfg(target_os = "linux")]
+    {
+        let path = format!("/proc/{pid}/cgroup");
+        let contents = std::fs::read_to_string(path).ok()?;
+        parse_raw_cgroup_path(&contents).map(str::to_string)
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    {
+        let _ = pid;
+        None
+    }
+}
+
+/// Innermost systemd unit (`.service` / `.scope`) in the cgroup of `pid`.
+pub fn systemd_unit_for_pid(pid: u32) -> Option<String> {
+    cgroup_path_for_pid(pid)
+        .as_deref()
+        .and_then(systemd_unit)
+        .map(str::to_string)
+}
+
+/// Innermost `.service` / `.scope` segment of a cgroup path, e.g.
+/// "nginx.service" from "/system.slice/nginx.service".
+fn systemd_unit(path: &str) -> Option<&str> {
+    path.rsplit('/')
+        .find(|segment| segment.ends_with(".service") || segment.ends_with(".scope"))
+}
+
+/// Read `memory.current` and `memory.max` from a cgroup v2 directory.
+///
+/// Returns the current usage and the limit (`None` when unlimited).
+pub fn cgroup_memory(dir: &str) -> Option<(u64, Option<u64>)> {
+    let current = std::fs::read_to_string(format!("{dir}/memory.current")).ok()?;
+    let current = current.trim().parse::<u64>().ok()?;
+    let limit = std::fs::read_to_string(format!("{dir}/memory.max"))
+        .ok()
+        .and_then(|value| parse_memory_max(&value));
+    Some((current, limit))
+}
+
+/// Read the CPU quota from `cpu.max` in a cgroup v2 directory, in cores.
+pub fn cgroup_cpu_limit(dir: &str) -> Option<f32> {
+    let value = std::fs::read_to_string(format!("{dir}/cpu.max")).ok()?;
+    parse_cpu_max(&value)
+}
+
+fn parse_unified_path(contents: &str) -> Option<&str> {
+    contents
+        .lines()
+        .find_map(|line| line.strip_prefix("0::"))
+        .map(str::trim)
+        .filter(|path| !path.is_empty())
+}
+
+fn parse_raw_cgroup_path(contents: &str) -> Option<&str> {
+    let paths = contents
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.splitn(3, ':');
+            let (_, controllers, path) = (fields.next()?, fields.next()?, fields.next()?);
+            Some((controllers, path.trim()))
+        })
+        .filter(|(_, path)| !path.is_empty())
+        .collect::<Vec<_>>();
+    paths
+        .iter()
+        .find(|(controllers, _)| controllers.is_empty())
+        .or_else(|| {
+            paths
+                .iter()
+                .find(|(controllers, _)| *controllers == "name=systemd")
+        })
+        .or_else(|| paths.first())
+        .map(|(_, path)| *path)
+}
+
+fn container_path_prefix<'a>(path: &'a str, id: &str) -> Option<&'a str> {
+    let start = path.find(id)?;
+    let end = path[start..]
+        .find('/')
+        .map(|offset| start + offset)
+        .unwrap_or(path.len());
+    Some(&path[..end])
+}
+
+fn parse_memory_max(value: &str) -> Option<u64> {
+    match value.trim() {
+        "max" => None,
+        value => value.parse::<u64>().ok(),
+    }
+}
+
+/// Parse `cpu.max` ("<quota> <period>" or "max <period>") into cores.
+fn parse_cpu_max(value: &str) -> Option<f32> {
+    let mut fields = value.split_whitespace();
+    let quota = fields.next()?.parse::<u64>().ok()?;
+    let period = fields
+        .next()
+        .and_then(|period| period.parse::<u64>().ok())
+        .unwrap_or(100_000);
+    if period == 0 {
+        return None;
+    }
+    Some(quota as f32 / period as f32)
+}
+
 fn parse_cgroup(contents: &str) -> Option<ContainerKey> {
     for line in contents.lines() {
         if let Some(path) = line.splitn(3, ':').nth(2)
@@ -167,6 +298,36 @@ fn pod_segment(segment: &str) -> Option<String> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn raw_cgroup_path_prefers_unified() {
+        let v2 = "0::/system.slice/nginx.service\n";
+        assert_eq!(
+            parse_raw_cgroup_path(v2),
+            Some("/system.slice/nginx.service")
+        );
+        let v1 = "12:cpu,cpuacct:/user.slice\n1:name=systemd:/user.slice/session-2.scope\n";
+        assert_eq!(
+            parse_raw_cgroup_path(v1),
+            Some("/user.slice/session-2.scope")
+        );
+        assert_eq!(parse_raw_cgroup_path(""), None);
+    }
+
+    #[test]
+    fn systemd_unit_is_innermost() {
+        assert_eq!(
+            systemd_unit("/system.slice/nginx.service"),
+            Some("nginx.service")
+        );
+        assert_eq!(
+            systemd_unit(
+                "/user.slice/user-1000.slice/user@1000.service/app.slice/app-firefox-42.scope"
+            ),
+            Some("app-firefox-42.scope")
+        );
+        assert_eq!(systemd_unit("/user.slice/user-1000.slice"), None);
+    }
+
     #[test]
     fn parse_docker_scope() {
         let input = "0::/user.slice/user-1000.slice/user@1000.service/app.slice/docker-0123456789abcdef.scope";
@@ -199,6 +360,30 @@ mod tests {
         assert_eq!(key.id, "bbbbbbbbbbbbbbbb");
     }
 
+    #[test]
+    fn unified_path_trimmed_to_container() {
+        let input = "0::/system.slice/docker-0123456789abcdef.scope/init.scope\n";
+        let path = parse_unified_path(input).unwrap();
+        assert_eq!(
+            container_path_prefix(path, "0123456789abcdef"),
+            Some("/system.slice/docker-0123456789abcdef.scope")
+        );
+        assert_eq!(parse_unified_path("1:name=systemd:/docker/abc\n"), None);
+    }
+
+    #[test]
+    fn parse_memory_max_values() {
+        assert_eq!(parse_memory_max("max\n"), None);
+        assert_eq!(parse_memory_max("536870912\n"), Some(536_870_912));
+    }
+
+    #[test]
+    fn parse_cpu_max_values() {
+        assert_eq!(parse_cpu_max("max 100000\n"), None);
+        assert_eq!(parse_cpu_max("150000 100000\n"), Some(1.5));
+        assert_eq!(parse_cpu_max("50000\n"), Some(0.5));
+    }
+
     #[test]
     fn parse_kube_crio_scope() {
         let input = "0::/kubepods.slice/kubepods-pod123.slice/crio-cccccccccccccccc.scope";
@@ -1,7 +1,16 @@
 mod cgroup;
+mod names;
 mod net;
+mod runtime;
 mod types;
 
-pub use cgroup::container_key_for_pid;
+pub use cgroup::{
+    cgroup_cpu_limit, cgroup_memory, cgroup_path_for_pid, container_cgroup_dir,
+    container_key_for_pid, systemd_unit_for_pid,
+};
+pub use names::container_name;
 pub use net::{net_sample_for_pid, netns_id_for_pid};
-pub use types::{ContainerKey, ContainerRow, ContainerRuntime, NetSample};
+pub use runtime::{RuntimeContainer, start_runtime_monitor};
+pub use types::{
+    ContainerKey, ContainerRow, ContainerRuntime, ContainerState, ContainerTotals, NetSample,
+};
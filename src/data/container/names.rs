@@ -0,0 +1,77 @@
+use std::path::PathBuf;
+
+use serde_json::Value;
+
+use super::types::{ContainerKey, ContainerRuntime};
+
+/// Human-readable container name from the runtime's state files.
+///
+/// Returns `None` when the runtime is not Docker/Podman or no readable file
+/// knows the ID; the API socket listing fills those in, and callers fall back
+/// to the short ID until then.
+pub fn container_name(key: &ContainerKey) -> Option<String> {
+    match key.runtime {
+        ContainerRuntime::Docker => docker_config_name(&key.id),
+        ContainerRuntime::Podman => podman_store_name(&key.id),
+        _ => None,
+    }
+}
+
+/// `/var/lib/docker/containers/<id>/config.v2.json` (usually root-only)
+fn docker_config_name(id: &str) -> Option<String> {
+    let path = format!("/var/lib/docker/containers/{id}/config.v2.json");
+    let contents = std::fs::read_to_string(path).ok()?;
+    inspect_name(&contents)
+}
+
+/// Podman's container store lists every container with its names
+fn podman_store_name(id: &str) -> Option<String> {
+    let mut stores = vec![PathBuf::from("/var/lib/containers/storage")];
+    if let Some(data) = dirs::data_dir() {
+        stores.push(data.join("containers/storage"));
+    }
+    stores.into_iter().find_map(|store| {
+        let path = store.join("overlay-containers/containers.json");
+        let contents = std::fs::read_to_string(path).ok()?;
+        podman_store_lookup(&contents, id)
+    })
+}
+
+fn podman_store_lookup(contents: &str, id: &str) -> Option<String> {
+    let containers: Value = serde_json::from_str(contents).ok()?;
+    let entry = containers
+        .as_array()?
+        .iter()
+        .find(|entry| entry.get("id").and_then(Value::as_str) == Some(id))?;
+    let name = entry.get("names")?.as_array()?.first()?.as_str()?;
+    (!name.is_empty()).then(|| name.to_string())
+}
+
+/// "Name" from a Docker-style inspect document ("/web" -> "web")
+fn inspect_name(contents: &str) -> Option<String> {
+    let value: Value = serde_json::from_str(contents).ok()?;
+    let name = value.get("Name")?.as_str()?.trim_start_matches('/');
+    (!name.is_empty()).then(|| name.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inspect_name_strips_slash() {
+        let json = r#"{"ID":"abc","Name":"/web","Config":{"Hostname":"abc"}}"#;
+        assert_eq!(inspect_name(json).as_deref(), Some("web"));
+        assert_eq!(inspect_name(r#"{"Name":""}"#), None);
+    }
+
+    #[test]
+    fn podman_store_finds_id() {
+        let json = r#"[
+            {"id":"aaa","names":["db"]},
+            {"id":"bbb","names":["web","alias"]}
+        ]"#;
+        assert_eq!(podman_store_lookup(json, "bbb").as_deref(), Some("web"));
+        assert_eq!(podman_store_lookup(json, "ccc"), None);
+    }
+}
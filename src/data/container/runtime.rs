@@ -0,0 +1,140 @@
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use serde_json::Value;
+
+use super::types::{ContainerKey, ContainerRuntime, ContainerState};
+
+/// A container as reported by the runtime API, running or not
+#[derive(Clone, Debug)]
+pub struct RuntimeContainer {
+    pub key: ContainerKey,
+    pub name: Option<String>,
+    pub state: ContainerState,
+}
+
+/// Spawns a thread that lists runtime containers every `interval`.
+///
+/// Socket queries can block for up to their timeout per runtime, so they stay
+/// off the UI thread. The thread exits once the receiver is dropped.
+pub fn start_runtime_monitor(interval: Duration) -> mpsc::Receiver<Vec<RuntimeContainer>> {
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        while tx.send(list_runtime_containers()).is_ok() {
+            thread::sleep(interval);
+        }
+    });
+    rx
+}
+
+/// Every container known to the Docker and Podman API sockets we can reach.
+///
+/// Empty when no socket is accessible; PID-based detection still covers
+/// running containers in that case.
+fn list_runtime_containers() -> Vec<RuntimeContainer> {
+    let mut containers = Vec::new();
+    for runtime in [ContainerRuntime::Docker, ContainerRuntime::Podman] {
+        let listed = socket_candidates(runtime)
+            .into_iter()
+            .find_map(|socket| socket_get(&socket, "/containers/json?all=true"));
+        if let Some(body) = listed {
+            containers.extend(parse_container_list(&body, runtime));
+        }
+    }
+    containers
+}
+
+fn socket_candidates(runtime: ContainerRuntime) -> Vec<PathBuf> {
+    match runtime {
+        ContainerRuntime::Docker => vec![PathBuf::from("/var/run/docker.sock")],
+        ContainerRuntime::Podman => {
+            let mut sockets = Vec::new();
+            if let Some(runtime_dir) = dirs::runtime_dir() {
+                sockets.push(runtime_dir.join("podman/podman.sock"));
+            }
+            sockets.push(PathBuf::from("/run/podman/podman.sock"));
+            sockets
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// Body of a successful `GET` on the API socket
+#[cfg(unix)]
+fn socket_get(socket: &Path, path: &str) -> Option<String> {
+    use std::io::{Read, Write};
+    use std::os::unix::net::UnixStream;
+    use std::time::Duration;
+
+    const SOCKET_TIMEOUT: Duration = Duration::from_millis(200);
+
+    let mut stream = UnixStream::connect(socket).ok()?;
+    stream.set_read_timeout(Some(SOCKET_TIMEOUT)).ok()?;
+    stream.set_write_timeout(Some(SOCKET_TIMEOUT)).ok()?;
+    // HTTP/1.0 keeps the response unchunked and closes the connection
+    let request = format!("GET {path} HTTP/1.0\r\nHost: localhost\r\n\r\n");
+    stream.write_all(request.as_bytes()).ok()?;
+    let mut response = String::new();
+    stream.read_to_string(&mut response).ok()?;
+    let (head, body) = response.split_once("\r\n\r\n")?;
+    if !head.lines().next()?.contains(" 200 ") {
+        return None;
+    }
+    Some(body.to_string())
+}
+
+#[cfg(not(unix))]
+fn socket_get(_socket: &Path, _path: &str) -> Option<String> {
+    None
+}
+
+fn parse_container_list(body: &str, runtime: ContainerRuntime) -> Vec<RuntimeContainer> {
+    let Ok(Value::Array(entries)) = serde_json::from_str::<Value>(body) else {
+        return Vec::new();
+    };
+    entries
+        .iter()
+        .filter_map(|entry| {
+            let id = entry.get("Id")?.as_str()?;
+            let name = entry
+                .get("Names")
+                .and_then(Value::as_array)
+                .and_then(|names| names.first())
+                .and_then(Value::as_str)
+                .map(|name| name.trim_start_matches('/').to_string())
+                .filter(|name| !name.is_empty());
+            let state = ContainerState::parse(entry.get("State")?.as_str()?);
+            Some(RuntimeContainer {
+                key: ContainerKey {
+                    runtime,
+                    id: id.to_string(),
+                },
+                name,
+                state,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_container_list() {
+        let body = r#"[
+            {"Id":"aaa","Names":["/web"],"State":"running"},
+            {"Id":"bbb","Names":["/db"],"State":"exited"},
+            {"Id":"ccc","Names":[],"State":"paused"}
+        ]"#;
+        let list = parse_container_list(body, ContainerRuntime::Docker);
+        assert_eq!(list.len(), 3);
+        assert_eq!(list[0].name.as_deref(), Some("web"));
+        assert_eq!(list[1].state, ContainerState::Exited);
+        assert_eq!(list[2].state, ContainerState::Paused);
+        assert_eq!(list[2].name, None);
+        assert!(parse_container_list("{}", ContainerRuntime::Docker).is_empty());
+    }
+}
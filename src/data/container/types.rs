@@ -19,6 +19,26 @@ impl ContainerRuntime {
     }
 }
 
+/// Lifecycle state reported by the runtime API
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ContainerState {
+    #[default]
+    Running,
+    Paused,
+    /// Exited, created or otherwise not running
+    Exited,
+}
+
+impl ContainerState {
+    pub fn parse(value: &str) -> Self {
+        match value.to_ascii_lowercase().as_str() {
+            "running" | "restarting" => ContainerState::Running,
+            "paused" => ContainerState::Paused,
+            _ => ContainerState::Exited,
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct ContainerKey {
     pub runtime: ContainerRuntime,
@@ -29,6 +49,14 @@ impl ContainerKey {
     pub fn label(&self) -> String {
         format!("{}:{}", self.runtime.label(), short_id(&self.id))
     }
+
+    /// Like [`label`](Self::label), with the resolved name instead of the ID
+    pub fn named_label(&self, name: Option<&str>) -> String {
+        match name {
+            Some(name) => format!("{}:{name}", self.runtime.label()),
+            None => self.label(),
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -36,16 +64,31 @@ pub struct ContainerRow {
     pub key: ContainerKey,
     pub label: String,
     pub cpu: f32,
+    /// cgroup CPU quota (`cpu.max`) in cores, when one is set
+    pub cpu_limit: Option<f32>,
     pub mem_bytes: u64,
+    /// cgroup memory limit (`memory.max`), when one is set
+    pub mem_limit: Option<u64>,
     pub proc_count: usize,
     pub net_bytes_per_sec: Option<u64>,
+    /// Run time of the oldest process in the container
+    pub uptime_secs: u64,
+    pub state: ContainerState,
 }
 
 impl ContainerRow {
+    /// Share of the CPU quota in use (1.0 = at the limit)
+    pub fn cpu_quota_usage(&self) -> Option<f32> {
+        let limit = self.cpu_limit.filter(|limit| *limit > 0.0)?;
+        Some(self.cpu / 100.0 / limit)
+    }
+
     pub fn new(
         key: ContainerKey,
         cpu: f32,
+        cpu_limit: Option<f32>,
         mem_bytes: u64,
+        mem_limit: Option<u64>,
         proc_count: usize,
         net_bytes_per_sec: Option<u64>,
     ) -> Self {
@@ -54,10 +97,44 @@ impl ContainerRow {
             key,
             label,
             cpu,
+            cpu_limit,
             mem_bytes,
+            mem_limit,
             proc_count,
             net_bytes_per_sec,
+            uptime_secs: 0,
+            state: ContainerState::Running,
+        }
+    }
+}
+
+/// Aggregate load of all detected containers
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ContainerTotals {
+    /// Running containers only
+    pub count: usize,
+    pub cpu: f32,
+    pub mem_bytes: u64,
+    /// Sum of the known per-container rates; `None` when none were sampled
+    pub net_bytes_per_sec: Option<u64>,
+}
+
+impl ContainerTotals {
+    pub fn from_rows(rows: &[ContainerRow]) -> Self {
+        let mut totals = Self::default();
+        for row in rows
+            .iter()
+            .filter(|row| row.state == ContainerState::Running)
+        {
+            totals.count += 1;
+            totals.cpu += row.cpu;
+            totals.mem_bytes = totals.mem_bytes.saturating_add(row.mem_bytes);
+            if let Some(rate) = row.net_bytes_per_sec {
+                totals.net_bytes_per_sec =
+                    Some(totals.net_bytes_per_sec.unwrap_or(0).saturating_add(rate));
+            }
         }
+        totals
     }
 }
 
@@ -70,3 +147,46 @@ pub struct NetSample {
 fn short_id(value: &str) -> String {
     value.chars().take(12).collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(id: &str, cpu: f32, mem_bytes: u64, net: Option<u64>) -> ContainerRow {
+        let key = ContainerKey {
+            runtime: ContainerRuntime::Docker,
+            id: id.to_string(),
+        };
+        ContainerRow::new(key, cpu, None, mem_bytes, None, 1, net)
+    }
+
+    #[test]
+    fn totals_sum_rows() {
+        let rows = [
+            row("a", 12.5, 1_000, Some(300)),
+            row("b", 7.5, 2_000, None),
+            row("c", 0.0, 500, Some(200)),
+        ];
+        let totals = ContainerTotals::from_rows(&rows);
+        assert_eq!(totals.count, 3);
+        assert_eq!(totals.cpu, 20.0);
+        assert_eq!(totals.mem_bytes, 3_500);
+        assert_eq!(totals.net_bytes_per_sec, Some(500));
+    }
+
+    #[test]
+    fn totals_without_net_samples() {
+        let totals = ContainerTotals::from_rows(&[row("a", 1.0, 10, None)]);
+        assert_eq!(totals.net_bytes_per_sec, None);
+        assert_eq!(ContainerTotals::from_rows(&[]), ContainerTotals::default());
+    }
+
+    #[test]
+    fn totals_skip_stopped_containers() {
+        let mut stopped = row("b", 0.0, 0, None);
+        stopped.state = ContainerState::parse("exited");
+        let totals = ContainerTotals::from_rows(&[row("a", 5.0, 100, None), stopped]);
+        assert_eq!(totals.count, 1);
+        assert_eq!(ContainerState::parse("Paused"), ContainerState::Paused);
+    }
+}
@@ -0,0 +1,49 @@
+/// Scheduler task counts from field 4 of `/proc/loadavg` ("2/1043")
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TaskCounts {
+    /// Runnable tasks (threads) at the moment of reading
+    pub running: u32,
+    /// All tasks (threads) on the system
+    pub total: u32,
+}
+
+impl TaskCounts {
+    /// Read the current counts; `None` off Linux or when the file is unreadable
+    #[cfg(target_os = "linux")]
+    pub fn read() -> Option<Self> {
+        let content = std::fs::read_to_string("/proc/loadavg").ok()?;
+        parse_loadavg(&content)
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub fn read() -> Option<Self> {
+        None
+    }
+}
+
+/// "0.52 0.58 0.59 2/1043 12345": three averages, running/total, last PID
+fn parse_loadavg(content: &str) -> Option<TaskCounts> {
+    let (running, total) = content.split_whitespace().nth(3)?.split_once('/')?;
+    Some(TaskCounts {
+        running: running.parse().ok()?,
+        total: total.parse().ok()?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_task_counts() {
+        assert_eq!(
+            parse_loadavg("0.52 0.58 0.59 2/1043 12345\n"),
+            Some(TaskCounts {
+                running: 2,
+                total: 1043
+            })
+        );
+        assert_eq!(parse_loadavg("0.52 0.58 0.59"), None);
+        assert_eq!(parse_loadavg("0.52 0.58 0.59 x/1043 1"), None);
+    }
+}
@@ -1,15 +1,22 @@
 mod cache;
 mod cpuinfo;
 mod database;
+mod loadavg;
+mod psi;
+mod vulnerabilities;
 
 pub use cache::CpuCaches;
 pub use cpuinfo::CpuDetails;
 pub use database::{CpuCodename, lookup_cpu_codename};
+pub use loadavg::TaskCounts;
+pub use psi::{Pressure, PressureStall, PsiResource};
+pub use vulnerabilities::{MitigationLevel, Mitigations, VulnerabilityStatus};
 
 use std::sync::OnceLock;
 
 static CPU_INFO: OnceLock<CpuDetails> = OnceLock::new();
 static CPU_CACHES: OnceLock<CpuCaches> = OnceLock::new();
+static CPU_MITIGATIONS: OnceLock<Option<Mitigations>> = OnceLock::new();
 
 /// Get cached CPU details (parsed once from /proc/cpuinfo)
 pub fn cpu_details() -> &'static CpuDetails {
@@ -20,3 +27,24 @@ pub fn cpu_details() -> &'static CpuDetails {
 pub fn cpu_caches() -> &'static CpuCaches {
     CPU_CACHES.get_or_init(CpuCaches::read)
 }
+
+/// Get cached vulnerability mitigation status (read once from /sys)
+pub fn cpu_mitigations() -> Option<&'static Mitigations> {
+    CPU_MITIGATIONS.get_or_init(Mitigations::read).as_ref()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mitigations_read_once() {
+        let first = cpu_mitigations();
+        assert!(CPU_MITIGATIONS.get().is_some());
+        let second = cpu_mitigations();
+        match (first, second) {
+            (Some(first), Some(second)) => assert!(std::ptr::eq(first, second)),
+            (first, second) => assert_eq!(first.is_none(), second.is_none()),
+        }
+    }
+}
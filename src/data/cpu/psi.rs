@@ -0,0 +1,111 @@
+use std::fs;
+
+/// Resource tracked by Linux pressure stall information (/proc/pressure)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PsiResource {
+    Cpu,
+    Memory,
+    Io,
+}
+
+impl PsiResource {
+    fn path(self) -> &'static str {
+        match self {
+            PsiResource::Cpu => "/proc/pressure/cpu",
+            PsiResource::Memory => "/proc/pressure/memory",
+            PsiResource::Io => "/proc/pressure/io",
+        }
+    }
+}
+
+/// Stall percentages averaged over the last 10 seconds
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Pressure {
+    /// Share of time at least one task was stalled
+    pub some_avg10: f32,
+    /// Share of time all non-idle tasks were stalled (absent for CPU on older kernels)
+    pub full_avg10: Option<f32>,
+}
+
+impl Pressure {
+    /// Read PSI for a resource; `None` when the kernel doesn't expose it (< 4.20 or psi=0)
+    pub fn read(resource: PsiResource) -> Option<Self> {
+        let content = fs::read_to_string(resource.path()).ok()?;
+        parse_pressure(&content)
+    }
+
+    /// Format for display (e.g., "some 2.1% · full 0.4%")
+    pub fn format(&self) -> String {
+        match self.full_avg10 {
+            Some(full) => format!("some {:.1}% · full {full:.1}%", self.some_avg10),
+            None => format!("some {:.1}%", self.some_avg10),
+        }
+    }
+}
+
+/// One sample of every PSI resource, taken together on refresh
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PressureStall {
+    pub cpu: Option<Pressure>,
+    pub memory: Option<Pressure>,
+    pub io: Option<Pressure>,
+}
+
+impl PressureStall {
+    pub fn read() -> Self {
+        Self {
+            cpu: Pressure::read(PsiResource::Cpu),
+            memory: Pressure::read(PsiResource::Memory),
+            io: Pressure::read(PsiResource::Io),
+        }
+    }
+}
+
+fn parse_pressure(content: &str) -> Option<Pressure> {
+    let mut some = None;
+    let mut full = None;
+    for line in content.lines() {
+        let mut fields = line.split_whitespace();
+        let kind = fields.next();
+        let avg10 = fields
+            .find_map(|field| field.strip_prefix("avg10="))
+            .and_then(|value| value.parse::<f32>().ok());
+        match kind {
+            Some("some") => some = avg10,
+            Some("full") => full = avg10,
+            _ => {}
+        }
+    }
+    Some(Pressure {
+        some_avg10: some?,
+        full_avg10: full,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_memory_pressure() {
+        let content = "some avg10=2.10 avg60=1.05 avg300=0.30 total=123456\n\
+                       full avg10=0.40 avg60=0.20 avg300=0.05 total=23456\n";
+        let pressure = parse_pressure(content).unwrap();
+        assert_eq!(pressure.some_avg10, 2.10);
+        assert_eq!(pressure.full_avg10, Some(0.40));
+        assert_eq!(pressure.format(), "some 2.1% · full 0.4%");
+    }
+
+    #[test]
+    fn parse_cpu_pressure_without_full() {
+        let content = "some avg10=0.00 avg60=0.00 avg300=0.00 total=0\n";
+        let pressure = parse_pressure(content).unwrap();
+        assert_eq!(pressure.full_avg10, None);
+        assert_eq!(pressure.format(), "some 0.0%");
+    }
+
+    #[test]
+    fn parse_empty_pressure() {
+        assert_eq!(parse_pressure(""), None);
+    }
+}
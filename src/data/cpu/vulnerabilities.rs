@@ -0,0 +1,148 @@
+use std::fs;
+use std::path::Path;
+
+const VULNERABILITIES_DIR: &str = "/sys/devices/system/cpu/vulnerabilities";
+
+/// Kernel verdict for one entry in `/sys/devices/system/cpu/vulnerabilities`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VulnerabilityStatus {
+    NotAffected,
+    Mitigated,
+    Vulnerable,
+    /// "Unknown: ..." or text we do not recognise
+    Unknown,
+}
+
+impl VulnerabilityStatus {
+    /// Classify the first word(s) of a vulnerability file
+    pub fn classify(content: &str) -> Self {
+        let content = content.trim().to_ascii_lowercase();
+        if content.starts_with("not affected") {
+            Self::NotAffected
+        } else if content.starts_with("mitigation") {
+            Self::Mitigated
+        } else if content.starts_with("vulnerable") || content.starts_with("processor vulnerable") {
+            Self::Vulnerable
+        } else {
+            Self::Unknown
+        }
+    }
+}
+
+/// Overall state reported as "mitigations: all / partial / off"
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MitigationLevel {
+    /// Every affecting vulnerability is mitigated (or none affect this CPU)
+    All,
+    Partial,
+    /// Every affecting vulnerability is left open
+    Off,
+}
+
+/// Summary of all vulnerability files
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Mitigations {
+    pub mitigated: u32,
+    pub not_affected: u32,
+    pub unknown: u32,
+    /// Names of the vulnerabilities reported as "Vulnerable", sorted
+    pub vulnerable: Vec<String>,
+}
+
+impl Mitigations {
+    /// Read the sysfs entries; `None` when the directory is missing or empty
+    pub fn read() -> Option<Self> {
+        let mut entries: Vec<(String, String)> = fs::read_dir(Path::new(VULNERABILITIES_DIR))
+            .ok()?
+            .flatten()
+            .filter_map(|entry| {
+                let name = entry.file_name().to_string_lossy().into_owned();
+                let content = fs::read_to_string(entry.path()).ok()?;
+                Some((name, content))
+            })
+            .collect();
+        entries.sort();
+        Self::from_entries(
+            entries
+                .iter()
+                .map(|(name, content)| (name.as_str(), content.as_str())),
+        )
+    }
+
+    fn from_entries<'a>(entries: impl IntoIterator<Item = (&'a str, &'a str)>) -> Option<Self> {
+        let mut summary = Self::default();
+        let mut seen = false;
+        for (name, content) in entries {
+            seen = true;
+            match VulnerabilityStatus::classify(content) {
+                VulnerabilityStatus::NotAffected => summary.not_affected += 1,
+                VulnerabilityStatus::Mitigated => summary.mitigated += 1,
+                VulnerabilityStatus::Vulnerable => summary.vulnerable.push(name.to_string()),
+                VulnerabilityStatus::Unknown => summary.unknown += 1,
+            }
+        }
+        seen.then_some(summary)
+    }
+
+    /// Vulnerabilities that apply to this CPU, mitigated or not
+    pub fn affected(&self) -> u32 {
+        self.mitigated + self.vulnerable.len() as u32
+    }
+
+    pub fn level(&self) -> MitigationLevel {
+        match (self.mitigated, self.vulnerable.len()) {
+            (_, 0) => MitigationLevel::All,
+            (0, _) => MitigationLevel::Off,
+            _ => MitigationLevel::Partial,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classify_statuses() {
+        assert_eq!(
+            VulnerabilityStatus::classify("Not affected\n"),
+            VulnerabilityStatus::NotAffected
+        );
+        assert_eq!(
+            VulnerabilityStatus::classify("Mitigation: PTI"),
+            VulnerabilityStatus::Mitigated
+        );
+        assert_eq!(
+            VulnerabilityStatus::classify("Vulnerable: Clear CPU buffers attempted, no microcode"),
+            VulnerabilityStatus::Vulnerable
+        );
+        assert_eq!(
+            VulnerabilityStatus::classify("Processor vulnerable"),
+            VulnerabilityStatus::Vulnerable
+        );
+        assert_eq!(
+            VulnerabilityStatus::classify("Unknown: No mitigations"),
+            VulnerabilityStatus::Unknown
+        );
+    }
+
+    #[test]
+    fn summary_levels() {
+        let partial = Mitigations::from_entries([
+            ("meltdown", "Mitigation: PTI"),
+            ("mds", "Vulnerable; SMT vulnerable"),
+            ("l1tf", "Not affected"),
+        ])
+        .unwrap();
+        assert_eq!(partial.level(), MitigationLevel::Partial);
+        assert_eq!(partial.affected(), 2);
+        assert_eq!(partial.vulnerable, vec!["mds".to_string()]);
+
+        let off = Mitigations::from_entries([("mds", "Vulnerable"), ("l1tf", "Not affected")]);
+        assert_eq!(off.unwrap().level(), MitigationLevel::Off);
+
+        let clean = Mitigations::from_entries([("l1tf", "Not affected")]);
+        assert_eq!(clean.unwrap().level(), MitigationLevel::All);
+        assert_eq!(Mitigations::from_entries([]), None);
+    }
+}
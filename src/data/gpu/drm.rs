@@ -3,7 +3,7 @@ use std::fs;
 use std::path::{Path, PathBuf};
 use std::time::{Duration, Instant};
 
-use super::types::GpuProcessUsage;
+use super::types::{GpuProcessSource, GpuProcessUsage};
 
 const MIN_SAMPLE_INTERVAL: Duration = Duration::from_millis(500);
 
@@ -59,6 +59,7 @@ impl DrmProcessTracker {
                 enc_pct: None,
                 dec_pct: None,
                 fb_mb: bytes_to_mb(counters.preferred_mem_bytes()),
+                sources: vec![GpuProcessSource::Drm],
             };
 
             if let Some(interval_ns) = interval_ns
@@ -12,7 +12,8 @@ pub use provider::{
     GpuProvider, GpuProviderRegistry, LspciProvider, NvidiaProvider, SysfsProvider,
 };
 pub use types::{
-    GpuInfo, GpuKind, GpuMemory, GpuPreference, GpuProcessUsage, GpuSnapshot, GpuTelemetry, PciName,
+    GpuInfo, GpuKind, GpuMemory, GpuPreference, GpuProcessSource, GpuProcessUsage, GpuSnapshot,
+    GpuTelemetry, PciName,
 };
 
 use std::collections::HashMap;
@@ -116,6 +117,9 @@ fn merge_process_usage(current: &mut GpuProcessUsage, incoming: &GpuProcessUsage
         let merged = current.fb_mb.unwrap_or(0).max(fb_mb);
         current.fb_mb = Some(merged);
     }
+    current.sources.extend(incoming.sources.iter().copied());
+    current.sources.sort();
+    current.sources.dedup();
 }
 
 fn merge_optional_max(current: &mut Option<f32>, incoming: Option<f32>) {
@@ -203,6 +207,20 @@ pub fn default_gpu_index(gpus: &[GpuInfo], pref: GpuPreference) -> Option<usize>
     None
 }
 
+/// Sums VRAM across all GPUs that report memory, as `(used_bytes, total_bytes)`.
+/// Returns `None` when no GPU exposes memory information.
+pub fn total_vram(gpus: &[GpuInfo]) -> Option<(u64, u64)> {
+    gpus.iter()
+        .filter_map(|gpu| gpu.memory.as_ref())
+        .fold(None, |acc, memory| {
+            let (used, total) = acc.unwrap_or((0, 0));
+            Some((
+                used.saturating_add(memory.used_bytes),
+                total.saturating_add(memory.total_bytes),
+            ))
+        })
+}
+
 pub fn gpu_vendor_label(gpu: &GpuInfo) -> String {
     if let Some(vendor) = gpu.vendor.as_deref() {
         return short_vendor_name(vendor).to_string();
@@ -350,4 +368,68 @@ mod tests {
         assert_eq!(default_gpu_index(&gpus, GpuPreference::Auto), Some(1));
         assert_eq!(default_gpu_index(&gpus, GpuPreference::Integrated), Some(0));
     }
+
+    #[test]
+    fn total_vram_sums_reporting_gpus() {
+        let gpu = |id: &str, memory: Option<GpuMemory>| GpuInfo {
+            id: id.to_string(),
+            name: id.to_string(),
+            vendor: None,
+            device: None,
+            driver: None,
+            driver_version: None,
+            kind: GpuKind::Discrete,
+            memory,
+            telemetry: GpuTelemetry::default(),
+        };
+
+        assert_eq!(total_vram(&[]), None);
+        assert_eq!(total_vram(&[gpu("pci:0", None)]), None);
+
+        let gpus = vec![
+            gpu(
+                "nvidia:0",
+                Some(GpuMemory {
+                    used_bytes: 1024,
+                    total_bytes: 8192,
+                }),
+            ),
+            gpu("pci:00:02.0", None),
+            gpu(
+                "nvidia:1",
+                Some(GpuMemory {
+                    used_bytes: 512,
+                    total_bytes: 4096,
+                }),
+            ),
+        ];
+        assert_eq!(total_vram(&gpus), Some((1536, 12288)));
+    }
+
+    #[test]
+    fn merge_process_lists_keeps_sources() {
+        let usage = |source: GpuProcessSource, sm_pct: Option<f32>| GpuProcessUsage {
+            gpu_id: "nvidia:0".to_string(),
+            pid: 42,
+            kind: None,
+            sm_pct,
+            mem_pct: None,
+            enc_pct: None,
+            dec_pct: None,
+            fb_mb: None,
+            sources: vec![source],
+        };
+
+        let merged = merge_process_lists(vec![
+            vec![usage(GpuProcessSource::Drm, None)],
+            vec![usage(GpuProcessSource::Nvidia, Some(30.0))],
+        ]);
+
+        assert_eq!(merged.len(), 1);
+        assert_eq!(merged[0].sm_pct, Some(30.0));
+        assert_eq!(
+            merged[0].sources,
+            [GpuProcessSource::Nvidia, GpuProcessSource::Drm]
+        );
+    }
 }
@@ -4,9 +4,18 @@ use std::time::Duration;
 
 use super::{DrmProcessTracker, GpuSnapshot, probe_gpus_with_tracker};
 
-pub fn start_gpu_monitor(interval: Duration) -> mpsc::Receiver<GpuSnapshot> {
+const MIN_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Spawns the GPU polling thread.
+///
+/// Returns the snapshot receiver and a control sender; sending a new interval
+/// makes the monitor use it starting from its next cycle.
+pub fn start_gpu_monitor(
+    interval: Duration,
+) -> (mpsc::Receiver<GpuSnapshot>, mpsc::Sender<Duration>) {
     let (tx, rx) = mpsc::channel();
-    let interval = interval.max(Duration::from_millis(100));
+    let (ctrl_tx, ctrl_rx) = mpsc::channel::<Duration>();
+    let mut interval = interval.max(MIN_INTERVAL);
     thread::spawn(move || {
         let mut drm_tracker = DrmProcessTracker::new();
         loop {
@@ -14,8 +23,18 @@ pub fn start_gpu_monitor(interval: Duration) -> mpsc::Receiver<GpuSnapshot> {
             if tx.send(snapshot).is_err() {
                 break;
             }
-            thread::sleep(interval);
+            match ctrl_rx.recv_timeout(interval) {
+                Ok(next) => {
+                    interval = next.max(MIN_INTERVAL);
+                    // Keep the latest requested interval if several arrived
+                    while let Ok(next) = ctrl_rx.try_recv() {
+                        interval = next.max(MIN_INTERVAL);
+                    }
+                }
+                Err(mpsc::RecvTimeoutError::Timeout) => {}
+                Err(mpsc::RecvTimeoutError::Disconnected) => break,
+            }
         }
     });
-    rx
+    (rx, ctrl_tx)
 }
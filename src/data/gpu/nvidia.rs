@@ -1,13 +1,14 @@
 use std::collections::HashMap;
 use std::time::Duration;
 
-use super::types::{GpuInfo, GpuKind, GpuMemory, GpuProcessUsage, GpuTelemetry};
+use super::types::{GpuInfo, GpuKind, GpuMemory, GpuProcessSource, GpuProcessUsage, GpuTelemetry};
 use crate::utils::{mib_to_bytes, run_command_with_timeout};
 
 const NVIDIA_QUERY_BASE: &str = "index,name,memory.used,memory.total,driver_version";
 const NVIDIA_QUERY_EXTENDED: &str = concat!(
     "index,name,memory.used,memory.total,utilization.gpu,utilization.memory,temperature.gpu,",
-    "power.draw,power.limit,fan.speed,encoder.stats.average,decoder.stats.average,driver_version"
+    "power.draw,power.limit,fan.speed,encoder.stats.average,decoder.stats.average,driver_version,",
+    "clocks.gr,clocks.mem"
 );
 const NVIDIA_QUERY_UUID: &str = "index,uuid";
 const NVIDIA_QUERY_COMPUTE_APPS: &str = "gpu_uuid,pid,used_memory";
@@ -128,9 +129,14 @@ fn parse_nvidia_smi_output(output: &str) -> Option<Vec<GpuInfo>> {
                 temperature_c: parse_optional_f32(parts[6]),
                 power_draw_w: parse_optional_f32(parts[7]),
                 power_limit_w: parse_optional_f32(parts[8]),
-                fan_speed_pct: parse_optional_f32(parts[9]),
+                // nvidia-smi reports one fan speed per GPU
+                fans_pct: parse_optional_f32(parts[9])
+                    .map(|pct| vec![pct.clamp(0.0, 100.0).round() as u8])
+                    .unwrap_or_default(),
                 encoder_pct: parse_optional_f32(parts[10]),
                 decoder_pct: parse_optional_f32(parts[11]),
+                core_clock_mhz: parts.get(13).and_then(|value| parse_optional_mhz(value)),
+                mem_clock_mhz: parts.get(14).and_then(|value| parse_optional_mhz(value)),
             }
         } else {
             GpuTelemetry::default()
@@ -188,6 +194,7 @@ fn parse_nvidia_pmon_output(output: &str) -> Vec<GpuProcessUsage> {
                 enc_pct,
                 dec_pct,
                 fb_mb,
+                sources: vec![GpuProcessSource::Nvidia],
             })
         })
         .collect()
@@ -216,6 +223,7 @@ fn apply_compute_apps_memory(
                 enc_pct: None,
                 dec_pct: None,
                 fb_mb: Some(entry.used_memory_mb),
+                sources: vec![GpuProcessSource::Nvidia],
             });
     }
 }
@@ -294,6 +302,10 @@ fn parse_optional_u64(value: &str) -> Option<u64> {
     }
 }
 
+fn parse_optional_mhz(value: &str) -> Option<u32> {
+    parse_optional_u64(value).and_then(|mhz| u32::try_from(mhz).ok())
+}
+
 fn parse_optional_char(value: &str) -> Option<char> {
     let trimmed = value.trim();
     if trimmed.is_empty() || trimmed == "-" {
@@ -337,6 +349,19 @@ mod tests {
         assert_eq!(telemetry.temperature_c, Some(74.0));
         assert_eq!(telemetry.power_draw_w, Some(285.5));
         assert_eq!(telemetry.encoder_pct, Some(23.0));
+        assert_eq!(telemetry.core_clock_mhz, None);
+    }
+
+    #[test]
+    fn parse_nvidia_smi_output_parses_clocks() {
+        let output =
+            "0, RTX 3060, 120, 4096, 68, 12, 74, 285.5, 320.0, 52, 23, 0, 550.54, 2100, 7501\n";
+        let gpus = parse_nvidia_smi_output(output).unwrap();
+
+        let telemetry = &gpus[0].telemetry;
+        assert_eq!(gpus[0].driver_version.as_deref(), Some("550.54"));
+        assert_eq!(telemetry.core_clock_mhz, Some(2100));
+        assert_eq!(telemetry.mem_clock_mhz, Some(7501));
     }
 
     #[test]
@@ -196,6 +196,9 @@ fn read_pkgconfig_version(path: &Path) -> Option<String> {
 
 fn mesa_version_from_glxinfo() -> Option<String> {
     use std::process::Command;
+    if crate::utils::exec_disabled() {
+        return None;
+    }
     let output = Command::new("glxinfo").arg("-B").output().ok()?;
     if !output.status.success() {
         return None;
@@ -235,11 +238,24 @@ fn read_sysfs_telemetry(device_path: &Path) -> GpuTelemetry {
         read_percent_file(device_path, &["gpu_busy_percent", "gt_busy_percent"]);
     let utilization_mem_pct = read_percent_file(device_path, &["mem_busy_percent"]);
     let temperature_c = read_hwmon_temp_c(&hwmon_dirs);
-    let fan_speed_pct = read_hwmon_fan_pct(&hwmon_dirs);
+    let fans_pct = read_hwmon_fans_pct(&hwmon_dirs);
     let power_draw_w = read_hwmon_u64(&hwmon_dirs, &["power1_average", "power1_input"])
         .map(|value| value as f32 / 1_000_000.0);
     let power_limit_w = read_hwmon_u64(&hwmon_dirs, &["power1_cap", "power1_cap_max"])
         .map(|value| value as f32 / 1_000_000.0);
+    // amdgpu reports clocks in Hz via hwmon (freq1 = sclk, freq2 = mclk)
+    let hwmon_mhz = |name: &str| {
+        read_hwmon_u64(&hwmon_dirs, &[name]).and_then(|hz| u32::try_from(hz / 1_000_000).ok())
+    };
+    let core_clock_mhz = hwmon_mhz("freq1_input")
+        .or_else(|| read_dpm_clock_mhz(&device_path.join("pp_dpm_sclk")))
+        .or_else(|| {
+            // i915 exposes the actual GT frequency on the drm card directory
+            let card = device_path.parent()?;
+            read_u64(card.join("gt_act_freq_mhz")).and_then(|mhz| u32::try_from(mhz).ok())
+        });
+    let mem_clock_mhz =
+        hwmon_mhz("freq2_input").or_else(|| read_dpm_clock_mhz(&device_path.join("pp_dpm_mclk")));
 
     GpuTelemetry {
         utilization_gpu_pct,
@@ -247,12 +263,29 @@ fn read_sysfs_telemetry(device_path: &Path) -> GpuTelemetry {
         temperature_c,
         power_draw_w,
         power_limit_w,
-        fan_speed_pct,
+        fans_pct,
         encoder_pct: None,
         decoder_pct: None,
+        core_clock_mhz,
+        mem_clock_mhz,
     }
 }
 
+/// Active level of an amdgpu DPM table ("1: 1800Mhz *").
+fn read_dpm_clock_mhz(path: &Path) -> Option<u32> {
+    parse_dpm_clock_mhz(&fs::read_to_string(path).ok()?)
+}
+
+fn parse_dpm_clock_mhz(contents: &str) -> Option<u32> {
+    let line = contents
+        .lines()
+        .find(|line| line.trim_end().ends_with('*'))?;
+    let (_, value) = line.split_once(':')?;
+    let value = value.trim().trim_end_matches('*').trim();
+    let digits = value.trim_end_matches(|ch: char| ch.is_ascii_alphabetic());
+    digits.trim().parse().ok()
+}
+
 fn read_link_basename<P: AsRef<Path>>(path: P) -> Option<String> {
     fs::read_link(path).ok().and_then(|path| {
         path.file_name()
@@ -340,21 +373,55 @@ fn read_hwmon_temp_c(hwmon_dirs: &[PathBuf]) -> Option<f32> {
     max_temp
 }
 
-fn read_hwmon_fan_pct(hwmon_dirs: &[PathBuf]) -> Option<f32> {
+/// Maximum number of fans probed per hwmon directory
+const MAX_FANS: usize = 8;
+
+/// Speed of every fan (fanN_input against fanN_max, or the pwmN duty cycle),
+/// taken from the first hwmon directory that reports any.
+fn read_hwmon_fans_pct(hwmon_dirs: &[PathBuf]) -> Vec<u8> {
     for dir in hwmon_dirs {
-        if let (Some(speed), Some(max)) = (
-            read_u64(dir.join("fan1_input")),
-            read_u64(dir.join("fan1_max")),
-        ) && max > 0
-        {
-            return Some((speed as f32 / max as f32) * 100.0);
-        }
-        if let Some(pwm) = read_u64(dir.join("pwm1")) {
-            let max = read_u64(dir.join("pwm1_max")).unwrap_or(255);
-            if max > 0 {
-                return Some((pwm as f32 / max as f32) * 100.0);
-            }
+        let fans = (1..=MAX_FANS)
+            .filter_map(|index| {
+                let rpm = (
+                    read_u64(dir.join(format!("fan{index}_input"))),
+                    read_u64(dir.join(format!("fan{index}_max"))),
+                );
+                if let (Some(speed), Some(max)) = rpm
+                    && max > 0
+                {
+                    return Some(fan_pct(speed, max));
+                }
+                let pwm = read_u64(dir.join(format!("pwm{index}")))?;
+                let max = read_u64(dir.join(format!("pwm{index}_max"))).unwrap_or(255);
+                (max > 0).then(|| fan_pct(pwm, max))
+            })
+            .collect::<Vec<_>>();
+        if !fans.is_empty() {
+            return fans;
         }
     }
-    None
+    Vec::new()
+}
+
+fn fan_pct(value: u64, max: u64) -> u8 {
+    (value.min(max) * 100 / max) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_dpm_clock_picks_active_level() {
+        let contents = "0: 500Mhz\n1: 1800Mhz *\n2: 2450Mhz\n";
+        assert_eq!(parse_dpm_clock_mhz(contents), Some(1800));
+        assert_eq!(parse_dpm_clock_mhz("0: 96Mhz\n1: 1000Mhz\n"), None);
+    }
+
+    #[test]
+    fn fan_pct_clamps_to_max() {
+        assert_eq!(fan_pct(1200, 3000), 40);
+        assert_eq!(fan_pct(255, 255), 100);
+        assert_eq!(fan_pct(4000, 3000), 100);
+    }
 }
@@ -40,9 +40,12 @@ pub struct GpuTelemetry {
     pub temperature_c: Option<f32>,
     pub power_draw_w: Option<f32>,
     pub power_limit_w: Option<f32>,
-    pub fan_speed_pct: Option<f32>,
+    /// Speed of each fan in percent, empty when the card has no fan sensors
+    pub fans_pct: Vec<u8>,
     pub encoder_pct: Option<f32>,
     pub decoder_pct: Option<f32>,
+    pub core_clock_mhz: Option<u32>,
+    pub mem_clock_mhz: Option<u32>,
 }
 
 impl GpuTelemetry {
@@ -52,9 +55,13 @@ impl GpuTelemetry {
         self.temperature_c = self.temperature_c.or(other.temperature_c);
         self.power_draw_w = self.power_draw_w.or(other.power_draw_w);
         self.power_limit_w = self.power_limit_w.or(other.power_limit_w);
-        self.fan_speed_pct = self.fan_speed_pct.or(other.fan_speed_pct);
+        if self.fans_pct.is_empty() {
+            self.fans_pct = other.fans_pct.clone();
+        }
         self.encoder_pct = self.encoder_pct.or(other.encoder_pct);
         self.decoder_pct = self.decoder_pct.or(other.decoder_pct);
+        self.core_clock_mhz = self.core_clock_mhz.or(other.core_clock_mhz);
+        self.mem_clock_mhz = self.mem_clock_mhz.or(other.mem_clock_mhz);
     }
 }
 
@@ -77,6 +84,22 @@ pub struct GpuInfo {
     pub telemetry: GpuTelemetry,
 }
 
+/// Provider that reported a GPU process entry
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum GpuProcessSource {
+    Nvidia,
+    Drm,
+}
+
+impl GpuProcessSource {
+    pub fn label(self) -> &'static str {
+        match self {
+            GpuProcessSource::Nvidia => "nvidia",
+            GpuProcessSource::Drm => "drm",
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct GpuProcessUsage {
     pub gpu_id: String,
@@ -87,6 +110,8 @@ pub struct GpuProcessUsage {
     pub enc_pct: Option<f32>,
     pub dec_pct: Option<f32>,
     pub fb_mb: Option<u64>,
+    /// Every provider that reported this (gpu, pid) pair, sorted
+    pub sources: Vec<GpuProcessSource>,
 }
 
 #[derive(Debug)]
@@ -169,9 +194,11 @@ mod tests {
             temperature_c: None,
             power_draw_w: None,
             power_limit_w: None,
-            fan_speed_pct: None,
+            fans_pct: Vec::new(),
             encoder_pct: None,
             decoder_pct: None,
+            core_clock_mhz: None,
+            mem_clock_mhz: Some(7000),
         };
 
         let other = GpuTelemetry {
@@ -180,9 +207,11 @@ mod tests {
             temperature_c: Some(45.0),
             power_draw_w: Some(10.5),
             power_limit_w: Some(200.0),
-            fan_speed_pct: Some(30.0),
+            fans_pct: vec![30, 35],
             encoder_pct: Some(20.0),
             decoder_pct: Some(15.0),
+            core_clock_mhz: Some(2100),
+            mem_clock_mhz: Some(10500),
         };
 
         telemetry.merge_from(&other);
@@ -192,9 +221,11 @@ mod tests {
         assert_eq!(telemetry.temperature_c, Some(45.0));
         assert_eq!(telemetry.power_draw_w, Some(10.5));
         assert_eq!(telemetry.power_limit_w, Some(200.0));
-        assert_eq!(telemetry.fan_speed_pct, Some(30.0));
+        assert_eq!(telemetry.fans_pct, [30, 35]);
         assert_eq!(telemetry.encoder_pct, Some(20.0));
         assert_eq!(telemetry.decoder_pct, Some(15.0));
+        assert_eq!(telemetry.core_clock_mhz, Some(2100));
+        assert_eq!(telemetry.mem_clock_mhz, Some(7000));
     }
 
     #[test]
@@ -222,9 +253,11 @@ mod tests {
         assert!(telemetry.temperature_c.is_none());
         assert!(telemetry.power_draw_w.is_none());
         assert!(telemetry.power_limit_w.is_none());
-        assert!(telemetry.fan_speed_pct.is_none());
+        assert!(telemetry.fans_pct.is_empty());
         assert!(telemetry.encoder_pct.is_none());
         assert!(telemetry.decoder_pct.is_none());
+        assert!(telemetry.core_clock_mhz.is_none());
+        assert!(telemetry.mem_clock_mhz.is_none());
     }
 
     #[test]
@@ -266,6 +299,7 @@ mod tests {
             enc_pct: None,
             dec_pct: None,
             fb_mb: None,
+            sources: Vec::new(),
         };
 
         assert!(usage.kind.is_none());
@@ -0,0 +1,74 @@
+/// Extra memory counters from /proc/meminfo that sysinfo does not expose (bytes)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MemInfoDetails {
+    pub buffers: u64,
+    pub cached: u64,
+    pub dirty: u64,
+    pub shmem: u64,
+}
+
+/// Read /proc/meminfo; None on other platforms or when the file is unreadable.
+pub fn read_meminfo() -> Option<MemInfoDetails> {
+    #[cfg(target_os = "linux")]
+    {
+        let contents = std::fs::read_to_string("/proc/meminfo").ok()?;
+        parse_meminfo(&contents)
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    {
+        None
+    }
+}
+
+/// Parse "Key:   1234 kB" lines, requiring all four counters.
+fn parse_meminfo(contents: &str) -> Option<MemInfoDetails> {
+    let mut buffers = None;
+    let mut cached = None;
+    let mut dirty = None;
+    let mut shmem = None;
+    for line in contents.lines() {
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        let slot = match key {
+            "Buffers" => &mut buffers,
+            "Cached" => &mut cached,
+            "Dirty" => &mut dirty,
+            "Shmem" => &mut shmem,
+            _ => continue,
+        };
+        let kib = value
+            .split_whitespace()
+            .next()
+            .and_then(|value| value.parse::<u64>().ok());
+        *slot = kib.map(|kib| kib * 1024);
+    }
+    Some(MemInfoDetails {
+        buffers: buffers?,
+        cached: cached?,
+        dirty: dirty?,
+        shmem: shmem?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_meminfo_counters() {
+        let contents = "MemTotal:       16318480 kB\n\
+                        Buffers:          123456 kB\n\
+                        Cached:          4000000 kB\n\
+                        SwapCached:            0 kB\n\
+                        Dirty:               512 kB\n\
+                        Shmem:            262144 kB\n";
+        let details = parse_meminfo(contents).unwrap();
+        assert_eq!(details.buffers, 123_456 * 1024);
+        assert_eq!(details.cached, 4_000_000 * 1024);
+        assert_eq!(details.dirty, 512 * 1024);
+        assert_eq!(details.shmem, 262_144 * 1024);
+        assert_eq!(parse_meminfo("MemTotal: 1 kB\n"), None);
+    }
+}
@@ -1,14 +1,22 @@
 mod container;
 pub mod cpu;
 pub mod gpu;
+pub mod memory;
+pub mod mounts;
 mod process;
+pub mod sensors;
 mod sorting;
 
 pub use container::{
-    ContainerKey, ContainerRow, ContainerRuntime, NetSample, container_key_for_pid,
-    net_sample_for_pid, netns_id_for_pid,
+    ContainerKey, ContainerRow, ContainerRuntime, ContainerState, ContainerTotals, NetSample,
+    RuntimeContainer, cgroup_cpu_limit, cgroup_memory, cgroup_path_for_pid, container_cgroup_dir,
+    container_key_for_pid, container_name, net_sample_for_pid, netns_id_for_pid,
+    start_runtime_monitor, systemd_unit_for_pid,
 };
 pub use cpu::{CpuCaches, CpuCodename, CpuDetails, cpu_caches, cpu_details, lookup_cpu_codename};
 pub use gpu::{GpuInfo, GpuKind, GpuMemory, GpuPreference, GpuProcessUsage, GpuSnapshot};
-pub use process::ProcessRow;
+pub use process::{
+    ProcessRow, ProcessStateCounts, cpu_list_len, display_name, read_cpu_affinity,
+    read_sched_priority, read_swap_bytes, script_name,
+};
 pub use sorting::{SortDir, SortKey, sort_process_rows};
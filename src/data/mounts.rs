@@ -0,0 +1,83 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+/// One entry of /proc/self/mountinfo
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MountInfo {
+    /// Underlying device as (major, minor); bind mounts share it with their source
+    pub device: (u32, u32),
+    pub mount_point: PathBuf,
+    /// Mount source, e.g. /dev/nvme0n1p2 (pseudo filesystems report their type)
+    pub source: String,
+}
+
+pub fn read_mountinfo() -> Vec<MountInfo> {
+    fs::read_to_string("/proc/self/mountinfo")
+        .map(|content| content.lines().filter_map(parse_mountinfo_line).collect())
+        .unwrap_or_default()
+}
+
+/// Mount table keyed by mount point; for stacked mounts the topmost wins
+pub fn read_mount_table() -> HashMap<PathBuf, MountInfo> {
+    read_mountinfo()
+        .into_iter()
+        .map(|info| (info.mount_point.clone(), info))
+        .collect()
+}
+
+/// Parse "36 35 98:0 /mnt1 /mnt2 rw,noatime master:1 - ext3 /dev/root rw"
+fn parse_mountinfo_line(line: &str) -> Option<MountInfo> {
+    let mut fields = line.split_whitespace();
+    let device = fields.nth(2)?;
+    let mount_point = fields.nth(1)?;
+    // Optional fields end at the "-" separator, followed by fstype and source
+    let source = fields.skip_while(|field| *field != "-").nth(2)?;
+    let (major, minor) = device.split_once(':')?;
+    Some(MountInfo {
+        device: (major.parse().ok()?, minor.parse().ok()?),
+        mount_point: PathBuf::from(unescape_octal(mount_point)),
+        source: unescape_octal(source),
+    })
+}
+
+/// Mount points escape space, tab, newline and backslash as \ooo
+fn unescape_octal(value: &str) -> String {
+    let mut output = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(idx) = rest.find('\\') {
+        output.push_str(&rest[..idx]);
+        let code = rest
+            .get(idx + 1..idx + 4)
+            .and_then(|digits| u8::from_str_radix(digits, 8).ok());
+        match code {
+            Some(byte) => {
+                output.push(byte as char);
+                rest = &rest[idx + 4..];
+            }
+            None => {
+                output.push('\\');
+                rest = &rest[idx + 1..];
+            }
+        }
+    }
+    output.push_str(rest);
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_mountinfo_entry() {
+        let info = parse_mountinfo_line(
+            "36 35 259:2 /home /mnt/my\\040home rw,noatime shared:1 - ext4 /dev/nvme0n1p2 rw",
+        )
+        .unwrap();
+        assert_eq!(info.device, (259, 2));
+        assert_eq!(info.mount_point, PathBuf::from("/mnt/my home"));
+        assert_eq!(info.source, "/dev/nvme0n1p2");
+        assert!(parse_mountinfo_line("garbage").is_none());
+    }
+}
@@ -1,15 +1,33 @@
+use std::ffi::OsStr;
+
 pub struct ProcessRow {
     pub pid: u32,
     pub user: Option<String>,
     pub name: String,
     pub cpu: f32,
     pub mem_bytes: u64,
+    /// Accumulated user + system CPU time
+    pub cpu_time_ms: u64,
     pub status: String,
     pub start_time: u64,
     pub uptime_secs: u64,
     pub is_current_user: bool,
     pub is_non_root: bool,
     pub is_gui: bool,
+    /// PID appeared since the previous `update_rows`
+    pub is_new: bool,
+    /// Name was not valid UTF-8 (or held control characters) and was sanitized
+    pub name_lossy: bool,
+    /// Disk read + write rate exceeded `IO_ACTIVE_BYTES_PER_SEC` over the last refresh
+    pub io_active: bool,
+    /// Nice value (-20..19); 0 when not read
+    pub nice: i64,
+    /// Kernel priority as shown by `ps`/`top` (negative for real-time); 0 when not read
+    pub priority: i64,
+    /// systemd unit from the cgroup path ("nginx.service"); None outside units or when not read
+    pub unit: Option<String>,
+    /// Swapped-out memory (`VmSwap`); None when not read or not readable
+    pub swap_bytes: Option<u64>,
     pub gpu_sm_pct: Option<f32>,
     pub gpu_mem_pct: Option<f32>,
     pub gpu_enc_pct: Option<f32>,
@@ -17,3 +35,265 @@ pub struct ProcessRow {
     pub gpu_fb_bytes: Option<u64>,
     pub gpu_kind: Option<char>,
 }
+
+/// Processes per state letter, as in `top`'s task summary
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ProcessStateCounts {
+    pub running: usize,
+    pub sleeping: usize,
+    /// Uninterruptible (D) sleep, usually blocked on I/O
+    pub disk_sleep: usize,
+    pub zombie: usize,
+    /// Stopped or traced
+    pub stopped: usize,
+}
+
+impl ProcessStateCounts {
+    /// Counts `ProcessRow::status` values (sysinfo's `ProcessStatus` names).
+    pub fn from_statuses<'a>(statuses: impl IntoIterator<Item = &'a str>) -> Self {
+        let mut counts = Self::default();
+        for status in statuses {
+            match status {
+                "Run" => counts.running += 1,
+                "UninterruptibleDiskSleep" => counts.disk_sleep += 1,
+                "Zombie" => counts.zombie += 1,
+                "Stop" | "Tracing" => counts.stopped += 1,
+                // Idle kernel threads, parked and waking tasks count as sleeping
+                _ => counts.sleeping += 1,
+            }
+        }
+        counts
+    }
+}
+
+/// Display-safe process name plus whether it had to be altered.
+///
+/// Invalid UTF-8 becomes U+FFFD; control characters are replaced too, since
+/// they would otherwise break table alignment.
+pub fn display_name(name: &OsStr) -> (String, bool) {
+    let mut lossy = name.to_str().is_none();
+    let display = name
+        .to_string_lossy()
+        .chars()
+        .map(|ch| {
+            if ch.is_control() {
+                lossy = true;
+                char::REPLACEMENT_CHARACTER
+            } else {
+                ch
+            }
+        })
+        .collect();
+    (display, lossy)
+}
+
+/// (priority, nice) of `pid` from `/proc/<pid>/stat`.
+#[cfg(target_os = "linux")]
+pub fn read_sched_priority(pid: u32) -> Option<(i64, i64)> {
+    let stat = std::fs::read_to_string(format!("/proc/{pid}/stat")).ok()?;
+    parse_stat_priority(&stat)
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn read_sched_priority(_pid: u32) -> Option<(i64, i64)> {
+    None
+}
+
+/// Fields 18 (priority) and 19 (nice) of a stat line. The command name in
+/// field 2 may contain spaces and parentheses, so counting starts after the
+/// last `)`.
+fn parse_stat_priority(stat: &str) -> Option<(i64, i64)> {
+    let rest = &stat[stat.rfind(')')? + 1..];
+    let mut fields = rest.split_whitespace().skip(15);
+    let priority = fields.next()?.parse().ok()?;
+    let nice = fields.next()?.parse().ok()?;
+    Some((priority, nice))
+}
+
+/// Swapped-out memory of `pid` from the `VmSwap` line of `/proc/<pid>/status`.
+///
+/// None for kernel threads and processes whose status is not readable.
+#[cfg(target_os = "linux")]
+pub fn read_swap_bytes(pid: u32) -> Option<u64> {
+    let status = std::fs::read_to_string(format!("/proc/{pid}/status")).ok()?;
+    parse_vm_swap(&status)
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn read_swap_bytes(_pid: u32) -> Option<u64> {
+    None
+}
+
+fn parse_vm_swap(status: &str) -> Option<u64> {
+    let line = status
+        .lines()
+        .find_map(|line| line.strip_prefix("VmSwap:"))?;
+    let kib = line.trim().strip_suffix("kB")?.trim().parse::<u64>().ok()?;
+    Some(kib * 1024)
+}
+
+/// CPUs `pid` may run on, as the kernel's range list ("0-3,8") from the
+/// `Cpus_allowed_list` line of `/proc/<pid>/status`.
+#[cfg(target_os = "linux")]
+pub fn read_cpu_affinity(pid: u32) -> Option<String> {
+    let status = std::fs::read_to_string(format!("/proc/{pid}/status")).ok()?;
+    status
+        .lines()
+        .find_map(|line| line.strip_prefix("Cpus_allowed_list:"))
+        .map(|list| list.trim().to_string())
+        .filter(|list| cpu_list_len(list).is_some())
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn read_cpu_affinity(_pid: u32) -> Option<String> {
+    None
+}
+
+/// Number of CPUs in a range list such as "0-3,8"; None if it is malformed.
+pub fn cpu_list_len(list: &str) -> Option<usize> {
+    let mut count = 0;
+    for part in list.split(',') {
+        count += match part.split_once('-') {
+            Some((start, end)) => {
+                let start = start.trim().parse::<usize>().ok()?;
+                let end = end.trim().parse::<usize>().ok()?;
+                end.checked_sub(start)? + 1
+            }
+            None => {
+                part.trim().parse::<usize>().ok()?;
+                1
+            }
+        };
+    }
+    Some(count)
+}
+
+/// Interpreters whose process name hides what is actually running
+const INTERPRETERS: [&str; 5] = ["python", "node", "ruby", "java", "perl"];
+
+/// For an interpreter process (`python3.11`, `node`, ...), the basename of the
+/// script, module or jar it runs, taken from the command line.
+pub fn script_name(name: &str, cmd: &[String]) -> Option<String> {
+    let interpreter = INTERPRETERS
+        .iter()
+        .find(|interpreter| name.starts_with(**interpreter))?;
+    let mut args = cmd.iter().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            // python -m http.server, java -jar app.jar
+            "-m" if *interpreter == "python" => return args.next().cloned(),
+            "-jar" if *interpreter == "java" => return args.next().map(|jar| basename(jar)),
+            // Options that take a separate value
+            "-cp" | "-classpath" | "--class-path" | "-W" | "-X" | "-r" | "--require" => {
+                args.next();
+            }
+            "-c" | "-e" | "--eval" | "-" => return None,
+            arg if arg.starts_with('-') => {}
+            arg => return Some(basename(arg)),
+        }
+    }
+    None
+}
+
+fn basename(path: &str) -> String {
+    path.rsplit('/').next().unwrap_or(path).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stat_priority_skips_command_name() {
+        let stat = "1234 (tmux: server (1)) S 1 1234 1234 0 -1 4194560 900 0 0 0 \
+                    12 5 0 0 20 0 1 0 5000 10000000 800 18446744073709551615";
+        assert_eq!(parse_stat_priority(stat), Some((20, 0)));
+        let realtime = "42 (irq/9-acpi) S 2 0 0 0 -1 2129984 0 0 0 0 0 0 0 0 -51 0 1 0 30";
+        assert_eq!(parse_stat_priority(realtime), Some((-51, 0)));
+        assert_eq!(parse_stat_priority("7 (x) S 1"), None);
+    }
+
+    #[test]
+    fn process_state_counts_by_letter() {
+        let counts = ProcessStateCounts::from_statuses([
+            "Run",
+            "Sleep",
+            "Idle",
+            "UninterruptibleDiskSleep",
+            "Zombie",
+            "Stop",
+            "Tracing",
+        ]);
+        assert_eq!(
+            counts,
+            ProcessStateCounts {
+                running: 1,
+                sleeping: 2,
+                disk_sleep: 1,
+                zombie: 1,
+                stopped: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn vm_swap_from_status() {
+        let status = "Name:\tfirefox\nVmRSS:\t  204800 kB\nVmSwap:\t    1536 kB\n";
+        assert_eq!(parse_vm_swap(status), Some(1536 * 1024));
+        // Kernel threads have no Vm* lines
+        assert_eq!(parse_vm_swap("Name:\tkthreadd\n"), None);
+    }
+
+    #[test]
+    fn cpu_list_len_counts_ranges() {
+        assert_eq!(cpu_list_len("0-3,8"), Some(5));
+        assert_eq!(cpu_list_len("5"), Some(1));
+        assert_eq!(cpu_list_len("0-15"), Some(16));
+        assert_eq!(cpu_list_len("3-1"), None);
+        assert_eq!(cpu_list_len(""), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn display_name_flags_non_utf8() {
+        use std::ffi::OsString;
+        use std::os::unix::ffi::OsStringExt;
+
+        let name = OsString::from_vec(vec![b'b', b'a', b'd', 0xff, b'x']);
+        assert_eq!(display_name(&name), ("bad\u{fffd}x".to_string(), true));
+        assert_eq!(
+            display_name(OsStr::new("tab\tname")),
+            ("tab\u{fffd}name".to_string(), true)
+        );
+        assert_eq!(
+            display_name(OsStr::new("bash")),
+            ("bash".to_string(), false)
+        );
+    }
+
+    fn cmd(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn script_name_from_interpreter_command_line() {
+        assert_eq!(
+            script_name("python3.11", &cmd(&["python3", "-u", "/opt/app/server.py"])),
+            Some("server.py".to_string())
+        );
+        assert_eq!(
+            script_name("python3", &cmd(&["python3", "-m", "http.server"])),
+            Some("http.server".to_string())
+        );
+        assert_eq!(
+            script_name("java", &cmd(&["java", "-Xmx1g", "-jar", "/srv/app.jar"])),
+            Some("app.jar".to_string())
+        );
+        assert_eq!(
+            script_name("java", &cmd(&["java", "-cp", "lib/*", "com.example.Main"])),
+            Some("com.example.Main".to_string())
+        );
+        assert_eq!(script_name("node", &cmd(&["node", "-e", "1"])), None);
+        assert_eq!(script_name("bash", &cmd(&["bash", "run.sh"])), None);
+    }
+}
@@ -0,0 +1,225 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+/// Fan and power readings of one hwmon chip
+#[derive(Debug, Clone, Default)]
+pub struct HwmonChip {
+    pub name: String,
+    /// (label, RPM)
+    pub fans: Vec<(String, u64)>,
+    /// (label, watts)
+    pub power: Vec<(String, f32)>,
+}
+
+/// Read fan speeds and power draw from /sys/class/hwmon, one entry per chip.
+/// Chips exposing neither are skipped (temperatures come from sysinfo).
+pub fn read_hwmon_chips() -> Vec<HwmonChip> {
+    read_hwmon_chips_in(Path::new("/sys/class/hwmon"))
+}
+
+fn read_hwmon_chips_in(class_dir: &Path) -> Vec<HwmonChip> {
+    let Ok(entries) = fs::read_dir(class_dir) else {
+        return Vec::new();
+    };
+    let mut chips = entries
+        .flatten()
+        .filter_map(|entry| read_chip(&entry.path()))
+        .filter(|chip| !chip.fans.is_empty() || !chip.power.is_empty())
+        .collect::<Vec<_>>();
+    chips.sort_by(|a, b| a.name.cmp(&b.name));
+    chips
+}
+
+fn read_chip(dir: &Path) -> Option<HwmonChip> {
+    let name = read_trimmed(&dir.join("name"))?;
+    let mut chip = HwmonChip {
+        name,
+        ..HwmonChip::default()
+    };
+    for index in 1..=16 {
+        let fan = dir.join(format!("fan{index}_input"));
+        if let Some(rpm) = read_trimmed(&fan).and_then(|value| value.parse::<u64>().ok()) {
+            let label = read_trimmed(&dir.join(format!("fan{index}_label")))
+                .unwrap_or_else(|| format!("fan{index}"));
+            chip.fans.push((label, rpm));
+        }
+        // Power is reported in microwatts
+        let power = ["average", "input"].iter().find_map(|kind| {
+            read_trimmed(&dir.join(format!("power{index}_{kind}")))
+                .and_then(|value| value.parse::<u64>().ok())
+        });
+        if let Some(microwatts) = power {
+            let label = read_trimmed(&dir.join(format!("power{index}_label")))
+                .unwrap_or_else(|| format!("power{index}"));
+            chip.power.push((label, microwatts as f32 / 1_000_000.0));
+        }
+    }
+    Some(chip)
+}
+
+/// Temperatures (°C) of the given block devices, keyed by device name.
+/// Devices without a hwmon sensor are left out.
+pub fn disk_temperatures<'a>(devices: impl IntoIterator<Item = &'a str>) -> HashMap<String, f32> {
+    let mut temps = HashMap::new();
+    for device in devices {
+        if temps.contains_key(device) {
+            continue;
+        }
+        if let Some(temp) = block_device_temperature(device) {
+            temps.insert(device.to_string(), temp);
+        }
+    }
+    temps
+}
+
+/// Temperature (°C) of the drive backing `device` (e.g. "/dev/nvme0n1p2"), if its
+/// driver registers a hwmon sensor (nvme, drivetemp).
+fn block_device_temperature(device: &str) -> Option<f32> {
+    block_device_temperature_in(Path::new("/sys/class/block"), device)
+}
+
+fn block_device_temperature_in(class_dir: &Path, device: &str) -> Option<f32> {
+    let device = fs::canonicalize(device).unwrap_or_else(|_| device.into());
+    let name = device.file_name()?.to_str()?;
+    let mut block = fs::canonicalize(class_dir.join(name)).ok()?;
+    // Partitions live under their parent disk
+    if block.join("partition").exists() {
+        block = block.parent()?.to_path_buf();
+    }
+    let device_dir = block.join("device");
+    // nvme exposes device/hwmonN, drivetemp exposes device/hwmon/hwmonN
+    [device_dir.clone(), device_dir.join("hwmon")]
+        .iter()
+        .filter_map(|dir| fs::read_dir(dir).ok())
+        .flat_map(|entries| entries.flatten())
+        .filter(|entry| entry.file_name().to_string_lossy().starts_with("hwmon"))
+        .find_map(|entry| {
+            let millis = read_trimmed(&entry.path().join("temp1_input"))?;
+            Some(millis.parse::<i64>().ok()? as f32 / 1000.0)
+        })
+}
+
+/// Split a sysinfo component label ("coretemp Package id 0") into chip and sensor.
+pub fn split_component_label(label: &str) -> (&str, &str) {
+    match label.split_once(' ') {
+        Some((chip, sensor)) => (chip, sensor.trim()),
+        None => (label, ""),
+    }
+}
+
+/// Whether a sysinfo component label belongs to a CPU sensor (package, die or core).
+pub fn is_cpu_sensor(label: &str) -> bool {
+    const CPU_CHIPS: &[&str] = &["coretemp", "k10temp", "zenpower", "cpu_thermal"];
+    let (chip, sensor) = split_component_label(label);
+    CPU_CHIPS.contains(&chip)
+        || chip.eq_ignore_ascii_case("cpu")
+        || sensor.to_ascii_lowercase().contains("cpu")
+}
+
+fn read_trimmed(path: &Path) -> Option<String> {
+    let value = fs::read_to_string(path).ok()?;
+    let value = value.trim();
+    (!value.is_empty()).then(|| value.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::os::unix::fs::symlink;
+    use std::path::PathBuf;
+
+    /// Empty scratch directory standing in for /sys
+    fn fixture_root(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("rtop-sensors-{}-{name}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+        root
+    }
+
+    fn write(path: &Path, contents: &str) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, contents).unwrap();
+    }
+
+    #[test]
+    fn hwmon_chips_from_sysfs_tree() {
+        let root = fixture_root("hwmon");
+        let fans = root.join("hwmon0");
+        write(&fans.join("name"), "nct6798\n");
+        write(&fans.join("fan1_input"), "1200\n");
+        write(&fans.join("fan1_label"), "CPU fan\n");
+        write(&fans.join("fan3_input"), "850\n");
+        let gpu = root.join("hwmon1");
+        write(&gpu.join("name"), "amdgpu\n");
+        write(&gpu.join("power1_average"), "35500000\n");
+        // Temperature-only chips are left to sysinfo
+        write(&root.join("hwmon2/name"), "acpitz\n");
+        write(&root.join("hwmon2/temp1_input"), "40000\n");
+
+        let chips = read_hwmon_chips_in(&root);
+        let names: Vec<_> = chips.iter().map(|chip| chip.name.as_str()).collect();
+        assert_eq!(names, ["amdgpu", "nct6798"]);
+        assert_eq!(chips[0].power, [("power1".to_string(), 35.5)]);
+        assert_eq!(
+            chips[1].fans,
+            [("CPU fan".to_string(), 1200), ("fan3".to_string(), 850)]
+        );
+        assert!(read_hwmon_chips_in(&root.join("missing")).is_empty());
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn block_device_temperature_resolves_partitions() {
+        let root = fixture_root("block");
+        let devices = root.join("devices");
+        // nvme: device/hwmonN; the partition sits inside its disk
+        write(
+            &devices.join("nvme0n1/device/hwmon3/temp1_input"),
+            "41850\n",
+        );
+        write(&devices.join("nvme0n1/nvme0n1p2/partition"), "2\n");
+        // drivetemp: device/hwmon/hwmonN
+        write(
+            &devices.join("sda/device/hwmon/hwmon5/temp1_input"),
+            "35000\n",
+        );
+        fs::create_dir_all(devices.join("sdb/device")).unwrap();
+        let class = root.join("class/block");
+        fs::create_dir_all(&class).unwrap();
+        for (link, target) in [
+            ("nvme0n1", "nvme0n1"),
+            ("nvme0n1p2", "nvme0n1/nvme0n1p2"),
+            ("sda", "sda"),
+            ("sdb", "sdb"),
+        ] {
+            symlink(devices.join(target), class.join(link)).unwrap();
+        }
+
+        let temp = |device| block_device_temperature_in(&class, device);
+        assert_eq!(temp("/dev/nvme0n1p2"), Some(41.85));
+        assert_eq!(temp("/dev/nvme0n1"), Some(41.85));
+        assert_eq!(temp("/dev/sda"), Some(35.0));
+        assert_eq!(temp("/dev/sdb"), None);
+        assert_eq!(temp("/dev/sdz"), None);
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn component_label_split() {
+        assert_eq!(
+            split_component_label("coretemp Package id 0"),
+            ("coretemp", "Package id 0")
+        );
+        assert_eq!(split_component_label("acpitz"), ("acpitz", ""));
+    }
+
+    #[test]
+    fn cpu_sensor_detection() {
+        assert!(is_cpu_sensor("coretemp Core 3"));
+        assert!(is_cpu_sensor("k10temp Tctl"));
+        assert!(is_cpu_sensor("acpitz CPU"));
+        assert!(!is_cpu_sensor("nvme Composite"));
+        assert!(!is_cpu_sensor("amdgpu edge"));
+    }
+}
@@ -39,8 +39,15 @@ pub enum SortKey {
     User,
     Cpu,
     Mem,
+    Swap,
+    GpuSm,
+    GpuVram,
+    CpuTime,
     Uptime,
     Status,
+    Priority,
+    Nice,
+    Unit,
     Name,
 }
 
@@ -51,16 +58,35 @@ impl SortKey {
             SortKey::User => "user",
             SortKey::Cpu => "cpu",
             SortKey::Mem => "mem",
+            SortKey::Swap => "swap",
+            SortKey::GpuSm => "gpu",
+            SortKey::GpuVram => "vram",
+            SortKey::CpuTime => "time",
             SortKey::Uptime => "uptime",
             SortKey::Status => "stat",
+            SortKey::Priority => "pri",
+            SortKey::Nice => "nice",
+            SortKey::Unit => "unit",
             SortKey::Name => "name",
         }
     }
 
     pub fn default_dir(self) -> SortDir {
         match self {
-            SortKey::Cpu | SortKey::Mem | SortKey::Uptime => SortDir::Desc,
-            SortKey::Pid | SortKey::User | SortKey::Status | SortKey::Name => SortDir::Asc,
+            SortKey::Cpu
+            | SortKey::Mem
+            | SortKey::Swap
+            | SortKey::GpuSm
+            | SortKey::GpuVram
+            | SortKey::CpuTime
+            | SortKey::Uptime => SortDir::Desc,
+            SortKey::Pid
+            | SortKey::User
+            | SortKey::Status
+            | SortKey::Priority
+            | SortKey::Nice
+            | SortKey::Unit
+            | SortKey::Name => SortDir::Asc,
         }
     }
 
@@ -70,8 +96,15 @@ impl SortKey {
             "user" => Some(SortKey::User),
             "cpu" => Some(SortKey::Cpu),
             "mem" => Some(SortKey::Mem),
+            "swap" => Some(SortKey::Swap),
+            "gpu" | "sm" => Some(SortKey::GpuSm),
+            "vram" => Some(SortKey::GpuVram),
+            "time" | "cputime" => Some(SortKey::CpuTime),
             "up" | "uptime" => Some(SortKey::Uptime),
             "stat" | "status" => Some(SortKey::Status),
+            "pri" | "priority" => Some(SortKey::Priority),
+            "ni" | "nice" => Some(SortKey::Nice),
+            "unit" => Some(SortKey::Unit),
             "name" => Some(SortKey::Name),
             _ => None,
         }
@@ -82,9 +115,16 @@ impl SortKey {
             SortKey::Pid => SortKey::User,
             SortKey::User => SortKey::Cpu,
             SortKey::Cpu => SortKey::Mem,
-            SortKey::Mem => SortKey::Uptime,
+            SortKey::Mem => SortKey::Swap,
+            SortKey::Swap => SortKey::GpuSm,
+            SortKey::GpuSm => SortKey::GpuVram,
+            SortKey::GpuVram => SortKey::CpuTime,
+            SortKey::CpuTime => SortKey::Uptime,
             SortKey::Uptime => SortKey::Status,
-            SortKey::Status => SortKey::Name,
+            SortKey::Status => SortKey::Priority,
+            SortKey::Priority => SortKey::Nice,
+            SortKey::Nice => SortKey::Unit,
+            SortKey::Unit => SortKey::Name,
             SortKey::Name => SortKey::Pid,
         }
     }
@@ -95,37 +135,87 @@ impl SortKey {
             SortKey::User => SortKey::Pid,
             SortKey::Cpu => SortKey::User,
             SortKey::Mem => SortKey::Cpu,
-            SortKey::Uptime => SortKey::Mem,
+            SortKey::Swap => SortKey::Mem,
+            SortKey::GpuSm => SortKey::Swap,
+            SortKey::GpuVram => SortKey::GpuSm,
+            SortKey::CpuTime => SortKey::GpuVram,
+            SortKey::Uptime => SortKey::CpuTime,
             SortKey::Status => SortKey::Uptime,
-            SortKey::Name => SortKey::Status,
+            SortKey::Priority => SortKey::Status,
+            SortKey::Nice => SortKey::Priority,
+            SortKey::Unit => SortKey::Nice,
+            SortKey::Name => SortKey::Unit,
         }
     }
 }
 
-pub fn sort_process_rows(rows: &mut [ProcessRow], sort_key: SortKey, sort_dir: SortDir) {
+/// Sorts by `sort_key`, then by `secondary` (in its default direction), then
+/// by PID, so rows that tie keep the same order from one refresh to the next.
+pub fn sort_process_rows(
+    rows: &mut [ProcessRow],
+    sort_key: SortKey,
+    sort_dir: SortDir,
+    secondary: Option<SortKey>,
+) {
+    let secondary = secondary.filter(|key| *key != sort_key && *key != SortKey::Pid);
     rows.sort_by(|a, b| {
-        let ordering = match sort_key {
-            SortKey::Pid => a.pid.cmp(&b.pid),
-            SortKey::User => match (&a.user, &b.user) {
-                (Some(a), Some(b)) => a.cmp(b),
-                (None, Some(_)) => Ordering::Greater,
-                (Some(_), None) => Ordering::Less,
-                (None, None) => Ordering::Equal,
-            },
-            SortKey::Cpu => a.cpu.partial_cmp(&b.cpu).unwrap_or(Ordering::Equal),
-            SortKey::Mem => a.mem_bytes.cmp(&b.mem_bytes),
-            SortKey::Uptime => a.uptime_secs.cmp(&b.uptime_secs),
-            SortKey::Status => a.status.cmp(&b.status),
-            SortKey::Name => a.name.cmp(&b.name),
-        };
+        compare_rows(a, b, sort_key, sort_dir)
+            .then_with(|| match secondary {
+                Some(key) => compare_rows(a, b, key, key.default_dir()),
+                None => Ordering::Equal,
+            })
+            .then_with(|| a.pid.cmp(&b.pid))
+    });
+}
 
-        let ordering = match sort_dir {
-            SortDir::Asc => ordering,
-            SortDir::Desc => ordering.reverse(),
-        };
+fn compare_rows(a: &ProcessRow, b: &ProcessRow, sort_key: SortKey, sort_dir: SortDir) -> Ordering {
+    // Processes without swap or GPU data stay at the bottom in either direction
+    let missing = match sort_key {
+        SortKey::Swap => Some((a.swap_bytes.is_none(), b.swap_bytes.is_none())),
+        SortKey::GpuSm => Some((a.gpu_sm_pct.is_none(), b.gpu_sm_pct.is_none())),
+        SortKey::GpuVram => Some((a.gpu_fb_bytes.is_none(), b.gpu_fb_bytes.is_none())),
+        _ => None,
+    };
+    if let Some((a_missing, b_missing)) = missing
+        && a_missing != b_missing
+    {
+        return a_missing.cmp(&b_missing);
+    }
 
-        ordering.then_with(|| a.pid.cmp(&b.pid))
-    });
+    let ordering = match sort_key {
+        SortKey::Pid => a.pid.cmp(&b.pid),
+        SortKey::User => cmp_present_first(&a.user, &b.user),
+        SortKey::Cpu => a.cpu.partial_cmp(&b.cpu).unwrap_or(Ordering::Equal),
+        SortKey::Mem => a.mem_bytes.cmp(&b.mem_bytes),
+        SortKey::Swap => a.swap_bytes.cmp(&b.swap_bytes),
+        SortKey::GpuSm => a
+            .gpu_sm_pct
+            .partial_cmp(&b.gpu_sm_pct)
+            .unwrap_or(Ordering::Equal),
+        SortKey::GpuVram => a.gpu_fb_bytes.cmp(&b.gpu_fb_bytes),
+        SortKey::CpuTime => a.cpu_time_ms.cmp(&b.cpu_time_ms),
+        SortKey::Uptime => a.uptime_secs.cmp(&b.uptime_secs),
+        SortKey::Status => a.status.cmp(&b.status),
+        SortKey::Priority => a.priority.cmp(&b.priority),
+        SortKey::Nice => a.nice.cmp(&b.nice),
+        SortKey::Unit => cmp_present_first(&a.unit, &b.unit),
+        SortKey::Name => a.name.cmp(&b.name),
+    };
+
+    match sort_dir {
+        SortDir::Asc => ordering,
+        SortDir::Desc => ordering.reverse(),
+    }
+}
+
+/// Orders values, with missing ones after all present ones.
+fn cmp_present_first(a: &Option<String>, b: &Option<String>) -> Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) => a.cmp(b),
+        (None, Some(_)) => Ordering::Greater,
+        (Some(_), None) => Ordering::Less,
+        (None, None) => Ordering::Equal,
+    }
 }
 
 #[cfg(test)]
@@ -141,12 +231,20 @@ mod tests {
                 name: "b".to_string(),
                 cpu: 20.0,
                 mem_bytes: 200,
+                cpu_time_ms: 0,
                 status: "Sleep".to_string(),
                 start_time: 0,
                 uptime_secs: 20,
                 is_current_user: false,
                 is_non_root: false,
                 is_gui: false,
+                is_new: false,
+                name_lossy: false,
+                io_active: false,
+                nice: 0,
+                priority: 0,
+                unit: None,
+                swap_bytes: None,
                 gpu_sm_pct: None,
                 gpu_mem_pct: None,
                 gpu_enc_pct: None,
@@ -160,12 +258,20 @@ mod tests {
                 name: "a".to_string(),
                 cpu: 20.0,
                 mem_bytes: 100,
+                cpu_time_ms: 0,
                 status: "Run".to_string(),
                 start_time: 0,
                 uptime_secs: 30,
                 is_current_user: false,
                 is_non_root: false,
                 is_gui: false,
+                is_new: false,
+                name_lossy: false,
+                io_active: false,
+                nice: 0,
+                priority: 0,
+                unit: None,
+                swap_bytes: None,
                 gpu_sm_pct: None,
                 gpu_mem_pct: None,
                 gpu_enc_pct: None,
@@ -179,12 +285,20 @@ mod tests {
                 name: "c".to_string(),
                 cpu: 10.0,
                 mem_bytes: 300,
+                cpu_time_ms: 0,
                 status: "Run".to_string(),
                 start_time: 0,
                 uptime_secs: 10,
                 is_current_user: false,
                 is_non_root: false,
                 is_gui: false,
+                is_new: false,
+                name_lossy: false,
+                io_active: false,
+                nice: 0,
+                priority: 0,
+                unit: None,
+                swap_bytes: None,
                 gpu_sm_pct: None,
                 gpu_mem_pct: None,
                 gpu_enc_pct: None,
@@ -194,7 +308,7 @@ mod tests {
             },
         ];
 
-        sort_process_rows(&mut rows, SortKey::Cpu, SortDir::Desc);
+        sort_process_rows(&mut rows, SortKey::Cpu, SortDir::Desc, None);
 
         assert_eq!(rows[0].pid, 1);
         assert_eq!(rows[1].pid, 2);
@@ -210,12 +324,20 @@ mod tests {
                 name: "b".to_string(),
                 cpu: 20.0,
                 mem_bytes: 200,
+                cpu_time_ms: 0,
                 status: "Sleep".to_string(),
                 start_time: 0,
                 uptime_secs: 20,
                 is_current_user: false,
                 is_non_root: false,
                 is_gui: false,
+                is_new: false,
+                name_lossy: false,
+                io_active: false,
+                nice: 0,
+                priority: 0,
+                unit: None,
+                swap_bytes: None,
                 gpu_sm_pct: None,
                 gpu_mem_pct: None,
                 gpu_enc_pct: None,
@@ -229,12 +351,20 @@ mod tests {
                 name: "a".to_string(),
                 cpu: 20.0,
                 mem_bytes: 100,
+                cpu_time_ms: 0,
                 status: "Run".to_string(),
                 start_time: 0,
                 uptime_secs: 30,
                 is_current_user: false,
                 is_non_root: false,
                 is_gui: false,
+                is_new: false,
+                name_lossy: false,
+                io_active: false,
+                nice: 0,
+                priority: 0,
+                unit: None,
+                swap_bytes: None,
                 gpu_sm_pct: None,
                 gpu_mem_pct: None,
                 gpu_enc_pct: None,
@@ -248,12 +378,20 @@ mod tests {
                 name: "c".to_string(),
                 cpu: 10.0,
                 mem_bytes: 300,
+                cpu_time_ms: 0,
                 status: "Run".to_string(),
                 start_time: 0,
                 uptime_secs: 10,
                 is_current_user: false,
                 is_non_root: false,
                 is_gui: false,
+                is_new: false,
+                name_lossy: false,
+                io_active: false,
+                nice: 0,
+                priority: 0,
+                unit: None,
+                swap_bytes: None,
                 gpu_sm_pct: None,
                 gpu_mem_pct: None,
                 gpu_enc_pct: None,
@@ -263,10 +401,81 @@ mod tests {
             },
         ];
 
-        sort_process_rows(&mut rows, SortKey::User, SortDir::Asc);
+        sort_process_rows(&mut rows, SortKey::User, SortDir::Asc, None);
 
         assert_eq!(rows[0].user.as_deref(), Some("alice"));
         assert_eq!(rows[1].user.as_deref(), Some("bob"));
         assert_eq!(rows[2].user.as_deref(), None);
     }
+
+    fn gpu_row(pid: u32, sm: Option<f32>) -> ProcessRow {
+        ProcessRow {
+            pid,
+            user: None,
+            name: format!("p{pid}"),
+            cpu: 0.0,
+            mem_bytes: 0,
+            cpu_time_ms: 0,
+            status: "Run".to_string(),
+            start_time: 0,
+            uptime_secs: 0,
+            is_current_user: false,
+            is_non_root: false,
+            is_gui: false,
+            is_new: false,
+            name_lossy: false,
+            io_active: false,
+            nice: 0,
+            priority: 0,
+            unit: None,
+            swap_bytes: None,
+            gpu_sm_pct: sm,
+            gpu_mem_pct: None,
+            gpu_enc_pct: None,
+            gpu_dec_pct: None,
+            gpu_fb_bytes: sm.map(|pct| pct as u64 * 1024),
+            gpu_kind: None,
+        }
+    }
+
+    #[test]
+    fn sort_process_rows_gpu_missing_last() {
+        let mut rows = vec![
+            gpu_row(1, None),
+            gpu_row(2, Some(10.0)),
+            gpu_row(3, Some(50.0)),
+        ];
+
+        sort_process_rows(&mut rows, SortKey::GpuSm, SortDir::Desc, None);
+        let pids = rows.iter().map(|row| row.pid).collect::<Vec<_>>();
+        assert_eq!(pids, vec![3, 2, 1]);
+
+        sort_process_rows(&mut rows, SortKey::GpuVram, SortDir::Asc, None);
+        let pids = rows.iter().map(|row| row.pid).collect::<Vec<_>>();
+        assert_eq!(pids, vec![2, 3, 1]);
+    }
+
+    #[test]
+    fn ties_keep_pid_order_across_refreshes() {
+        let pids = |rows: &[ProcessRow]| rows.iter().map(|row| row.pid).collect::<Vec<_>>();
+        // Every row at 0% CPU, arriving in a different order each refresh
+        let mut first = vec![gpu_row(7, None), gpu_row(3, None), gpu_row(5, None)];
+        let mut second = vec![gpu_row(5, None), gpu_row(7, None), gpu_row(3, None)];
+        sort_process_rows(&mut first, SortKey::Cpu, SortDir::Desc, None);
+        sort_process_rows(&mut second, SortKey::Cpu, SortDir::Desc, None);
+        assert_eq!(pids(&first), vec![3, 5, 7]);
+        assert_eq!(pids(&second), pids(&first));
+    }
+
+    #[test]
+    fn secondary_key_breaks_ties() {
+        let mut rows = vec![gpu_row(1, None), gpu_row(2, None), gpu_row(3, None)];
+        rows[0].mem_bytes = 100;
+        rows[1].mem_bytes = 300;
+        rows[2].mem_bytes = 200;
+        sort_process_rows(&mut rows, SortKey::Cpu, SortDir::Desc, Some(SortKey::Mem));
+        let pids = rows.iter().map(|row| row.pid).collect::<Vec<_>>();
+        // Memory sorts in its default (descending) direction
+        assert_eq!(pids, vec![2, 3, 1]);
+    }
 }
@@ -1,9 +1,15 @@
+use std::time::Instant;
+
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
 use ratatui::prelude::Rect;
 
 use super::types::{AppEvent, EventResult};
-use crate::app::{App, ViewMode};
+use crate::app::{App, StatusLevel, ViewMode};
 use crate::data::SortKey;
+use crate::utils::copy_to_clipboard;
+
+/// Lines moved by PgUp/PgDn in the process detail overlay
+const DETAIL_PAGE: isize = 10;
 
 /// Handle an application event
 pub fn handle_event(app: &mut App, event: AppEvent) -> EventResult {
@@ -18,7 +24,8 @@ pub fn handle_event(app: &mut App, event: AppEvent) -> EventResult {
             EventResult::Continue
         }
         AppEvent::Resize(_, _) => {
-            // UI will handle resize automatically
+            // Layout follows on the next draw; the logo waits for the resize to settle
+            app.pending_resize = Some(Instant::now());
             EventResult::Continue
         }
         AppEvent::Quit => EventResult::Exit,
@@ -27,9 +34,13 @@ pub fn handle_event(app: &mut App, event: AppEvent) -> EventResult {
 
 /// Handle a key event, returns EventResult
 pub fn handle_key(app: &mut App, key: KeyEvent) -> EventResult {
+    app.last_input = Instant::now();
     if app.confirm.is_some() {
         return handle_confirm_key(app, key);
     }
+    if app.detail.is_some() {
+        return handle_detail_key(app, key);
+    }
     if app.show_setup {
         return handle_setup_key(app, key);
     }
@@ -43,7 +54,13 @@ pub fn handle_key(app: &mut App, key: KeyEvent) -> EventResult {
     match key.code {
         KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => EventResult::Exit,
         KeyCode::Char('с') if key.modifiers.contains(KeyModifiers::CONTROL) => EventResult::Exit,
-        KeyCode::Char('q') | KeyCode::Char('й') => EventResult::Exit,
+        KeyCode::Char('q') | KeyCode::Char('й') => {
+            if app.request_quit() {
+                EventResult::Exit
+            } else {
+                EventResult::Continue
+            }
+        }
         KeyCode::F(2) => {
             app.toggle_setup();
             EventResult::Continue
@@ -52,6 +69,10 @@ pub fn handle_key(app: &mut App, key: KeyEvent) -> EventResult {
             app.toggle_help();
             EventResult::Continue
         }
+        KeyCode::F(3) => {
+            app.toggle_debug_overlay();
+            EventResult::Continue
+        }
         KeyCode::Up => {
             if app.view_mode == ViewMode::Container {
                 app.move_container_selection(-1);
@@ -98,7 +119,7 @@ pub fn handle_key(app: &mut App, key: KeyEvent) -> EventResult {
             } else if matches!(app.view_mode, ViewMode::Overview | ViewMode::Processes) {
                 let delta = page_delta(app.process_body);
                 if delta > 0 {
-                    app.move_selection(-delta);
+                    app.page_selection(-delta);
                 }
             }
             EventResult::Continue
@@ -112,7 +133,7 @@ pub fn handle_key(app: &mut App, key: KeyEvent) -> EventResult {
             } else if matches!(app.view_mode, ViewMode::Overview | ViewMode::Processes) {
                 let delta = page_delta(app.process_body);
                 if delta > 0 {
-                    app.move_selection(delta);
+                    app.page_selection(delta);
                 }
             }
             EventResult::Continue
@@ -129,13 +150,28 @@ pub fn handle_key(app: &mut App, key: KeyEvent) -> EventResult {
             }
             EventResult::Continue
         }
+        KeyCode::Left
+            if key.modifiers.contains(KeyModifiers::SHIFT)
+                && matches!(app.view_mode, ViewMode::Overview | ViewMode::Processes) =>
+        {
+            app.process_hscroll = app.process_hscroll.saturating_sub(1);
+            EventResult::Continue
+        }
+        KeyCode::Right
+            if key.modifiers.contains(KeyModifiers::SHIFT)
+                && matches!(app.view_mode, ViewMode::Overview | ViewMode::Processes) =>
+        {
+            // The table clamps the offset so NAME and one other column stay visible
+            app.process_hscroll = app.process_hscroll.saturating_add(1);
+            EventResult::Continue
+        }
         KeyCode::Left => {
             if app.view_mode == ViewMode::SystemInfo {
                 app.prev_system_tab();
             } else if app.view_mode == ViewMode::GpuFocus {
                 app.set_gpu_process_sort_key(app.gpu_process_sort_key.prev());
             } else {
-                app.set_sort_key(app.sort_key.prev());
+                app.cycle_sort_key(false);
             }
             EventResult::Continue
         }
@@ -145,11 +181,21 @@ pub fn handle_key(app: &mut App, key: KeyEvent) -> EventResult {
             } else if app.view_mode == ViewMode::GpuFocus {
                 app.set_gpu_process_sort_key(app.gpu_process_sort_key.next());
             } else {
-                app.set_sort_key(app.sort_key.next());
+                app.cycle_sort_key(true);
             }
             EventResult::Continue
         }
         KeyCode::Char(' ') => {
+            if matches!(app.view_mode, ViewMode::Overview | ViewMode::Processes) {
+                app.toggle_mark();
+            } else if app.view_mode == ViewMode::GpuFocus {
+                app.toggle_gpu_process_sort_dir();
+            } else {
+                app.toggle_sort_dir();
+            }
+            EventResult::Continue
+        }
+        KeyCode::Char('i') | KeyCode::Char('ш') => {
             if app.view_mode == ViewMode::GpuFocus {
                 app.toggle_gpu_process_sort_dir();
             } else {
@@ -157,6 +203,14 @@ pub fn handle_key(app: &mut App, key: KeyEvent) -> EventResult {
             }
             EventResult::Continue
         }
+        KeyCode::Char('k') | KeyCode::Char('л') => {
+            app.open_confirm_marked();
+            EventResult::Continue
+        }
+        KeyCode::Char('v') | KeyCode::Char('м') => {
+            app.clear_marks();
+            EventResult::Continue
+        }
         KeyCode::Enter => {
             if app.view_mode == ViewMode::Container {
                 app.enter_container();
@@ -213,6 +267,18 @@ pub fn handle_key(app: &mut App, key: KeyEvent) -> EventResult {
             app.cycle_highlight_mode();
             EventResult::Continue
         }
+        KeyCode::Char('n') | KeyCode::Char('т')
+            if app.search_highlight && !app.process_filter.trim().is_empty() =>
+        {
+            app.select_search_match(true, false);
+            EventResult::Continue
+        }
+        KeyCode::Char('N') | KeyCode::Char('Т')
+            if app.search_highlight && !app.process_filter.trim().is_empty() =>
+        {
+            app.select_search_match(false, false);
+            EventResult::Continue
+        }
         KeyCode::Char('n') | KeyCode::Char('т') => {
             app.set_sort_key(SortKey::Name);
             EventResult::Continue
@@ -225,6 +291,14 @@ pub fn handle_key(app: &mut App, key: KeyEvent) -> EventResult {
             }
             EventResult::Continue
         }
+        KeyCode::Char('R') | KeyCode::Char('К') => {
+            if app.view_mode == ViewMode::GpuFocus {
+                app.reset_gpu_process_sort();
+            } else {
+                app.reset_sort();
+            }
+            EventResult::Continue
+        }
         KeyCode::Char('g') | KeyCode::Char('п') => {
             app.select_next_gpu();
             EventResult::Continue
@@ -233,6 +307,105 @@ pub fn handle_key(app: &mut App, key: KeyEvent) -> EventResult {
             app.toggle_tree_view();
             EventResult::Continue
         }
+        KeyCode::Char('[') | KeyCode::Char('х') if app.tree_view => {
+            app.select_parent();
+            EventResult::Continue
+        }
+        KeyCode::Char(']') | KeyCode::Char('ъ') if app.tree_view => {
+            app.select_first_child();
+            EventResult::Continue
+        }
+        KeyCode::Char('z') | KeyCode::Char('я') => {
+            if app.tree_view {
+                app.toggle_tree_collapse();
+            } else {
+                app.toggle_group_expand();
+            }
+            EventResult::Continue
+        }
+        KeyCode::Char('w') | KeyCode::Char('ц') => {
+            app.reset_peaks();
+            EventResult::Continue
+        }
+        KeyCode::Char('x') | KeyCode::Char('ч') => {
+            app.toggle_process_grouping();
+            EventResult::Continue
+        }
+        KeyCode::Char('-') => {
+            app.collapse_all_tree();
+            EventResult::Continue
+        }
+        KeyCode::Char('+') | KeyCode::Char('=') => {
+            app.expand_all_tree();
+            EventResult::Continue
+        }
+        KeyCode::Char('y') | KeyCode::Char('н') if app.view_mode == ViewMode::SystemInfo => {
+            let text = crate::ui::system_overview_text(app);
+            match copy_to_clipboard(&text) {
+                Ok(()) => app.set_status(
+                    StatusLevel::Info,
+                    format!("Copied system info ({} lines)", text.lines().count()),
+                ),
+                Err(err) => {
+                    app.set_status(StatusLevel::Warn, format!("Clipboard copy failed: {err}"))
+                }
+            }
+            EventResult::Continue
+        }
+        KeyCode::Char('d') | KeyCode::Char('в') if app.view_mode == ViewMode::SystemInfo => {
+            app.toggle_disk_label_mode();
+            EventResult::Continue
+        }
+        KeyCode::Char('l') | KeyCode::Char('д') if app.view_mode == ViewMode::SystemInfo => {
+            app.toggle_temps_sort();
+            EventResult::Continue
+        }
+        KeyCode::Char('f') | KeyCode::Char('а') if app.view_mode == ViewMode::SystemInfo => {
+            app.toggle_show_all_fs();
+            EventResult::Continue
+        }
+        KeyCode::Char('e') | KeyCode::Char('у')
+            if matches!(app.view_mode, ViewMode::Overview | ViewMode::Processes) =>
+        {
+            app.open_process_detail();
+            EventResult::Continue
+        }
+        KeyCode::Char('O') | KeyCode::Char('Щ')
+            if matches!(app.view_mode, ViewMode::Overview | ViewMode::Processes) =>
+        {
+            app.open_selected_cwd();
+            EventResult::Continue
+        }
+        KeyCode::Char('F') | KeyCode::Char('А')
+            if matches!(app.view_mode, ViewMode::Overview | ViewMode::Processes) =>
+        {
+            app.toggle_follow();
+            EventResult::Continue
+        }
+        KeyCode::Char('o') | KeyCode::Char('щ') => {
+            app.toggle_show_ignored();
+            EventResult::Continue
+        }
+        KeyCode::Char('S') | KeyCode::Char('Ы') if app.view_mode == ViewMode::Overview => {
+            app.toggle_search_panel();
+            EventResult::Continue
+        }
+        KeyCode::Char('s') | KeyCode::Char('ы') => {
+            app.toggle_search_highlight();
+            EventResult::Continue
+        }
+        KeyCode::Char('U') | KeyCode::Char('Г') => {
+            app.toggle_byte_unit();
+            EventResult::Continue
+        }
+        KeyCode::Char('C') | KeyCode::Char('С') => {
+            app.toggle_temp_unit();
+            EventResult::Continue
+        }
+        KeyCode::Char('a') | KeyCode::Char('ф') => {
+            app.show_start_time = !app.show_start_time;
+            EventResult::Continue
+        }
         KeyCode::Char('1') => {
             app.set_view_mode(ViewMode::Overview);
             EventResult::Continue
@@ -294,6 +467,10 @@ pub fn handle_key(app: &mut App, key: KeyEvent) -> EventResult {
             app.select_prev_gpu();
             EventResult::Continue
         }
+        KeyCode::Char('P') | KeyCode::Char('З') => {
+            app.pin_selected_gpu();
+            EventResult::Continue
+        }
         _ => EventResult::Continue,
     }
 }
@@ -332,20 +509,17 @@ fn handle_process_filter_input(app: &mut App, key: KeyEvent) -> EventResult {
                 app.update_rows();
             }
         }
-        KeyCode::Backspace => {
-            if !app.process_filter.is_empty() {
-                app.process_filter.pop();
-                app.update_rows();
-            }
+        KeyCode::Backspace if !app.process_filter.is_empty() => {
+            app.process_filter.pop();
+            app.process_filter_changed();
         }
-        KeyCode::Char(ch) => {
+        KeyCode::Char(ch)
             if !key.modifiers.contains(KeyModifiers::CONTROL)
                 && !key.modifiers.contains(KeyModifiers::ALT)
-                && app.process_filter_type.validate_char(ch)
-            {
-                app.process_filter.push(ch);
-                app.update_rows();
-            }
+                && app.process_filter_type.validate_char(ch) =>
+        {
+            app.process_filter.push(ch);
+            app.process_filter_changed();
         }
         _ => {}
     }
@@ -405,6 +579,65 @@ fn handle_setup_key(app: &mut App, key: KeyEvent) -> EventResult {
     }
 }
 
+fn handle_detail_key(app: &mut App, key: KeyEvent) -> EventResult {
+    let Some(detail) = app.detail.as_mut() else {
+        return EventResult::Continue;
+    };
+    match key.code {
+        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => EventResult::Exit,
+        KeyCode::Char('с') if key.modifiers.contains(KeyModifiers::CONTROL) => EventResult::Exit,
+        KeyCode::Esc
+        | KeyCode::Char('e')
+        | KeyCode::Char('у')
+        | KeyCode::Char('q')
+        | KeyCode::Char('й') => {
+            app.detail = None;
+            EventResult::Continue
+        }
+        KeyCode::Up => {
+            detail.move_cursor(-1);
+            EventResult::Continue
+        }
+        KeyCode::Down => {
+            detail.move_cursor(1);
+            EventResult::Continue
+        }
+        KeyCode::PageUp => {
+            detail.move_cursor(-DETAIL_PAGE);
+            EventResult::Continue
+        }
+        KeyCode::PageDown => {
+            detail.move_cursor(DETAIL_PAGE);
+            EventResult::Continue
+        }
+        KeyCode::Home => {
+            detail.cursor = 0;
+            EventResult::Continue
+        }
+        KeyCode::End => {
+            detail.move_cursor(isize::MAX);
+            EventResult::Continue
+        }
+        KeyCode::Char('p') | KeyCode::Char('з') => {
+            app.detail_full_path = !app.detail_full_path;
+            EventResult::Continue
+        }
+        KeyCode::Char('y') | KeyCode::Char('н') => {
+            let Some(value) = detail.current_value().map(str::to_string) else {
+                return EventResult::Continue;
+            };
+            match copy_to_clipboard(&value) {
+                Ok(()) => app.set_status(StatusLevel::Info, format!("Copied: {value}")),
+                Err(err) => {
+                    app.set_status(StatusLevel::Warn, format!("Clipboard copy failed: {err}"))
+                }
+            }
+            EventResult::Continue
+        }
+        _ => EventResult::Continue,
+    }
+}
+
 fn handle_help_key(app: &mut App, key: KeyEvent) -> EventResult {
     match key.code {
         KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => EventResult::Exit,
@@ -413,12 +646,42 @@ fn handle_help_key(app: &mut App, key: KeyEvent) -> EventResult {
             app.toggle_help();
             EventResult::Continue
         }
+        KeyCode::Up => {
+            app.scroll_help(-1);
+            EventResult::Continue
+        }
+        KeyCode::Down => {
+            app.scroll_help(1);
+            EventResult::Continue
+        }
+        KeyCode::PageUp => {
+            app.scroll_help(-(app.help_page.saturating_sub(1).max(1) as isize));
+            EventResult::Continue
+        }
+        KeyCode::PageDown => {
+            app.scroll_help(app.help_page.saturating_sub(1).max(1) as isize);
+            EventResult::Continue
+        }
+        KeyCode::Home => {
+            app.help_scroll = 0;
+            EventResult::Continue
+        }
+        KeyCode::End => {
+            app.help_scroll = usize::MAX;
+            EventResult::Continue
+        }
         _ => EventResult::Continue,
     }
 }
 
 fn handle_mouse(app: &mut App, mouse: MouseEvent) -> EventResult {
-    if app.tree_view || app.show_help || app.show_setup || app.confirm.is_some() {
+    app.last_input = Instant::now();
+    if app.tree_view
+        || app.show_help
+        || app.show_setup
+        || app.confirm.is_some()
+        || app.detail.is_some()
+    {
         return EventResult::Continue;
     }
 
@@ -463,8 +726,11 @@ fn handle_mouse(app: &mut App, mouse: MouseEvent) -> EventResult {
             {
                 let row_index = (mouse.row - body.y) as usize;
                 let index = app.scroll.saturating_add(row_index);
-                if index < app.rows.len() {
+                if let Some(pid) = app.rows.get(index).map(|row| row.pid) {
                     app.select_process_row(index);
+                    if app.register_row_click(pid, Instant::now()) {
+                        app.open_confirm_for_pid(pid);
+                    }
                 }
                 return EventResult::Continue;
             }
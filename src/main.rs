@@ -1,5 +1,5 @@
 use std::error::Error;
-use std::io;
+use std::io::{self, IsTerminal};
 use std::time::{Duration, Instant};
 
 use crossterm::cursor::Show;
@@ -16,11 +16,14 @@ use rtop::ui;
 
 type AppTerminal = Terminal<CrosstermBackend<io::Stdout>>;
 
+/// Redraw interval while the footer shows the refresh countdown
+const COUNTDOWN_REDRAW: Duration = Duration::from_millis(100);
+
 fn main() -> Result<(), Box<dyn Error>> {
     let config = match Config::from_args() {
         Ok(config) => config,
         Err(message) => {
-            if message.starts_with("Usage:") {
+            if message.starts_with("Usage:") || message == rtop::app::version() {
                 println!("{message}");
                 return Ok(());
             }
@@ -28,12 +31,25 @@ fn main() -> Result<(), Box<dyn Error>> {
             std::process::exit(1);
         }
     };
-    let tick_rate = config.tick_rate;
+    if config.print_keys {
+        print!("{}", rtop::ui::keys::key_reference(config.language));
+        return Ok(());
+    }
+    // Raw mode and the alternate screen fail cryptically on a pipe or file
+    if !io::stdout().is_terminal() {
+        eprintln!(
+            "rtop: stdout is not a terminal, so the interactive view cannot start.\n\
+             Run rtop directly in a terminal; `rtop --keys`, `--help` and `--version` \
+             work when redirected."
+        );
+        std::process::exit(1);
+    }
+    rtop::utils::set_exec_disabled(config.no_exec);
     let mut terminal = setup_terminal()?;
     install_panic_hook();
     let mut app = App::new(config);
 
-    let result = run_app(&mut terminal, &mut app, tick_rate);
+    let result = run_app(&mut terminal, &mut app);
     restore_terminal(&mut terminal)?;
 
     if let Err(err) = result {
@@ -78,15 +94,33 @@ fn restore_terminal_raw() {
     let _ = execute!(stdout, LeaveAlternateScreen, DisableMouseCapture, Show);
 }
 
-fn run_app(terminal: &mut AppTerminal, app: &mut App, tick_rate: Duration) -> io::Result<()> {
-    let mut last_tick = Instant::now();
+fn run_app(terminal: &mut AppTerminal, app: &mut App) -> io::Result<()> {
+    app.last_tick = Instant::now();
+    // When the last input event was read, until the frame reflecting it is drawn
+    let mut input_read_at: Option<Instant> = None;
 
     loop {
         app.tick();
+        let draw_start = Instant::now();
         terminal.draw(|frame| ui::render(frame, app))?;
+        app.perf.record_frame(draw_start.elapsed());
+        if let Some(read_at) = input_read_at.take() {
+            app.perf.latency = read_at.elapsed();
+        }
 
-        let timeout = tick_rate.saturating_sub(last_tick.elapsed());
+        // Re-read every loop so input immediately restores the fast rate
+        let tick_rate = app.effective_tick_rate();
+        let mut timeout = tick_rate.saturating_sub(app.last_tick.elapsed());
+        if app.show_countdown {
+            // Wake up between ticks so the footer countdown keeps moving
+            timeout = timeout.min(COUNTDOWN_REDRAW);
+        }
+        if let Some(settle) = app.resize_settles_in() {
+            // Redraw once the resize settles so the deferred logo rebuild happens
+            timeout = timeout.min(settle);
+        }
         if event::poll(timeout)? {
+            input_read_at = Some(Instant::now());
             let event = match event::read()? {
                 Event::Key(key) if key.kind == KeyEventKind::Press => AppEvent::Key(key),
                 Event::Mouse(mouse) => AppEvent::Mouse(mouse),
@@ -99,11 +133,13 @@ fn run_app(terminal: &mut AppTerminal, app: &mut App, tick_rate: Duration) -> io
             }
         }
 
-        if last_tick.elapsed() >= tick_rate {
+        if app.last_tick.elapsed() >= tick_rate {
+            let refresh_start = Instant::now();
             if handle_event(app, AppEvent::Tick).should_exit() {
                 return Ok(());
             }
-            last_tick = Instant::now();
+            app.perf.refresh = refresh_start.elapsed();
+            app.last_tick = Instant::now();
         }
     }
 }
@@ -6,7 +6,7 @@ use super::text::tr;
 use super::theme::{COLOR_ACCENT, COLOR_HOT, COLOR_MUTED};
 use super::widgets::centered_rect;
 use crate::app::App;
-use crate::utils::format_bytes;
+use crate::utils::format_bytes_with;
 
 pub fn render(frame: &mut Frame, app: &App) {
     let Some(confirm) = app.confirm.as_ref() else {
@@ -22,11 +22,31 @@ pub fn render(frame: &mut Frame, app: &App) {
         .add_modifier(Modifier::BOLD);
     let value_style = Style::default().fg(Color::White);
 
+    let title = if confirm.members.is_empty() {
+        tr(app.language, "Terminate process?", "Завершить процесс?").to_string()
+    } else if confirm.marked {
+        format!(
+            "{} {}?",
+            tr(
+                app.language,
+                "Terminate marked processes:",
+                "Завершить отмеченные процессы:"
+            ),
+            confirm.members.len()
+        )
+    } else {
+        format!(
+            "{} {}?",
+            tr(
+                app.language,
+                "Terminate all processes in group",
+                "Завершить все процессы группы"
+            ),
+            confirm.members.len()
+        )
+    };
     let lines = vec![
-        Line::from(Span::styled(
-            tr(app.language, "Terminate process?", "Завершить процесс?"),
-            title_style,
-        )),
+        Line::from(Span::styled(title, title_style)),
         Line::from(""),
         Line::from(vec![
             Span::styled("PID ", label_style),
@@ -36,7 +56,10 @@ pub fn render(frame: &mut Frame, app: &App) {
             Span::styled(format!("{:>5.1}%", confirm.cpu), value_style),
             Span::raw("  "),
             Span::styled("MEM ", label_style),
-            Span::styled(format_bytes(confirm.mem_bytes), value_style),
+            Span::styled(
+                format_bytes_with(confirm.mem_bytes, app.byte_unit),
+                value_style,
+            ),
         ]),
         Line::from(vec![
             Span::styled(tr(app.language, "Name ", "Имя "), label_style),
@@ -3,9 +3,10 @@ use ratatui::widgets::{Cell, HighlightSpacing, Paragraph, Row, Table, TableState
 
 use super::panel_block;
 use super::text::tr;
-use super::theme::COLOR_MUTED;
+use super::theme::{COLOR_HOT, COLOR_MUTED, COLOR_WARN};
 use crate::app::App;
-use crate::utils::format_bytes;
+use crate::data::ContainerState;
+use crate::utils::{ByteUnit, format_bytes_compact, format_bytes_with, format_duration_short};
 
 pub fn render(frame: &mut Frame, area: Rect, app: &mut App) {
     if area.width == 0 || area.height == 0 {
@@ -38,50 +39,92 @@ pub fn render(frame: &mut Frame, area: Rect, app: &mut App) {
     let table_rows = visible_rows
         .iter()
         .map(|row| {
-            Row::new(vec![
-                row.label.clone(),
-                format!("{:>5.1}", row.cpu),
-                format_bytes(row.mem_bytes),
-                row.proc_count.to_string(),
-                format_net(row.net_bytes_per_sec),
-            ])
+            // Flag containers getting close to their CPU quota (throttling risk)
+            let cpu_style = match row.cpu_quota_usage() {
+                Some(usage) if usage >= 0.9 => Style::default().fg(COLOR_HOT),
+                Some(usage) if usage >= 0.75 => Style::default().fg(COLOR_WARN),
+                _ => Style::default(),
+            };
+            let cpu_limit = row
+                .cpu_limit
+                .map(|cores| format!("{cores:.1} {}", tr(app.language, "cores", "ядер")))
+                .unwrap_or_else(|| "-".to_string());
+            let label = match row.state {
+                ContainerState::Running => row.label.clone(),
+                ContainerState::Paused => {
+                    format!("{} ({})", row.label, tr(app.language, "paused", "пауза"))
+                }
+                ContainerState::Exited => {
+                    format!(
+                        "{} ({})",
+                        row.label,
+                        tr(app.language, "stopped", "остановлен")
+                    )
+                }
+            };
+            let mut cells = vec![
+                Cell::from(label),
+                Cell::from(format!("{:>5.1}", row.cpu)).style(cpu_style),
+                Cell::from(cpu_limit),
+                Cell::from(format_mem(row.mem_bytes, row.mem_limit, app.byte_unit)),
+                Cell::from(row.proc_count.to_string()),
+                Cell::from(format_net(row.net_bytes_per_sec, app.byte_unit)),
+            ];
+            if app.container_uptime {
+                cells.push(Cell::from(match row.state {
+                    ContainerState::Exited => "-".to_string(),
+                    _ => format_duration_short(row.uptime_secs),
+                }));
+            }
+            // Stopped and paused containers are listed for completeness only
+            match row.state {
+                ContainerState::Running => Row::new(cells),
+                _ => Row::new(cells).style(Style::default().fg(COLOR_MUTED)),
+            }
         })
         .collect::<Vec<_>>();
 
-    let header = Row::new(vec![
+    let mut header_cells = vec![
         Cell::from(tr(app.language, "CONTAINER", "КОНТЕЙНЕР")),
         Cell::from("CPU%"),
+        Cell::from(tr(app.language, "CPU LIM", "ЛИМ CPU")),
         Cell::from(tr(app.language, "MEM", "ПАМ")),
         Cell::from(tr(app.language, "PROCS", "ПРОЦ")),
         Cell::from(tr(app.language, "NET", "СЕТЬ")),
-    ])
-    .style(
+    ];
+    let mut widths = vec![
+        Constraint::Min(14),
+        Constraint::Length(6),
+        Constraint::Length(10),
+        Constraint::Length(21),
+        Constraint::Length(7),
+        Constraint::Length(9),
+    ];
+    if app.container_uptime {
+        header_cells.push(Cell::from(tr(app.language, "UPTIME", "АПТАЙМ")));
+        widths.push(Constraint::Length(8));
+    }
+    let header = Row::new(header_cells).style(
         Style::default()
             .fg(COLOR_MUTED)
             .add_modifier(Modifier::BOLD),
     );
 
-    let table = Table::new(
-        table_rows,
-        [
-            Constraint::Min(14),
-            Constraint::Length(6),
-            Constraint::Length(10),
-            Constraint::Length(7),
-            Constraint::Length(9),
-        ],
-    )
-    .header(header)
-    .block(panel_block(tr(app.language, "Containers", "Контейнеры")))
-    .column_spacing(1)
-    .row_highlight_style(
-        Style::default()
-            .fg(Color::White)
-            .bg(Color::Rgb(40, 48, 58))
-            .add_modifier(Modifier::BOLD),
-    )
-    .highlight_symbol("> ")
-    .highlight_spacing(HighlightSpacing::Always);
+    let table = Table::new(table_rows, widths)
+        .header(header)
+        .block(
+            panel_block(tr(app.language, "Containers", "Контейнеры"))
+                .title_bottom(totals_line(app)),
+        )
+        .column_spacing(1)
+        .row_highlight_style(
+            Style::default()
+                .fg(Color::White)
+                .bg(Color::Rgb(40, 48, 58))
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("> ")
+        .highlight_spacing(HighlightSpacing::Always);
 
     let mut state = TableState::default();
     if let Some(selected) = app.container_table_state.selected()
@@ -94,27 +137,48 @@ pub fn render(frame: &mut Frame, area: Rect, app: &mut App) {
     frame.render_stateful_widget(table, area, &mut state);
 }
 
-fn format_net(value: Option<u64>) -> String {
-    let Some(bytes_per_sec) = value else {
-        return "-".to_string();
-    };
-    const UNIT: f64 = 1024.0;
-    let value = bytes_per_sec as f64;
-    if value < UNIT {
-        return format!("{value:.0}B/s");
-    }
-    let kb = value / UNIT;
-    if kb < UNIT {
-        return format!("{kb:.1}K/s");
-    }
-    let mb = kb / UNIT;
-    if mb < UNIT {
-        return format!("{mb:.1}M/s");
+/// " 3 containers | CPU 12.5% | MEM 1.2 GiB | NET 4.0K/s "
+fn totals_line(app: &App) -> Line<'static> {
+    let totals = &app.container_totals;
+    let muted = Style::default().fg(COLOR_MUTED);
+    let separator = Span::styled(" | ", muted);
+    Line::from(vec![
+        Span::raw(format!(
+            " {} {}",
+            totals.count,
+            tr(app.language, "containers", "контейнеров")
+        )),
+        separator.clone(),
+        Span::raw(format!("CPU {:.1}%", totals.cpu)),
+        separator.clone(),
+        Span::raw(format!(
+            "{} {}",
+            tr(app.language, "MEM", "ПАМ"),
+            format_bytes_with(totals.mem_bytes, app.byte_unit)
+        )),
+        separator,
+        Span::raw(format!(
+            "{} {} ",
+            tr(app.language, "NET", "СЕТЬ"),
+            format_net(totals.net_bytes_per_sec, app.byte_unit)
+        )),
+    ])
+}
+
+fn format_mem(used: u64, limit: Option<u64>, unit: ByteUnit) -> String {
+    match limit {
+        Some(limit) => format!(
+            "{} / {}",
+            format_bytes_with(used, unit),
+            format_bytes_with(limit, unit)
+        ),
+        None => format_bytes_with(used, unit),
     }
-    let gb = mb / UNIT;
-    if gb < UNIT {
-        return format!("{gb:.1}G/s");
+}
+
+fn format_net(value: Option<u64>, unit: ByteUnit) -> String {
+    match value {
+        Some(bytes_per_sec) => format!("{}/s", format_bytes_compact(bytes_per_sec, unit)),
+        None => "-".to_string(),
     }
-    let tb = gb / UNIT;
-    format!("{tb:.1}T/s")
 }
@@ -0,0 +1,51 @@
+use ratatui::prelude::*;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+
+use super::theme::{COLOR_BORDER, COLOR_MUTED};
+use crate::app::App;
+
+const WIDTH: u16 = 22;
+
+/// Draw/refresh timings in the top-right corner (`--debug` / F3).
+pub fn render(frame: &mut Frame, app: &App) {
+    if !app.debug_overlay {
+        return;
+    }
+    let perf = &app.perf;
+    let millis =
+        |duration: std::time::Duration| format!("{:.1} ms", duration.as_secs_f64() * 1000.0);
+    let rows = [
+        ("fps", format!("{:.1}", perf.fps)),
+        ("draw", millis(perf.draw)),
+        ("refresh", millis(perf.refresh)),
+        ("latency", millis(perf.latency)),
+        ("rows", app.rows.len().to_string()),
+    ];
+
+    let size = frame.area();
+    let area = Rect {
+        x: size.right().saturating_sub(WIDTH + 1),
+        y: size.y + 1,
+        width: WIDTH.min(size.width),
+        height: (rows.len() as u16 + 2).min(size.height),
+    };
+    frame.render_widget(Clear, area);
+
+    let label_style = Style::default().fg(COLOR_MUTED);
+    let value_style = Style::default().fg(Color::White);
+    let lines = rows
+        .into_iter()
+        .map(|(label, value)| {
+            Line::from(vec![
+                Span::styled(format!("{label:<9}"), label_style),
+                Span::styled(format!("{value:>9}"), value_style),
+            ])
+        })
+        .collect::<Vec<_>>();
+    let block = Block::default()
+        .title(" debug ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(COLOR_BORDER));
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
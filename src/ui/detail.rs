@@ -0,0 +1,211 @@
+use std::collections::VecDeque;
+use std::path::Path;
+
+use ratatui::prelude::*;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Sparkline};
+
+use super::text::tr;
+use super::theme::{COLOR_ACCENT, COLOR_BORDER, COLOR_MUTED, color_for_percent};
+use super::widgets::centered_rect;
+use crate::app::{App, DetailField, Language};
+use crate::utils::fit_text;
+
+const LABEL_WIDTH: usize = 9;
+
+pub fn render(frame: &mut Frame, app: &App) {
+    let Some(detail) = app.detail.as_ref() else {
+        return;
+    };
+
+    let area = centered_rect(80, 80, frame.area());
+    frame.render_widget(Clear, area);
+
+    let key_style = Style::default()
+        .fg(COLOR_ACCENT)
+        .add_modifier(Modifier::BOLD);
+    let label_style = Style::default()
+        .fg(COLOR_MUTED)
+        .add_modifier(Modifier::BOLD);
+    let value_style = Style::default().fg(Color::White);
+    let cursor_style = Style::default()
+        .fg(Color::White)
+        .bg(Color::Rgb(40, 48, 58))
+        .add_modifier(Modifier::BOLD);
+
+    let width = area.width.saturating_sub(2) as usize;
+    let value_width = width.saturating_sub(LABEL_WIDTH);
+
+    // Body rows plus the index of the row holding the cursor
+    let mut rows = Vec::with_capacity(detail.lines.len() + 1);
+    let mut cursor_row = 0;
+    for (idx, (field, value)) in detail.lines.iter().enumerate() {
+        if *field == DetailField::Env && (idx == 0 || detail.lines[idx - 1].0 != DetailField::Env) {
+            rows.push(Line::from(Span::styled(
+                tr(app.language, "Environment", "Окружение"),
+                key_style,
+            )));
+        }
+        if idx == detail.cursor {
+            cursor_row = rows.len();
+        }
+        let (label, value) = if *field == DetailField::Env {
+            (String::new(), fit_text(value, width))
+        } else if *field == DetailField::Exe && !app.detail_full_path {
+            (
+                format!("{:<LABEL_WIDTH$}", field_label(*field, app.language)),
+                fit_text(exe_basename(value), value_width),
+            )
+        } else {
+            (
+                format!("{:<LABEL_WIDTH$}", field_label(*field, app.language)),
+                fit_text(value, value_width),
+            )
+        };
+        let line = Line::from(vec![
+            Span::styled(label, label_style),
+            Span::styled(value, value_style),
+        ]);
+        rows.push(if idx == detail.cursor {
+            line.style(cursor_style)
+        } else {
+            line
+        });
+    }
+
+    // CPU history sits just above the key hints once there is a sample
+    let history = app
+        .process_cpu_history
+        .get(&detail.pid)
+        .filter(|history| !history.is_empty());
+    let history_rows = usize::from(history.is_some());
+
+    // Keep the cursor in view; the last inner row holds the key hints
+    let body_height = (area.height.saturating_sub(3) as usize).saturating_sub(history_rows);
+    let offset = cursor_row.saturating_sub(body_height.saturating_sub(1));
+    let mut lines = rows
+        .into_iter()
+        .skip(offset)
+        .take(body_height)
+        .collect::<Vec<_>>();
+    lines.resize(body_height, Line::from(""));
+    if let Some(history) = history {
+        let current = history.back().copied().unwrap_or(0.0);
+        lines.push(Line::from(vec![
+            Span::styled(format!("{:<LABEL_WIDTH$}", "CPU"), label_style),
+            Span::styled(format!("{current:>5.1}% "), value_style),
+        ]));
+    }
+    lines.push(Line::from(vec![
+        Span::styled("up/down", key_style),
+        Span::styled(
+            format!(" {}  ", tr(app.language, "move", "перемест.")),
+            label_style,
+        ),
+        Span::styled("p/з", key_style),
+        Span::styled(
+            format!(
+                " {}  ",
+                if app.detail_full_path {
+                    tr(app.language, "name only", "только имя")
+                } else {
+                    tr(app.language, "full path", "полный путь")
+                }
+            ),
+            label_style,
+        ),
+        Span::styled("y/н", key_style),
+        Span::styled(
+            format!(" {}  ", tr(app.language, "copy line", "копировать строку")),
+            label_style,
+        ),
+        Span::styled("Esc", key_style),
+        Span::styled(
+            format!(" {}", tr(app.language, "close", "закрыть")),
+            label_style,
+        ),
+    ]));
+
+    let title = format!(
+        " {} {} ",
+        tr(app.language, "Process", "Процесс"),
+        detail.pid
+    );
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(COLOR_BORDER))
+        .title_style(key_style);
+    let inner = block.inner(area);
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+
+    if let Some(history) = history {
+        // Label and current value take LABEL_WIDTH + 7 columns
+        let offset = (LABEL_WIDTH + 7) as u16;
+        let spark_area = Rect {
+            x: inner.x + offset.min(inner.width),
+            y: inner.y + body_height as u16,
+            width: inner.width.saturating_sub(offset),
+            height: 1,
+        };
+        if spark_area.width > 0 && spark_area.y < inner.bottom() {
+            render_cpu_history(frame, spark_area, history);
+        }
+    }
+}
+
+/// File name of an executable path ("/usr/lib/firefox/firefox" -> "firefox");
+/// values that are not paths ("-", "N/A") are kept as they are.
+fn exe_basename(value: &str) -> &str {
+    Path::new(value)
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or(value)
+}
+
+/// Sparkline of the newest samples that fit; per-process CPU% may exceed
+/// 100 on multi-core systems, so the scale grows with the peak.
+fn render_cpu_history(frame: &mut Frame, area: Rect, history: &VecDeque<f32>) {
+    let skip = history.len().saturating_sub(area.width as usize);
+    let data = history
+        .iter()
+        .skip(skip)
+        .map(|cpu| (cpu.max(0.0) * 10.0).round() as u64)
+        .collect::<Vec<_>>();
+    let max = data.iter().copied().max().unwrap_or(0).max(1000);
+    let current = history.back().copied().unwrap_or(0.0);
+    let sparkline = Sparkline::default()
+        .data(&data)
+        .max(max)
+        .style(Style::default().fg(color_for_percent(current)));
+    frame.render_widget(sparkline, area);
+}
+
+fn field_label(field: DetailField, language: Language) -> &'static str {
+    match field {
+        DetailField::Pid => "PID",
+        DetailField::Name => tr(language, "Name", "Имя"),
+        DetailField::User => tr(language, "User", "Юзер"),
+        DetailField::Exe => tr(language, "Exe", "Файл"),
+        DetailField::Cwd => tr(language, "Cwd", "Каталог"),
+        DetailField::Cgroup => "Cgroup",
+        DetailField::Affinity => tr(language, "Affinity", "Привязка"),
+        DetailField::Command => tr(language, "Command", "Команда"),
+        DetailField::Env => "",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exe_basename_strips_directories() {
+        assert_eq!(
+            exe_basename("/var/lib/docker/overlay2/abc/merged/usr/bin/python3"),
+            "python3"
+        );
+        assert_eq!(exe_basename("firefox"), "firefox");
+        assert_eq!(exe_basename("-"), "-");
+    }
+}
@@ -1,3 +1,5 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
 use ratatui::prelude::*;
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, BorderType, Borders, Paragraph};
@@ -5,6 +7,69 @@ use ratatui::widgets::{Block, BorderType, Borders, Paragraph};
 use super::text::tr;
 use super::theme::{COLOR_ACCENT, COLOR_BORDER, COLOR_MUTED};
 use crate::app::{App, ViewMode};
+use crate::utils::{ByteUnit, format_bytes_with};
+
+/// Seconds each rotating hint stays on screen
+const HINT_ROTATE_SECS: u64 = 4;
+
+/// (key, English, Russian) hints cycled on the first footer line
+type Hint = (&'static str, &'static str, &'static str);
+
+const PROCESS_HINTS: &[Hint] = &[
+    ("/", "search", "поиск"),
+    ("e/у", "details", "детали"),
+    ("x/ч", "group", "группы"),
+    ("o/щ", "ignored", "игнорируемые"),
+    ("z/я", "fold", "свернуть"),
+    ("a/ф", "uptime/start", "аптайм/запуск"),
+    ("h/р", "highlight", "подсветка"),
+    ("space", "mark", "отметить"),
+    ("k/л", "kill marked", "убить отмеч."),
+    ("shift+left/right", "scroll columns", "прокрутка"),
+];
+
+const GPU_HINTS: &[Hint] = &[
+    ("g/п", "next GPU", "след. GPU"),
+    ("G/П", "prev GPU", "пред. GPU"),
+    ("P/З", "pin GPU", "закрепить GPU"),
+    ("w/ц", "reset peaks", "сброс пиков"),
+];
+
+const SYSTEM_HINTS: &[Hint] = &[
+    ("y/н", "copy", "копировать"),
+    ("f/а", "all filesystems", "все ФС"),
+    ("d/в", "mount/device", "точка/устр."),
+    ("l/д", "sort temps", "сорт. темп."),
+];
+
+const CONTAINER_HINTS: &[Hint] = &[
+    ("1", "overview", "обзор"),
+    ("2", "system", "система"),
+    ("3", "GPU", "GPU"),
+];
+
+fn view_hints(mode: ViewMode) -> &'static [Hint] {
+    match mode {
+        ViewMode::Overview | ViewMode::Processes => PROCESS_HINTS,
+        ViewMode::GpuFocus => GPU_HINTS,
+        ViewMode::SystemInfo => SYSTEM_HINTS,
+        ViewMode::Container => CONTAINER_HINTS,
+    }
+}
+
+/// Hint for the current view, advancing every `HINT_ROTATE_SECS`.
+fn rotating_hint(mode: ViewMode, now_secs: u64) -> Option<&'static Hint> {
+    let hints = view_hints(mode);
+    if hints.is_empty() {
+        return None;
+    }
+    hints.get((now_secs / HINT_ROTATE_SECS) as usize % hints.len())
+}
+
+/// rtop's own footprint, e.g. "rtop: 0.3% · 12.0 MiB"
+fn self_usage_label(cpu: f32, mem_bytes: u64, unit: ByteUnit) -> String {
+    format!("rtop: {cpu:.1}% · {}", format_bytes_with(mem_bytes, unit))
+}
 
 pub fn render(frame: &mut Frame, area: Rect, app: &App) {
     let key_style = Style::default()
@@ -34,6 +99,12 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
         } else {
             None
         };
+        // Space marks processes in the process views
+        let order_key = if matches!(app.view_mode, ViewMode::Overview | ViewMode::Processes) {
+            "i/ш"
+        } else {
+            "space"
+        };
         let mut second_line = vec![
             Span::styled("up/down", key_style),
             Span::styled(
@@ -45,7 +116,7 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
                 format!(" {}  ", tr(app.language, "column", "колонка")),
                 hint_style,
             ),
-            Span::styled("space", key_style),
+            Span::styled(order_key, key_style),
             Span::styled(
                 format!(" {}  ", tr(app.language, "order", "порядок")),
                 hint_style,
@@ -74,31 +145,62 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
                 hint_style,
             ));
         }
-        vec![
-            Line::from(vec![
-                Span::styled("q/й", key_style),
-                Span::styled(
-                    format!(" {}  ", tr(app.language, "quit", "выход")),
-                    hint_style,
-                ),
-                Span::styled("r/к", key_style),
-                Span::styled(
-                    format!(" {}  ", tr(app.language, "refresh", "обновить")),
-                    hint_style,
-                ),
-                Span::styled("F2", key_style),
-                Span::styled(
-                    format!(" {}  ", tr(app.language, "setup", "настройки")),
-                    hint_style,
-                ),
-                Span::styled("F12", key_style),
-                Span::styled(
-                    format!(" {}", tr(app.language, "help", "справка")),
-                    hint_style,
+        let mut first_line = vec![
+            Span::styled("q/й", key_style),
+            Span::styled(
+                format!(" {}  ", tr(app.language, "quit", "выход")),
+                hint_style,
+            ),
+            Span::styled("r/к", key_style),
+            Span::styled(
+                format!(" {}  ", tr(app.language, "refresh", "обновить")),
+                hint_style,
+            ),
+            Span::styled("F2", key_style),
+            Span::styled(
+                format!(" {}  ", tr(app.language, "setup", "настройки")),
+                hint_style,
+            ),
+            Span::styled("F12", key_style),
+            Span::styled(
+                format!(" {}", tr(app.language, "help", "справка")),
+                hint_style,
+            ),
+        ];
+        // With reduce_motion the first hint stays put instead of rotating
+        let now_secs = if app.reduce_motion {
+            0
+        } else {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|elapsed| elapsed.as_secs())
+                .unwrap_or(0)
+        };
+        if let Some((key, en, ru)) = rotating_hint(app.view_mode, now_secs) {
+            first_line.push(Span::styled("  ", hint_style));
+            first_line.push(Span::styled(*key, key_style));
+            first_line.push(Span::styled(
+                format!(" {}", tr(app.language, en, ru)),
+                hint_style,
+            ));
+        }
+        if app.show_countdown {
+            first_line.push(Span::styled(
+                format!(
+                    "  {}: {:.1}s",
+                    tr(app.language, "next", "след."),
+                    app.next_refresh_in().as_secs_f32()
                 ),
-            ]),
-            Line::from(second_line),
-        ]
+                hint_style,
+            ));
+        }
+        if let Some((cpu, mem_bytes)) = app.self_usage {
+            first_line.push(Span::styled(
+                format!("  {}", self_usage_label(cpu, mem_bytes, app.byte_unit)),
+                hint_style,
+            ));
+        }
+        vec![Line::from(first_line), Line::from(second_line)]
     };
 
     let block = Block::default()
@@ -108,3 +210,36 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
     let paragraph = Paragraph::new(lines).block(block);
     frame.render_widget(paragraph, area);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hints_rotate_per_view() {
+        let first = rotating_hint(ViewMode::GpuFocus, 0).unwrap();
+        assert_eq!(first.0, "g/п");
+        assert_eq!(
+            rotating_hint(ViewMode::GpuFocus, HINT_ROTATE_SECS - 1),
+            Some(first)
+        );
+        assert_eq!(
+            rotating_hint(ViewMode::GpuFocus, HINT_ROTATE_SECS)
+                .unwrap()
+                .0,
+            "G/П"
+        );
+        // Wraps around after the last hint
+        let wrap = HINT_ROTATE_SECS * GPU_HINTS.len() as u64;
+        assert_eq!(rotating_hint(ViewMode::GpuFocus, wrap), Some(first));
+        assert_eq!(rotating_hint(ViewMode::Processes, 0).unwrap().0, "/");
+    }
+
+    #[test]
+    fn self_usage_label_format() {
+        assert_eq!(
+            self_usage_label(0.34, 12 * 1024 * 1024, ByteUnit::Iec),
+            "rtop: 0.3% · 12.0 MiB"
+        );
+    }
+}
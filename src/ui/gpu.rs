@@ -1,14 +1,21 @@
+use std::time::Instant;
+
 use ratatui::prelude::*;
 use ratatui::text::{Line, Span};
-use ratatui::widgets::Paragraph;
+use ratatui::widgets::{Paragraph, Sparkline};
 
 use super::processes;
 use super::text::tr;
-use super::theme::{COLOR_ACCENT, COLOR_MUTED, color_for_percent};
+use super::theme::{COLOR_ACCENT, COLOR_MUTED, alert_flash_style, color_for_percent};
 use super::{panel_block, panel_block_focused};
-use crate::app::{App, GpuFocusPanel};
+use crate::app::{AlertMetric, App, GpuFocusPanel, Language};
 use crate::data::gpu::{gpu_vendor_label, short_device_name};
-use crate::utils::{fit_text, format_bytes, percent, render_bar};
+use crate::utils::{
+    fit_text, format_bytes_with, format_temp_compact, percent, render_bar, text_width,
+};
+
+const MIN_DETAIL_HEIGHT: u16 = 7;
+const MIN_TABLE_HEIGHT: u16 = 6;
 
 pub fn render(frame: &mut Frame, area: Rect, app: &mut App) {
     render_inner(frame, area, app, false);
@@ -32,10 +39,25 @@ fn render_inner(frame: &mut Frame, area: Rect, app: &mut App, _focused: bool) {
         return;
     }
 
-    // Normal mode - both panels
-    const MIN_DETAIL_HEIGHT: u16 = 7;
-    const MIN_TABLE_HEIGHT: u16 = 6;
+    // Compact mode - nothing runs on the selected GPU, so the dashboard and
+    // utilization history take the space of the empty process table
+    let gpu_idle = app
+        .selected_gpu()
+        .is_some_and(|(_, gpu)| app.gpu_process_count(&gpu.id) == 0);
+    if app.gpu_compact && app.vram_enabled && gpu_idle {
+        app.gpu_process_order.clear();
+        app.gpu_process_body = None;
+        app.gpu_process_header_regions.clear();
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(MIN_DETAIL_HEIGHT), Constraint::Min(0)])
+            .split(area);
+        render_dashboard(frame, chunks[0], app, true);
+        render_history(frame, chunks[1], app);
+        return;
+    }
 
+    // Normal mode - both panels
     let detail_height = if area.height > MIN_DETAIL_HEIGHT + MIN_TABLE_HEIGHT {
         MIN_DETAIL_HEIGHT
     } else {
@@ -112,9 +134,14 @@ fn render_dashboard(frame: &mut Frame, area: Rect, app: &App, focused: bool) {
         } else {
             format!("{} {}", vendor_short, device_short)
         };
+        let proc_count = app.gpu_process_count(&gpu.id);
+        let gpu_label = format!(
+            "{gpu_label} · {proc_count} {}",
+            tr(app.language, "procs", "проц.")
+        );
 
         // Alignment: all labels same width
-        let label_width = gpu_label.len() + 2;
+        let label_width = text_width(&gpu_label) + 2;
         let bar_width = calc_bar_width(width, 35);
 
         // Line 1: GPU name + utilization bar | temperature | power
@@ -123,7 +150,7 @@ fn render_dashboard(frame: &mut Frame, area: Rect, app: &App, focused: bool) {
         let temp_str = gpu
             .telemetry
             .temperature_c
-            .map(|t| format!("{:.0}°C", t))
+            .map(|temp| format_temp_compact(temp, app.temp_unit))
             .unwrap_or_else(|| na_label.to_string());
         let power_str = format_power(
             gpu.telemetry.power_draw_w,
@@ -131,12 +158,24 @@ fn render_dashboard(frame: &mut Frame, area: Rect, app: &App, focused: bool) {
             na_label,
         );
 
+        let now = Instant::now();
+        let flash_style = |metric| {
+            if app.alert_flashing(metric, &gpu.id, now) {
+                alert_flash_style()
+            } else {
+                value_style
+            }
+        };
+
         lines.push(Line::from(vec![
             Span::styled(format!("{:<label_width$}", gpu_label), title_style),
             Span::styled(util_bar, Style::default().fg(color_for_percent(util_pct))),
-            Span::styled(format!(" {:>3.0}%", util_pct), value_style),
+            Span::styled(
+                format!(" {:>3.0}%", util_pct),
+                flash_style(AlertMetric::GpuUtil),
+            ),
             Span::styled(" | ", label_style),
-            Span::styled(temp_str, value_style),
+            Span::styled(temp_str, flash_style(AlertMetric::GpuTemp)),
             Span::styled(" | ", label_style),
             Span::styled(power_str, value_style),
         ]));
@@ -147,8 +186,8 @@ fn render_dashboard(frame: &mut Frame, area: Rect, app: &App, focused: bool) {
             let mem_bar = render_bar(mem_pct, bar_width);
             let mem_info = format!(
                 "{} / {}",
-                format_bytes(memory.used_bytes),
-                format_bytes(memory.total_bytes)
+                format_bytes_with(memory.used_bytes, app.byte_unit),
+                format_bytes_with(memory.total_bytes, app.byte_unit)
             );
             lines.push(Line::from(vec![
                 Span::styled(format!("{:<label_width$}", "VRAM"), label_style),
@@ -170,13 +209,40 @@ fn render_dashboard(frame: &mut Frame, area: Rect, app: &App, focused: bool) {
             Span::styled(format!("{:>3.0}%", dec_pct), value_style),
         ]));
 
-        // Line 4: Fan
-        if let Some(fan_pct) = gpu.telemetry.fan_speed_pct {
+        // Line 4: Fans - the bar follows the fastest fan, each fan listed after it
+        let fans = &gpu.telemetry.fans_pct;
+        if let Some(max_fan) = fans.iter().max() {
+            let fan_pct = f32::from(*max_fan);
             let fan_bar = render_bar(fan_pct, bar_width);
+            let fan_info = format_fans(fans);
             lines.push(Line::from(vec![
                 Span::styled(format!("{:<label_width$}", "Fan"), label_style),
                 Span::styled(fan_bar, Style::default().fg(color_for_percent(fan_pct))),
-                Span::styled(format!(" {:>3.0}%", fan_pct), value_style),
+                Span::styled(
+                    fit_text(
+                        &format!(" {fan_info}"),
+                        width.saturating_sub(label_width + bar_width),
+                    ),
+                    value_style,
+                ),
+            ]));
+        }
+
+        // Line 5: Clocks (only the ones the provider reports)
+        if let Some(clocks) = format_clocks(
+            gpu.telemetry.core_clock_mhz,
+            gpu.telemetry.mem_clock_mhz,
+            app.language,
+        ) {
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("{:<label_width$}", tr(app.language, "Clocks", "Частоты")),
+                    label_style,
+                ),
+                Span::styled(
+                    fit_text(&clocks, width.saturating_sub(label_width)),
+                    value_style,
+                ),
             ]));
         }
 
@@ -193,7 +259,11 @@ fn render_dashboard(frame: &mut Frame, area: Rect, app: &App, focused: bool) {
     } else {
         lines.push(Line::from(Span::styled(
             fit_text(
-                tr(app.language, "No GPU detected", "GPU не обнаружен"),
+                if app.gpu_probing() {
+                    tr(app.language, "Probing GPU…", "Опрос GPU…")
+                } else {
+                    tr(app.language, "No GPU detected", "GPU не обнаружен")
+                },
                 width,
             ),
             label_style,
@@ -204,10 +274,114 @@ fn render_dashboard(frame: &mut Frame, area: Rect, app: &App, focused: bool) {
     frame.render_widget(paragraph, inner);
 }
 
+/// Utilization graph for the selected GPU plus a one-line summary per GPU.
+fn render_history(frame: &mut Frame, area: Rect, app: &App) {
+    let block = panel_block(tr(app.language, "GPU History", "История GPU"));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+    if inner.width == 0 || inner.height == 0 {
+        return;
+    }
+
+    let width = inner.width as usize;
+    let label_style = Style::default().fg(COLOR_MUTED);
+    let value_style = Style::default().fg(Color::White);
+    let mut summary = Vec::new();
+    if app.gpu_list.len() > 1 {
+        let selected = app.selected_gpu().map(|(idx, _)| idx);
+        for (idx, gpu) in app.gpu_list.iter().enumerate() {
+            let device_name = gpu.device.as_deref().unwrap_or(&gpu.name);
+            let label = format!(
+                "[{}] {} {}",
+                idx + 1,
+                gpu_vendor_label(gpu),
+                short_device_name(device_name)
+            );
+            let util_pct = gpu.telemetry.utilization_gpu_pct.unwrap_or(0.0);
+            let mut details = format!("{util_pct:>3.0}%");
+            if let Some(memory) = gpu.memory.as_ref() {
+                details.push_str(&format!(
+                    " · VRAM {} / {}",
+                    format_bytes_with(memory.used_bytes, app.byte_unit),
+                    format_bytes_with(memory.total_bytes, app.byte_unit)
+                ));
+            }
+            if let Some(temp) = gpu.telemetry.temperature_c {
+                details.push_str(&format!(" · {}", format_temp_compact(temp, app.temp_unit)));
+            }
+            let label_width = (width / 2).min(text_width(&label) + 2);
+            let label_style = if Some(idx) == selected {
+                Style::default()
+                    .fg(COLOR_ACCENT)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                label_style
+            };
+            summary.push(Line::from(vec![
+                Span::styled(
+                    format!("{:<label_width$}", fit_text(&label, label_width)),
+                    label_style,
+                ),
+                Span::styled(
+                    fit_text(&details, width.saturating_sub(label_width)),
+                    value_style,
+                ),
+            ]));
+        }
+    }
+
+    // Keep at least a few rows for the graph; the summary gets what is left
+    let summary_height = (summary.len() as u16).min(inner.height.saturating_sub(3));
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(summary_height)])
+        .split(inner);
+
+    let samples = app
+        .selected_gpu()
+        .and_then(|(_, gpu)| app.gpu_util_history.get(&gpu.id));
+    let data = samples
+        .map(|history| {
+            let skip = history.len().saturating_sub(width);
+            history.iter().skip(skip).copied().collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    let current = data.last().copied().unwrap_or(0);
+    let sparkline = Sparkline::default()
+        .data(&data)
+        .max(100)
+        .style(Style::default().fg(color_for_percent(current as f32)));
+    frame.render_widget(sparkline, chunks[0]);
+    frame.render_widget(Paragraph::new(summary), chunks[1]);
+}
+
 fn calc_bar_width(total_width: usize, min_tail: usize) -> usize {
     total_width.saturating_sub(min_tail).clamp(10, 24)
 }
 
+/// " 48%" for one fan, "Fan1 48% · Fan2 52%" for several.
+fn format_fans(fans: &[u8]) -> String {
+    match fans {
+        [single] => format!("{single:>3}%"),
+        _ => fans
+            .iter()
+            .enumerate()
+            .map(|(idx, pct)| format!("Fan{} {pct}%", idx + 1))
+            .collect::<Vec<_>>()
+            .join(" · "),
+    }
+}
+
+/// "Core 2100 MHz · Mem 10500 MHz", skipping clocks that are unknown.
+fn format_clocks(core: Option<u32>, mem: Option<u32>, language: Language) -> Option<String> {
+    let parts = [
+        core.map(|mhz| format!("{} {mhz} MHz", tr(language, "Core", "Ядро"))),
+        mem.map(|mhz| format!("{} {mhz} MHz", tr(language, "Mem", "Память"))),
+    ];
+    let parts = parts.into_iter().flatten().collect::<Vec<_>>();
+    (!parts.is_empty()).then(|| parts.join(" · "))
+}
+
 fn format_power(draw: Option<f32>, limit: Option<f32>, na_label: &str) -> String {
     match (draw, limit) {
         (Some(draw), Some(limit)) => format!("{:.0}W/{:.0}W", draw, limit),
@@ -5,12 +5,13 @@ use sysinfo::System;
 
 use super::panel_block;
 use super::text::tr;
-use super::theme::{COLOR_ACCENT, COLOR_MUTED};
-use crate::app::{App, HighlightMode, ViewMode};
-use crate::utils::{format_bytes, format_duration, percent};
+use super::theme::{COLOR_ACCENT, COLOR_HOT, COLOR_MUTED, COLOR_WARN, color_for_percent};
+use crate::app::{App, HighlightMode, ProcessGrouping, ViewMode};
+use crate::utils::{format_bytes_with, format_duration, percent};
 
 pub fn render(frame: &mut Frame, area: Rect, app: &App) {
-    let cpu = app.system.global_cpu_usage();
+    // sysinfo can briefly report no CPUs in some VMs/containers
+    let cpu = (!app.system.cpus().is_empty()).then(|| app.system.global_cpu_usage());
     let total_mem = app.system.total_memory();
     let used_mem = app.system.used_memory();
     let total_swap = app.system.total_swap();
@@ -81,17 +82,32 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
             tree_style,
         ));
     }
-    if let Some(filter) = app.container_filter.as_ref() {
+    if app.process_grouping == ProcessGrouping::ByName && !app.tree_view {
         first_line.push(Span::raw("  "));
         first_line.push(Span::styled(
-            tr(app.language, "container ", "контейнер "),
+            tr(app.language, "group ", "группы "),
             label_style,
         ));
         first_line.push(Span::styled(
-            filter.label(),
+            tr(app.language, "name", "имя"),
             Style::default().fg(COLOR_ACCENT),
         ));
     }
+    if let Some(filter) = app.container_filter.as_ref() {
+        first_line.push(Span::raw("  "));
+        first_line.push(Span::styled(
+            tr(app.language, "container ", "контейнер "),
+            label_style,
+        ));
+        // Prefer the resolved name from the container list
+        let label = app
+            .container_rows
+            .iter()
+            .find(|row| &row.key == filter)
+            .map(|row| row.label.clone())
+            .unwrap_or_else(|| filter.label());
+        first_line.push(Span::styled(label, Style::default().fg(COLOR_ACCENT)));
+    }
     if !app.process_filter.is_empty() {
         first_line.push(Span::raw("  "));
         first_line.push(Span::styled(
@@ -104,40 +120,233 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
         ));
     }
 
-    let lines = vec![
-        Line::from(first_line),
-        Line::from(vec![
-            Span::styled(tr(app.language, "CPU", "CPU"), label_style),
-            Span::styled(format!(" {:>5.1}%  ", cpu), value_style),
-            Span::styled(tr(app.language, "Uptime", "Аптайм"), label_style),
-            Span::styled(format!(" {}", uptime), value_style),
-        ]),
-        Line::from(vec![
-            Span::styled(tr(app.language, "Mem", "ОЗУ"), label_style),
-            Span::styled(
-                format!(
-                    " {} / {} ({:>4.1}%)  ",
-                    format_bytes(used_mem),
-                    format_bytes(total_mem),
-                    mem_pct
-                ),
-                value_style,
+    if app.is_stale() {
+        first_line.push(Span::raw("  "));
+        first_line.push(Span::styled(
+            tr(app.language, "stale", "устарело"),
+            Style::default().fg(COLOR_MUTED).add_modifier(Modifier::DIM),
+        ));
+    }
+
+    let mut second_line = vec![
+        Span::styled(tr(app.language, "CPU", "CPU"), label_style),
+        Span::styled(
+            match cpu {
+                Some(cpu) => format!(" {cpu:>5.1}%  "),
+                None => format!(" {:>6}  ", tr(app.language, "n/a", "н/д")),
+            },
+            value_style,
+        ),
+        Span::styled(tr(app.language, "Uptime", "Аптайм"), label_style),
+        Span::styled(format!(" {}  ", uptime), value_style),
+        Span::styled(tr(app.language, "Peak", "Пик"), label_style),
+        Span::styled(
+            format!(
+                " CPU {:.1}%  {} {:.1}%",
+                app.peak_cpu,
+                tr(app.language, "Mem", "ОЗУ"),
+                app.peak_mem_pct
             ),
-            Span::styled(tr(app.language, "Swap", "Swap"), label_style),
-            Span::styled(
-                format!(
-                    " {} / {} ({:>4.1}%)  ",
-                    format_bytes(used_swap),
-                    format_bytes(total_swap),
-                    swap_pct
-                ),
-                value_style,
+            value_style,
+        ),
+    ];
+    let inner_width = area.width.saturating_sub(2) as usize;
+    let used_width = Line::from(second_line.clone()).width();
+    second_line.extend(core_spans(
+        app,
+        inner_width.saturating_sub(used_width),
+        label_style,
+        value_style,
+    ));
+
+    let mut third_line = vec![
+        Span::styled(tr(app.language, "Mem", "ОЗУ"), label_style),
+        Span::styled(
+            format!(
+                " {} / {} ({:>4.1}%)  ",
+                format_bytes_with(used_mem, app.byte_unit),
+                format_bytes_with(total_mem, app.byte_unit),
+                mem_pct
+            ),
+            value_style,
+        ),
+        Span::styled(tr(app.language, "Swap", "Swap"), label_style),
+        Span::styled(
+            format!(
+                " {} / {} ({:>4.1}%)  ",
+                format_bytes_with(used_swap, app.byte_unit),
+                format_bytes_with(total_swap, app.byte_unit),
+                swap_pct
             ),
-            Span::styled(tr(app.language, "Procs", "Проц."), label_style),
-            Span::styled(format!(" {}", process_count), value_style),
-        ]),
+            value_style,
+        ),
+        Span::styled(tr(app.language, "Procs", "Проц."), label_style),
+        Span::styled(format!(" {}", process_count), value_style),
+    ];
+    third_line.extend(state_spans(app, value_style));
+
+    let lines = vec![
+        Line::from(first_line),
+        Line::from(second_line),
+        Line::from(third_line),
     ];
 
     let paragraph = Paragraph::new(lines).block(panel_block("Summary"));
     frame.render_widget(paragraph, area);
 }
+
+/// "R:3 S:412 D:1 Z:0 T:2"; nonzero D and Z counts are colored.
+fn state_spans(app: &App, value_style: Style) -> Vec<Span<'static>> {
+    let counts = app.state_counts;
+    let alert = |count: usize, color: Color| {
+        if count > 0 {
+            Style::default().fg(color).add_modifier(Modifier::BOLD)
+        } else {
+            value_style
+        }
+    };
+    vec![
+        Span::styled(
+            format!("  R:{} S:{} ", counts.running, counts.sleeping),
+            value_style,
+        ),
+        Span::styled(
+            format!("D:{}", counts.disk_sleep),
+            alert(counts.disk_sleep, COLOR_WARN),
+        ),
+        Span::raw(" "),
+        Span::styled(
+            format!("Z:{}", counts.zombie),
+            alert(counts.zombie, COLOR_HOT),
+        ),
+        Span::styled(format!(" T:{}", counts.stopped), value_style),
+    ]
+}
+
+const LOAD_GLYPHS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+const HISTOGRAM_BUCKETS: usize = 10;
+
+/// Per-core load: one glyph per core when they fit in `width`, otherwise a
+/// min/avg/max summary with a load histogram. Empty when nothing fits.
+fn core_spans(
+    app: &App,
+    width: usize,
+    label_style: Style,
+    value_style: Style,
+) -> Vec<Span<'static>> {
+    let loads = app
+        .system
+        .cpus()
+        .iter()
+        .map(|cpu| cpu.cpu_usage().clamp(0.0, 100.0))
+        .collect::<Vec<_>>();
+    if loads.is_empty() {
+        return Vec::new();
+    }
+    let label = format!("  {} ", tr(app.language, "Cores", "Ядра"));
+    let label_width = label.chars().count();
+
+    if label_width + loads.len() <= width {
+        let mut spans = vec![Span::styled(label, label_style)];
+        spans.extend(loads.iter().map(|&load| {
+            Span::styled(
+                load_glyph(load / 100.0).to_string(),
+                Style::default().fg(color_for_percent(load)),
+            )
+        }));
+        return spans;
+    }
+
+    let min = loads.iter().copied().fold(f32::INFINITY, f32::min);
+    let max = loads.iter().copied().fold(0.0, f32::max);
+    let avg = loads.iter().sum::<f32>() / loads.len() as f32;
+    let summary = format!("{} min {min:.0}% avg {avg:.0}% max {max:.0}% ", loads.len());
+    let histogram = core_histogram(&loads, HISTOGRAM_BUCKETS);
+    let summary_width = label_width + summary.chars().count();
+    if summary_width > width {
+        return Vec::new();
+    }
+    let mut spans = vec![
+        Span::styled(label, label_style),
+        Span::styled(summary, value_style),
+    ];
+    if summary_width + HISTOGRAM_BUCKETS + 2 <= width {
+        spans.push(Span::styled(
+            format!("[{histogram}]"),
+            Style::default().fg(COLOR_ACCENT),
+        ));
+    }
+    spans
+}
+
+fn load_glyph(fraction: f32) -> char {
+    let idx = (fraction.clamp(0.0, 1.0) * (LOAD_GLYPHS.len() - 1) as f32).round() as usize;
+    LOAD_GLYPHS[idx]
+}
+
+/// Distribution of core loads over `buckets` equal ranges of 0..=100%, one glyph
+/// per bucket scaled to the fullest bucket (spaces for empty buckets).
+fn core_histogram(loads: &[f32], buckets: usize) -> String {
+    if buckets == 0 {
+        return String::new();
+    }
+    let mut counts = vec![0usize; buckets];
+    for &load in loads {
+        let idx = ((load.clamp(0.0, 100.0) / 100.0) * buckets as f32) as usize;
+        counts[idx.min(buckets - 1)] += 1;
+    }
+    let peak = counts.iter().copied().max().unwrap_or(0).max(1);
+    counts
+        .iter()
+        .map(|&count| {
+            if count == 0 {
+                ' '
+            } else {
+                load_glyph(count as f32 / peak as f32)
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::Config;
+    use ratatui::backend::TestBackend;
+    use sysinfo::System;
+
+    #[test]
+    fn core_histogram_buckets_loads() {
+        let loads = [0.0, 5.0, 9.9, 50.0, 100.0];
+        let histogram = core_histogram(&loads, 10);
+        assert_eq!(histogram.chars().count(), 10);
+        assert_eq!(histogram.chars().next(), Some('█'));
+        assert_eq!(histogram.chars().nth(1), Some(' '));
+        assert_eq!(histogram.chars().nth(9), Some('▃'));
+        assert_eq!(core_histogram(&[], 4), "    ");
+        assert_eq!(core_histogram(&loads, 0), "");
+    }
+
+    #[test]
+    fn renders_without_cpus() {
+        let mut app = App::new(Config {
+            vram_enabled: false,
+            ..Config::default()
+        });
+        app.system = System::new();
+        assert!(app.system.cpus().is_empty());
+
+        let mut terminal = Terminal::new(TestBackend::new(120, 6)).unwrap();
+        terminal
+            .draw(|frame| render(frame, frame.area(), &app))
+            .unwrap();
+        let text: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(text.contains("CPU    n/a"), "{text}");
+    }
+}
@@ -2,12 +2,13 @@ use ratatui::prelude::*;
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Clear, Paragraph};
 
+use super::keys::KEY_SECTIONS;
 use super::text::tr;
 use super::theme::{COLOR_ACCENT, COLOR_BORDER, COLOR_MUTED};
 use super::widgets::centered_rect;
 use crate::app::App;
 
-pub fn render(frame: &mut Frame, app: &App) {
+pub fn render(frame: &mut Frame, app: &mut App) {
     if !app.show_help {
         return;
     }
@@ -27,168 +28,68 @@ pub fn render(frame: &mut Frame, app: &App) {
     let col1 = 14; // Key column
     let col2 = 24; // Description column
 
-    let mut lines = vec![Line::from(Span::styled(
-        tr(app.language, "Quick Keys", "Быстрые клавиши"),
-        label_style,
-    ))];
-    lines.push(make_row(
-        "F2",
-        tr(app.language, "Setup", "Настройки"),
-        "F12",
-        tr(app.language, "Help", "Справка"),
-        col1,
-        col2,
-        key_style,
-        hint_style,
-    ));
-    lines.push(make_row(
-        "q/й",
-        tr(app.language, "Quit", "Выход"),
-        "r/к",
-        tr(app.language, "Refresh", "Обновить"),
-        col1,
-        col2,
-        key_style,
-        hint_style,
-    ));
-    lines.push(Line::from(""));
-
-    // Section: Navigation
-    lines.push(Line::from(Span::styled(
-        tr(app.language, "Navigation", "Навигация"),
-        label_style,
-    )));
-    lines.push(make_row(
-        "↑/↓",
-        tr(app.language, "Move selection", "Перемещение"),
-        "Enter",
-        tr(app.language, "Expand/Kill", "Развернуть/Убить"),
-        col1,
-        col2,
-        key_style,
-        hint_style,
-    ));
-    lines.push(make_row(
-        "Home/End",
-        tr(app.language, "First/Last", "Начало/Конец"),
-        "PgUp/PgDn",
-        tr(app.language, "Page up/down", "Страница"),
-        col1,
-        col2,
-        key_style,
-        hint_style,
-    ));
-    lines.push(make_row(
-        "Esc/b/и",
-        tr(app.language, "Back/Close", "Назад/Закрыть"),
-        "Tab",
-        tr(app.language, "Switch panel/tab", "Панель/вкладка"),
-        col1,
-        col2,
-        key_style,
-        hint_style,
-    ));
-    lines.push(Line::from(""));
-
-    // Section: Sorting
-    lines.push(Line::from(Span::styled(
-        tr(app.language, "Sorting", "Сортировка"),
-        label_style,
-    )));
-    lines.push(make_row(
-        "←/→",
-        tr(app.language, "Change column/tab", "Колонка/вкладка"),
-        "Space",
-        tr(app.language, "Toggle order", "Изменить порядок"),
-        col1,
-        col2,
-        key_style,
-        hint_style,
-    ));
-    lines.push(make_row(
-        "c/с",
-        tr(app.language, "Sort by CPU", "По CPU"),
-        "m/ь",
-        tr(app.language, "Sort by Memory", "По памяти"),
-        col1,
-        col2,
-        key_style,
-        hint_style,
-    ));
-    lines.push(make_row(
-        "p/з",
-        tr(app.language, "Sort by PID", "По PID"),
-        "n/т",
-        tr(app.language, "Sort by Name", "По имени"),
-        col1,
-        col2,
-        key_style,
-        hint_style,
-    ));
-    lines.push(make_row(
-        "u/г",
-        tr(app.language, "Sort by User", "По юзеру"),
-        "h/р",
-        tr(app.language, "Highlight mode", "Режим подсветки"),
-        col1,
-        col2,
-        key_style,
-        hint_style,
-    ));
-    lines.push(Line::from(""));
+    let mut lines = Vec::new();
+    for (idx, section) in KEY_SECTIONS.iter().enumerate() {
+        if idx > 0 {
+            lines.push(Line::from(""));
+        }
+        lines.push(Line::from(Span::styled(
+            tr(app.language, section.en, section.ru),
+            label_style,
+        )));
+        // Two bindings per row
+        for pair in section.bindings.chunks(2) {
+            let (key2, desc2) = pair
+                .get(1)
+                .map(|binding| (binding.key, tr(app.language, binding.en, binding.ru)))
+                .unwrap_or(("", ""));
+            lines.push(make_row(
+                pair[0].key,
+                tr(app.language, pair[0].en, pair[0].ru),
+                key2,
+                desc2,
+                col1,
+                col2,
+                key_style,
+                hint_style,
+            ));
+        }
+    }
 
-    // Section: Views
-    lines.push(Line::from(Span::styled(
-        tr(app.language, "Views", "Режимы"),
-        label_style,
-    )));
-    lines.push(make_row(
-        "1",
-        tr(app.language, "Overview", "Обзор"),
-        "2",
-        tr(app.language, "System Info", "Система"),
-        col1,
-        col2,
-        key_style,
-        hint_style,
-    ));
-    lines.push(make_row(
-        "3",
-        tr(app.language, "GPU", "GPU"),
-        "4",
-        tr(app.language, "Containers", "Контейнеры"),
-        col1,
-        col2,
-        key_style,
-        hint_style,
-    ));
-    lines.push(make_row(
-        "Tab",
-        tr(app.language, "Switch panel", "Сменить панель"),
-        "t/е",
-        tr(app.language, "Tree view", "Дерево"),
-        col1,
-        col2,
-        key_style,
-        hint_style,
-    ));
-    lines.push(Line::from(""));
+    // The last inner row holds the key hints and never scrolls
+    let body_height = area.height.saturating_sub(3) as usize;
+    let total_rows = lines.len();
+    app.clamp_help_scroll(total_rows, body_height);
+    let mut body = lines
+        .into_iter()
+        .skip(app.help_scroll)
+        .take(body_height)
+        .collect::<Vec<_>>();
+    body.resize(body_height, Line::from(""));
 
-    // Section: GPU
-    lines.push(Line::from(Span::styled(
-        tr(app.language, "GPU", "GPU"),
-        label_style,
-    )));
-    lines.push(make_row(
-        "g/п",
-        tr(app.language, "Next GPU", "Следующий GPU"),
-        "G/П",
-        tr(app.language, "Previous GPU", "Предыдущий GPU"),
-        col1,
-        col2,
-        key_style,
-        hint_style,
-    ));
+    let mut hints = vec![
+        Span::styled("Up/Down PgUp/PgDn", key_style),
+        Span::styled(
+            format!(" {}  ", tr(app.language, "scroll", "прокрутка")),
+            hint_style,
+        ),
+        Span::styled("Esc", key_style),
+        Span::styled(
+            format!(" {}", tr(app.language, "close", "закрыть")),
+            hint_style,
+        ),
+    ];
+    // Scroll indicator: arrows for the hidden directions
+    if total_rows > body_height {
+        let up = if app.help_scroll > 0 { '↑' } else { ' ' };
+        let down = if app.help_scroll + body_height < total_rows {
+            '↓'
+        } else {
+            ' '
+        };
+        hints.push(Span::styled(format!("  {up}{down}"), key_style));
+    }
+    body.push(Line::from(hints));
 
     let block = Block::default()
         .title(tr(app.language, " Help ", " Справка "))
@@ -199,7 +100,7 @@ pub fn render(frame: &mut Frame, app: &App) {
                 .fg(COLOR_ACCENT)
                 .add_modifier(Modifier::BOLD),
         );
-    let paragraph = Paragraph::new(lines).block(block);
+    let paragraph = Paragraph::new(body).block(block);
 
     frame.render_widget(paragraph, area);
 }
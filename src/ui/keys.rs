@@ -0,0 +1,154 @@
+use super::text::tr;
+use crate::app::Language;
+
+/// One key binding: key label plus English and Russian descriptions
+pub struct KeyBinding {
+    pub key: &'static str,
+    pub en: &'static str,
+    pub ru: &'static str,
+}
+
+pub struct KeySection {
+    pub en: &'static str,
+    pub ru: &'static str,
+    pub bindings: &'static [KeyBinding],
+}
+
+const fn bind(key: &'static str, en: &'static str, ru: &'static str) -> KeyBinding {
+    KeyBinding { key, en, ru }
+}
+
+/// Key reference shared by the help overlay and `--keys`.
+pub const KEY_SECTIONS: &[KeySection] = &[
+    KeySection {
+        en: "Quick Keys",
+        ru: "Быстрые клавиши",
+        bindings: &[
+            bind("F2", "Setup", "Настройки"),
+            bind("F12", "Help", "Справка"),
+            bind("F3", "Timing overlay", "Тайминги отрисовки"),
+            bind("q/й", "Quit", "Выход"),
+            bind("r/к", "Refresh", "Обновить"),
+        ],
+    },
+    KeySection {
+        en: "Navigation",
+        ru: "Навигация",
+        bindings: &[
+            bind("↑/↓", "Move selection", "Перемещение"),
+            bind("Enter", "Expand/Kill", "Развернуть/Убить"),
+            bind("Home/End", "First/Last", "Начало/Конец"),
+            bind("PgUp/PgDn", "Page up/down", "Страница"),
+            bind("Esc/b/и", "Back/Close", "Назад/Закрыть"),
+            bind("Tab", "Switch panel/tab", "Панель/вкладка"),
+        ],
+    },
+    KeySection {
+        en: "Sorting",
+        ru: "Сортировка",
+        bindings: &[
+            bind("←/→", "Change column/tab", "Колонка/вкладка"),
+            bind("i/ш", "Toggle order", "Изменить порядок"),
+            bind("c/с", "Sort by CPU", "По CPU"),
+            bind("m/ь", "Sort by Memory", "По памяти"),
+            bind("p/з", "Sort by PID", "По PID"),
+            bind("n/т", "Sort by Name", "По имени"),
+            bind("u/г", "Sort by User", "По юзеру"),
+            bind("h/р", "Highlight mode", "Режим подсветки"),
+            bind("R/К", "Reset sort", "Сброс сортировки"),
+        ],
+    },
+    KeySection {
+        en: "Views",
+        ru: "Режимы",
+        bindings: &[
+            bind("1", "Overview", "Обзор"),
+            bind("2", "System Info", "Система"),
+            bind("3", "GPU", "GPU"),
+            bind("4", "Containers", "Контейнеры"),
+            bind("Tab", "Switch panel", "Сменить панель"),
+            bind("t/е", "Tree view", "Дерево"),
+            bind("a/ф", "Uptime/start time", "Аптайм/запуск"),
+            bind("U/Г", "IEC/SI units", "Единицы IEC/SI"),
+            bind("C/С", "°C/°F", "°C/°F"),
+            bind("z/я", "Fold subtree/group", "Свернуть ветку/группу"),
+            bind("[/х", "Jump to parent", "К родителю"),
+            bind("]/ъ", "Jump to first child", "К первому потомку"),
+            bind("-", "Collapse all", "Свернуть всё"),
+            bind("+", "Expand all", "Развернуть всё"),
+            bind("x/ч", "Group by name", "Группы по имени"),
+            bind("o/щ", "Show ignored", "Показать игнорируемые"),
+            bind("s/ы", "Highlight search", "Подсветка поиска"),
+            bind("S/Ы", "Hide search panel", "Скрыть панель поиска"),
+            bind(
+                "n/N",
+                "Next/prev match (s mode)",
+                "След./пред. совпадение (режим s)",
+            ),
+            bind("w/ц", "Reset peaks", "Сбросить пики"),
+            bind("e/у", "Process details", "Детали процесса"),
+            bind("O/Щ", "Open working dir", "Открыть рабочий каталог"),
+            bind("F/А", "Follow process tree", "Следить за деревом"),
+            bind("Space", "Mark process", "Отметить процесс"),
+            bind("k/л", "Kill marked", "Убить отмеченные"),
+            bind("v/м", "Clear marks", "Снять отметки"),
+            bind("Shift+←/→", "Scroll columns", "Прокрутка колонок"),
+            bind("y/н", "Copy system info", "Копировать сведения"),
+            bind("f/а", "All filesystems", "Все ФС"),
+            bind("d/в", "Disk mount/device", "Диск: точка/устр."),
+            bind("l/д", "Temps by value/label", "Темп.: по значению/имени"),
+        ],
+    },
+    KeySection {
+        en: "GPU",
+        ru: "GPU",
+        bindings: &[
+            bind("g/п", "Next GPU", "Следующий GPU"),
+            bind("G/П", "Previous GPU", "Предыдущий GPU"),
+            bind("P/З", "Pin GPU at startup", "Закрепить GPU"),
+        ],
+    },
+];
+
+/// Plain-text key reference for `rtop --keys`.
+pub fn key_reference(language: Language) -> String {
+    let key_width = KEY_SECTIONS
+        .iter()
+        .flat_map(|section| section.bindings)
+        .map(|binding| binding.key.chars().count())
+        .max()
+        .unwrap_or(0);
+    let mut output = String::new();
+    for (idx, section) in KEY_SECTIONS.iter().enumerate() {
+        if idx > 0 {
+            output.push('\n');
+        }
+        output.push_str(tr(language, section.en, section.ru));
+        output.push('\n');
+        for binding in section.bindings {
+            let padding = key_width - binding.key.chars().count();
+            output.push_str(&format!(
+                "  {}{}  {}\n",
+                binding.key,
+                " ".repeat(padding),
+                tr(language, binding.en, binding.ru)
+            ));
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn key_reference_lists_every_binding() {
+        let output = key_reference(Language::English);
+        for binding in KEY_SECTIONS.iter().flat_map(|section| section.bindings) {
+            assert!(output.contains(binding.key));
+            assert!(output.contains(binding.en));
+        }
+        assert!(key_reference(Language::Russian).contains("Быстрые клавиши"));
+    }
+}
@@ -1,9 +1,12 @@
 mod confirm;
 mod containers;
+mod debug;
+mod detail;
 mod footer;
 mod gpu;
 mod header;
 mod help;
+pub mod keys;
 mod processes;
 mod setup;
 mod stats;
@@ -12,6 +15,8 @@ mod text;
 pub mod theme;
 mod widgets;
 
+pub use system::system_overview_text;
+
 use ratatui::prelude::*;
 use ratatui::widgets::*;
 
@@ -47,6 +52,13 @@ pub fn render(frame: &mut Frame, app: &mut App) {
         ViewMode::SystemInfo => render_system_info(frame, app, size),
         ViewMode::Container => render_containers(frame, app, size),
     }
+    debug::render(frame, app);
+
+    if app.monochrome {
+        theme::monochrome_buffer(frame.buffer_mut());
+    } else if !app.truecolor {
+        theme::downgrade_buffer(frame.buffer_mut());
+    }
 }
 
 pub fn panel_block(title: &str) -> Block<'_> {
@@ -93,6 +105,7 @@ fn render_overview(frame: &mut Frame, app: &mut App, size: Rect) {
         header::render(frame, chunks[0], app);
         processes::render_with_focus(frame, chunks[1], app, true);
         footer::render(frame, chunks[2], app);
+        detail::render(frame, app);
         confirm::render(frame, app);
         help::render(frame, app);
         setup::render(frame, app);
@@ -101,9 +114,14 @@ fn render_overview(frame: &mut Frame, app: &mut App, size: Rect) {
 
     // Normal mode
     let min_process_height = 8;
+    let top_height = if app.show_top_cpu {
+        stats::top_cpu_height()
+    } else {
+        0
+    };
     let available = size
         .height
-        .saturating_sub(header_height + footer_height + min_process_height);
+        .saturating_sub(header_height + footer_height + min_process_height + top_height);
     let cpu_height = available.clamp(5, 9);
 
     let chunks = Layout::default()
@@ -111,6 +129,7 @@ fn render_overview(frame: &mut Frame, app: &mut App, size: Rect) {
         .constraints([
             Constraint::Length(header_height),
             Constraint::Length(cpu_height),
+            Constraint::Length(top_height),
             Constraint::Min(8),
             Constraint::Length(footer_height),
         ])
@@ -118,18 +137,30 @@ fn render_overview(frame: &mut Frame, app: &mut App, size: Rect) {
 
     header::render(frame, chunks[0], app);
     stats::render_with_focus(frame, chunks[1], app, false);
-    let process_chunks = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([Constraint::Percentage(30), Constraint::Percentage(70)])
-        .split(chunks[2]);
-    processes::render_search_panel(frame, process_chunks[0], app);
+    if app.show_top_cpu {
+        stats::render_top_cpu(frame, chunks[2], app);
+    }
+    let table_area = if app.search_panel_visible() {
+        let process_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Percentage(app.search_panel_pct),
+                Constraint::Percentage(100 - app.search_panel_pct),
+            ])
+            .split(chunks[3]);
+        processes::render_search_panel(frame, process_chunks[0], app);
+        process_chunks[1]
+    } else {
+        chunks[3]
+    };
     processes::render_with_focus(
         frame,
-        process_chunks[1],
+        table_area,
         app,
         app.processes_focused && !app.process_filter_active,
     );
-    footer::render(frame, chunks[3], app);
+    footer::render(frame, chunks[4], app);
+    detail::render(frame, app);
     confirm::render(frame, app);
     help::render(frame, app);
     setup::render(frame, app);
@@ -150,6 +181,7 @@ fn render_processes_only(frame: &mut Frame, app: &mut App, size: Rect) {
     header::render(frame, chunks[0], app);
     processes::render(frame, chunks[1], app);
     footer::render(frame, chunks[2], app);
+    detail::render(frame, app);
     confirm::render(frame, app);
     help::render(frame, app);
     setup::render(frame, app);
@@ -170,6 +202,7 @@ fn render_gpu_focus(frame: &mut Frame, app: &mut App, size: Rect) {
     header::render(frame, chunks[0], app);
     gpu::render(frame, chunks[1], app);
     footer::render(frame, chunks[2], app);
+    detail::render(frame, app);
     confirm::render(frame, app);
     help::render(frame, app);
     setup::render(frame, app);
@@ -190,6 +223,7 @@ fn render_system_info(frame: &mut Frame, app: &mut App, size: Rect) {
     header::render(frame, chunks[0], app);
     system::render(frame, chunks[1], app);
     footer::render(frame, chunks[2], app);
+    detail::render(frame, app);
     confirm::render(frame, app);
     help::render(frame, app);
     setup::render(frame, app);
@@ -210,6 +244,7 @@ fn render_containers(frame: &mut Frame, app: &mut App, size: Rect) {
     header::render(frame, chunks[0], app);
     containers::render(frame, chunks[1], app);
     footer::render(frame, chunks[2], app);
+    detail::render(frame, app);
     confirm::render(frame, app);
     help::render(frame, app);
     setup::render(frame, app);
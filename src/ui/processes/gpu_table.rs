@@ -9,7 +9,7 @@ use super::super::theme::{COLOR_ACCENT, COLOR_MUTED};
 use super::super::{panel_block, panel_block_focused};
 use crate::app::{App, GpuProcessSortKey};
 use crate::data::SortDir;
-use crate::utils::format_bytes;
+use crate::utils::{ByteUnit, format_bytes_with};
 
 pub fn render_gpu_processes_with_focus(
     frame: &mut Frame,
@@ -36,7 +36,12 @@ fn render_gpu_processes_inner(frame: &mut Frame, area: Rect, app: &mut App, focu
         panel_block
     };
     let Some(selected_id) = app.selected_gpu().map(|(_, gpu)| gpu.id.as_str()) else {
-        let paragraph = Paragraph::new(tr(app.language, "No GPU selected", "GPU не выбран"))
+        let message = if app.gpu_probing() {
+            tr(app.language, "Probing GPU…", "Опрос GPU…")
+        } else {
+            tr(app.language, "No GPU selected", "GPU не выбран")
+        };
+        let paragraph = Paragraph::new(message)
             .block(block_fn(panel_title))
             .alignment(Alignment::Center);
         frame.render_widget(paragraph, area);
@@ -61,6 +66,12 @@ fn render_gpu_processes_inner(frame: &mut Frame, area: Rect, app: &mut App, focu
             enc_pct: entry.enc_pct,
             dec_pct: entry.dec_pct,
             fb_mb: entry.fb_mb,
+            sources: entry
+                .sources
+                .iter()
+                .map(|source| source.label())
+                .collect::<Vec<_>>()
+                .join("+"),
         })
         .collect::<Vec<_>>();
 
@@ -76,7 +87,17 @@ fn render_gpu_processes_inner(frame: &mut Frame, area: Rect, app: &mut App, focu
     rows.sort_by(|a, b| sort_gpu_rows(a, b, app.gpu_process_sort_key, app.gpu_process_sort_dir));
     app.gpu_process_order = rows.iter().map(|row| row.pid).collect();
 
-    let block = block_fn(panel_title);
+    // Which provider(s) reported the selected process, for diagnosing gaps
+    let panel_title = match app
+        .selected_pid
+        .and_then(|pid| rows.iter().find(|row| row.pid == pid))
+    {
+        Some(row) if !row.sources.is_empty() => {
+            format!("{panel_title} · PID {}: {}", row.pid, row.sources)
+        }
+        _ => panel_title.to_string(),
+    };
+    let block = block_fn(&panel_title);
     let inner = block.inner(area);
     if inner.width == 0 || inner.height == 0 {
         return;
@@ -114,7 +135,7 @@ fn render_gpu_processes_inner(frame: &mut Frame, area: Rect, app: &mut App, focu
                 format_optional_pct(row.mem_pct),
                 format_optional_pct(row.enc_pct),
                 format_optional_pct(row.dec_pct),
-                format_fb_mb(row.fb_mb),
+                format_fb_mb(row.fb_mb, app.byte_unit),
                 row.name.clone(),
             ])
         })
@@ -274,9 +295,9 @@ fn format_optional_pct(value: Option<f32>) -> String {
         .unwrap_or_else(|| "  -  ".to_string())
 }
 
-fn format_fb_mb(value: Option<u64>) -> String {
+fn format_fb_mb(value: Option<u64>, unit: ByteUnit) -> String {
     value
-        .map(|mb| format_bytes(mb.saturating_mul(1024 * 1024)))
+        .map(|mb| format_bytes_with(mb.saturating_mul(1024 * 1024), unit))
         .unwrap_or_else(|| "-".to_string())
 }
 
@@ -289,6 +310,8 @@ struct GpuProcessRow {
     enc_pct: Option<f32>,
     dec_pct: Option<f32>,
     fb_mb: Option<u64>,
+    /// Providers that reported the process, e.g. "nvidia+drm"
+    sources: String,
 }
 
 fn update_gpu_process_header_regions(app: &mut App, area: Rect) {
@@ -3,11 +3,15 @@ use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Cell, Row, Table, TableState};
 
 use super::super::text::tr;
-use super::super::theme::{COLOR_ACCENT, COLOR_GOOD, COLOR_MUTED};
+use super::super::theme::{
+    COLOR_ACCENT, COLOR_GOOD, COLOR_HOT, COLOR_MATCH_BG, COLOR_MUTED, COLOR_NEW_BG, COLOR_WARN,
+};
 use super::super::{panel_block, panel_block_focused};
 use crate::app::{App, HighlightMode};
 use crate::data::{SortDir, SortKey};
-use crate::utils::{fit_text, format_bytes, format_duration_short};
+use crate::utils::{
+    fit_text, format_bytes_with, format_cpu_time, format_duration_short, format_start_time,
+};
 
 pub fn render(frame: &mut Frame, area: Rect, app: &mut App) {
     render_with_focus(frame, area, app, false);
@@ -15,9 +19,11 @@ pub fn render(frame: &mut Frame, area: Rect, app: &mut App) {
 
 pub fn render_with_focus(frame: &mut Frame, area: Rect, app: &mut App, focused: bool) {
     let process_area = area;
+    app.process_hscroll = app.process_hscroll.min(max_hscroll(app));
     update_process_header_regions(app, process_area);
     let block = process_block(app, focused);
     let inner = block.inner(process_area);
+    let columns = visible_columns(app, inner.width);
     app.process_body = if inner.width > 0 && inner.height > 1 {
         Some(Rect {
             x: inner.x,
@@ -55,6 +61,7 @@ pub fn render_with_focus(frame: &mut Frame, area: Rect, app: &mut App, focused:
     } else {
         None
     };
+    let search_needle = app.search_highlight_needle();
 
     let table_rows = visible_rows
         .iter()
@@ -67,56 +74,116 @@ pub fn render_with_focus(frame: &mut Frame, area: Rect, app: &mut App, focused:
             let name_text = tree_labels
                 .and_then(|labels| labels.get(&row.pid))
                 .map(|label| fit_text(label, name_width))
-                .unwrap_or_else(|| row.name.clone());
-            let name_cell = if highlight {
-                Cell::from(name_text).style(Style::default().fg(COLOR_GOOD))
+                .unwrap_or_else(|| match app.group_members.get(&row.pid) {
+                    Some(members) => format!("{} ×{}", row.name, members.len()),
+                    None => row.name.clone(),
+                });
+            let mut name_style = if highlight {
+                Style::default().fg(COLOR_GOOD)
             } else {
-                Cell::from(name_text)
+                Style::default()
             };
-            Row::new(vec![
-                Cell::from(row.pid.to_string()),
+            if row.name_lossy {
+                // Sanitized non-UTF-8 name
+                name_style = name_style.add_modifier(Modifier::ITALIC);
+            }
+            let name_cell = Cell::from(name_text).style(name_style);
+            let mut row_style = if search_needle
+                .as_deref()
+                .is_some_and(|needle| app.is_search_match(row, needle))
+            {
+                Style::default().bg(COLOR_MATCH_BG)
+            } else if row.is_new && !app.reduce_motion {
+                Style::default().bg(COLOR_NEW_BG)
+            } else {
+                Style::default()
+            };
+            let marked = app.marked.contains_key(&row.pid);
+            if marked {
+                row_style = row_style.fg(COLOR_WARN).add_modifier(Modifier::BOLD);
+            }
+            let cells = vec![
+                Cell::from(if marked {
+                    format!("*{}", row.pid)
+                } else {
+                    row.pid.to_string()
+                }),
                 Cell::from(row.user.clone().unwrap_or_else(|| "-".to_string())),
                 Cell::from(format!("{:>5.1}", row.cpu)),
-                Cell::from(format_bytes(row.mem_bytes)),
-                Cell::from(format_duration_short(row.uptime_secs)),
-                Cell::from(row.status.clone()),
+                Cell::from(format_bytes_with(row.mem_bytes, app.byte_unit)),
+                Cell::from(
+                    row.swap_bytes
+                        .map(|bytes| format_bytes_with(bytes, app.byte_unit))
+                        .unwrap_or_else(|| "-".to_string()),
+                ),
+                Cell::from(
+                    row.gpu_sm_pct
+                        .map(|pct| format!("{pct:>5.1}"))
+                        .unwrap_or_else(|| format!("{:>5}", "-")),
+                ),
+                Cell::from(
+                    row.gpu_fb_bytes
+                        .map(|bytes| format_bytes_with(bytes, app.byte_unit))
+                        .unwrap_or_else(|| "-".to_string()),
+                ),
+                Cell::from(format_cpu_time(row.cpu_time_ms)),
+                Cell::from(if app.show_start_time {
+                    format_start_time(row.start_time, app.time_format)
+                } else {
+                    format_duration_short(row.uptime_secs)
+                }),
+                if row.io_active {
+                    // Heavy disk I/O; complements the D (disk sleep) status
+                    Cell::from(format!("⇅{}", row.status)).style(Style::default().fg(COLOR_HOT))
+                } else {
+                    Cell::from(row.status.clone())
+                },
+                Cell::from(format!("{:>3}", row.priority)),
+                Cell::from(format!("{:>3}", row.nice)),
+                Cell::from(fit_text(
+                    row.unit.as_deref().unwrap_or("-"),
+                    UNIT_WIDTH as usize,
+                )),
                 name_cell,
-            ])
+            ];
+            Row::new(columns.select(cells)).style(row_style)
         })
         .collect::<Vec<_>>();
 
-    let header = Row::new(vec![
-        header_cell(app, SortKey::Pid, "PID"),
-        header_cell(app, SortKey::User, "USER"),
-        header_cell(app, SortKey::Cpu, "CPU%"),
-        header_cell(app, SortKey::Mem, "MEM"),
-        header_cell(app, SortKey::Uptime, "UPTIME"),
-        header_cell(app, SortKey::Status, "STAT"),
-        header_cell(app, SortKey::Name, "NAME"),
-    ]);
-
-    let table = Table::new(
-        table_rows,
-        [
-            Constraint::Length(7),
-            Constraint::Length(8),
-            Constraint::Length(6),
-            Constraint::Length(9),
-            Constraint::Length(7),
-            Constraint::Length(7),
-            Constraint::Min(10),
-        ],
-    )
-    .header(header)
-    .block(block)
-    .column_spacing(1)
-    .row_highlight_style(
-        Style::default()
-            .fg(Color::White)
-            .bg(Color::Rgb(40, 48, 58))
-            .add_modifier(Modifier::BOLD),
+    let last_fixed = columns
+        .indices
+        .len()
+        .checked_sub(2)
+        .map(|pos| columns.indices[pos]);
+    let header = Row::new(
+        columns
+            .indices
+            .iter()
+            .map(|&idx| {
+                let mut label = column_label(app, idx).to_string();
+                // Subtle arrows when columns are scrolled off or dropped for width
+                if columns.hidden_left && idx == columns.indices[0] {
+                    label.insert(0, '◀');
+                }
+                if columns.dropped && Some(idx) == last_fixed {
+                    label.push('▶');
+                }
+                header_cell(app, COLUMN_KEYS[idx], &label)
+            })
+            .collect::<Vec<_>>(),
     );
 
+    let table = Table::new(table_rows, column_constraints(app, &columns))
+        .header(header)
+        .block(block)
+        .column_spacing(COLUMN_SPACING)
+        .row_highlight_style(
+            Style::default()
+                .fg(Color::White)
+                .bg(Color::Rgb(40, 48, 58))
+                .add_modifier(Modifier::BOLD),
+        );
+
     let mut state = TableState::default();
     if let Some(selected) = app.table_state.selected()
         && selected >= start
@@ -152,6 +219,160 @@ fn header_cell(app: &App, key: SortKey, label: &str) -> Cell<'static> {
     Cell::from(format!("{label}{indicator}")).style(style)
 }
 
+const COLUMN_KEYS: [SortKey; 14] = [
+    SortKey::Pid,
+    SortKey::User,
+    SortKey::Cpu,
+    SortKey::Mem,
+    SortKey::Swap,
+    SortKey::GpuSm,
+    SortKey::GpuVram,
+    SortKey::CpuTime,
+    SortKey::Uptime,
+    SortKey::Status,
+    SortKey::Priority,
+    SortKey::Nice,
+    SortKey::Unit,
+    SortKey::Name,
+];
+/// NAME is always the last column and never scrolls out of view
+const NAME_COLUMN: usize = COLUMN_KEYS.len() - 1;
+/// Order in which optional columns are dropped when NAME would get too narrow
+const DROP_ORDER: [SortKey; 12] = [
+    SortKey::Unit,
+    SortKey::Nice,
+    SortKey::Priority,
+    SortKey::GpuVram,
+    SortKey::GpuSm,
+    SortKey::Swap,
+    SortKey::Status,
+    SortKey::CpuTime,
+    SortKey::Uptime,
+    SortKey::User,
+    SortKey::Mem,
+    SortKey::Cpu,
+];
+const COLUMN_SPACING: u16 = 1;
+const UNIT_WIDTH: u16 = 18;
+
+/// Columns shown for the current horizontal scroll offset and table width.
+struct VisibleColumns {
+    indices: Vec<usize>,
+    hidden_left: bool,
+    /// Columns after the scroll offset were dropped to keep NAME wide enough
+    dropped: bool,
+}
+
+impl VisibleColumns {
+    fn select<T>(&self, cells: Vec<T>) -> Vec<T> {
+        cells
+            .into_iter()
+            .enumerate()
+            .filter(|(idx, _)| self.indices.contains(idx))
+            .map(|(_, cell)| cell)
+            .collect()
+    }
+}
+
+/// PRI/NI, SWAP, GPU%/VRAM and UNIT are only shown when enabled in `[processes]`.
+fn column_enabled(app: &App, idx: usize) -> bool {
+    app.sort_column_shown(COLUMN_KEYS[idx])
+}
+
+/// Largest horizontal scroll offset that still changes what is visible.
+fn max_hscroll(app: &App) -> usize {
+    (0..NAME_COLUMN)
+        .rev()
+        .find(|&idx| column_enabled(app, idx))
+        .unwrap_or(0)
+}
+
+fn visible_columns(app: &App, width: u16) -> VisibleColumns {
+    let start = app.process_hscroll;
+    let mut indices = (start..=NAME_COLUMN)
+        .filter(|&idx| column_enabled(app, idx))
+        .collect::<Vec<_>>();
+    // u32 so wide configured columns cannot overflow the sum
+    let total = |indices: &[usize]| -> u32 {
+        indices
+            .iter()
+            .map(|&idx| u32::from(column_width(app, idx)) + u32::from(COLUMN_SPACING))
+            .sum()
+    };
+    let mut dropped = false;
+    // Drop optional columns before squeezing NAME, keeping at least one next to it
+    for key in DROP_ORDER {
+        if total(&indices) <= u32::from(width) + u32::from(COLUMN_SPACING) || indices.len() <= 2 {
+            break;
+        }
+        if let Some(pos) = indices.iter().position(|&idx| COLUMN_KEYS[idx] == key) {
+            indices.remove(pos);
+            dropped = true;
+        }
+    }
+    VisibleColumns {
+        indices,
+        hidden_left: start > 0,
+        dropped,
+    }
+}
+
+fn column_label(app: &App, idx: usize) -> &'static str {
+    match COLUMN_KEYS[idx] {
+        SortKey::Pid => "PID",
+        SortKey::User => "USER",
+        SortKey::Cpu => "CPU%",
+        SortKey::Mem => "MEM",
+        SortKey::Swap => "SWAP",
+        SortKey::GpuSm => "GPU%",
+        SortKey::GpuVram => "VRAM",
+        SortKey::CpuTime => "TIME+",
+        SortKey::Uptime if app.show_start_time => "STARTED",
+        SortKey::Uptime => "UPTIME",
+        SortKey::Status => "STAT",
+        SortKey::Priority => "PRI",
+        SortKey::Nice => "NI",
+        SortKey::Unit => "UNIT",
+        SortKey::Name => "NAME",
+    }
+}
+
+fn column_width(app: &App, idx: usize) -> u16 {
+    match COLUMN_KEYS[idx] {
+        // One extra cell for the "*" mark so a 7-digit PID still fits
+        SortKey::Pid => 7 + u16::from(!app.marked.is_empty()),
+        SortKey::User => 8,
+        SortKey::Cpu => 6,
+        SortKey::Mem => 9,
+        SortKey::Swap => 9,
+        SortKey::GpuSm => 6,
+        SortKey::GpuVram => 9,
+        SortKey::CpuTime => 9,
+        // Absolute start time ("2024-06-01 14:03") needs a wider column than uptime
+        SortKey::Uptime if app.show_start_time => app.time_format.timestamp_width() as u16,
+        SortKey::Uptime => 7,
+        SortKey::Status => 7,
+        SortKey::Priority => 4,
+        SortKey::Nice => 4,
+        SortKey::Unit => UNIT_WIDTH,
+        SortKey::Name => app.name_min_width,
+    }
+}
+
+fn column_constraints(app: &App, columns: &VisibleColumns) -> Vec<Constraint> {
+    columns
+        .indices
+        .iter()
+        .map(|&idx| {
+            if idx == NAME_COLUMN {
+                Constraint::Min(app.name_min_width)
+            } else {
+                Constraint::Length(column_width(app, idx))
+            }
+        })
+        .collect()
+}
+
 fn update_process_header_regions(app: &mut App, area: Rect) {
     let block = process_block(app, false);
     let inner = block.inner(area);
@@ -160,21 +381,14 @@ fn update_process_header_regions(app: &mut App, area: Rect) {
         return;
     }
 
-    let spacing = 1u16;
-    let constraints = [
-        Constraint::Length(7),
-        Constraint::Length(8),
-        Constraint::Length(6),
-        Constraint::Length(9),
-        Constraint::Length(7),
-        Constraint::Length(7),
-        Constraint::Min(10),
-    ];
+    let spacing = COLUMN_SPACING;
+    let columns = visible_columns(app, inner.width);
+    let constraints = column_constraints(app, &columns);
     let total_spacing = spacing.saturating_mul(constraints.len().saturating_sub(1) as u16);
     let layout_width = inner.width.saturating_sub(total_spacing);
     let layout = Layout::default()
         .direction(Direction::Horizontal)
-        .constraints(constraints)
+        .constraints(&constraints)
         .split(Rect {
             x: 0,
             y: 0,
@@ -184,16 +398,8 @@ fn update_process_header_regions(app: &mut App, area: Rect) {
 
     let mut regions = Vec::with_capacity(constraints.len());
     let mut x = inner.x;
-    for (idx, rect) in layout.iter().enumerate() {
-        let key = match idx {
-            0 => SortKey::Pid,
-            1 => SortKey::User,
-            2 => SortKey::Cpu,
-            3 => SortKey::Mem,
-            4 => SortKey::Uptime,
-            5 => SortKey::Status,
-            _ => SortKey::Name,
-        };
+    for (&idx, rect) in columns.indices.iter().zip(layout.iter()) {
+        let key = COLUMN_KEYS[idx];
         regions.push(crate::app::HeaderRegion {
             key,
             rect: Rect {
@@ -238,7 +444,9 @@ fn process_title_line(app: &App) -> Line<'static> {
     } else {
         tr(app.language, "Processes", "Процессы")
     };
-    let active_tab = if app.process_filter_active || !app.process_filter.is_empty() {
+    let active_tab = if app.detail.is_some() {
+        ProcessTab::Details
+    } else if app.process_filter_active || !app.process_filter.is_empty() {
         ProcessTab::Filter
     } else {
         ProcessTab::List
@@ -246,12 +454,52 @@ fn process_title_line(app: &App) -> Line<'static> {
 
     let tabs = [
         (ProcessTab::List, tr(app.language, "List", "Список")),
-        (ProcessTab::Filter, tr(app.language, "Filter", "Фильтр")),
+        (
+            ProcessTab::Filter,
+            if app.search_highlight {
+                tr(app.language, "Search", "Поиск")
+            } else {
+                tr(app.language, "Filter", "Фильтр")
+            },
+        ),
         (ProcessTab::Details, tr(app.language, "Details", "Детали")),
     ];
 
     let mut spans = Vec::new();
     spans.push(Span::styled(format!(" {panel_title} "), title_style));
+    if let Some(pid) = app.follow_pid {
+        spans.push(Span::styled(
+            format!(
+                "({} {} {pid}) ",
+                tr(app.language, "following", "слежение:"),
+                app.follow_name
+            ),
+            Style::default().fg(COLOR_WARN),
+        ));
+    }
+    if app.ignored_count > 0 {
+        let note = if app.show_ignored {
+            tr(app.language, "ignored shown", "игнор. показаны").to_string()
+        } else {
+            format!(
+                "{} {}",
+                app.ignored_count,
+                tr(app.language, "ignored", "скрыто")
+            )
+        };
+        spans.push(Span::styled(format!("({note}) "), inactive_style));
+    }
+    if let Some(total) = app.truncated_from {
+        spans.push(Span::styled(
+            format!(
+                "({} {} {} {total}) ",
+                tr(app.language, "showing top", "показаны первые"),
+                app.rows.len(),
+                tr(app.language, "of", "из"),
+            ),
+            inactive_style,
+        ));
+    }
     let separator = " | ";
     spans.push(Span::styled(separator, separator_style));
     for (idx, (tab, label)) in tabs.iter().enumerate() {
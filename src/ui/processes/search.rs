@@ -9,7 +9,15 @@ use crate::ui::{panel_block, panel_block_focused};
 use crate::utils::{fit_text, text_width};
 
 pub fn render_search_panel(frame: &mut Frame, area: Rect, app: &App) {
-    let title = tr(app.language, "Process Search", "Поиск процесса");
+    let title = if app.search_highlight {
+        tr(
+            app.language,
+            "Process Search (highlight)",
+            "Поиск процесса (подсветка)",
+        )
+    } else {
+        tr(app.language, "Process Search", "Поиск процесса")
+    };
     let block = if app.process_filter_active {
         panel_block_focused(title)
     } else {
@@ -1,13 +1,14 @@
 use ratatui::prelude::*;
 use ratatui::text::{Line, Span};
-use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
 
 use super::text::tr;
 use super::theme::{COLOR_ACCENT, COLOR_BORDER, COLOR_MUTED};
 use super::widgets::centered_rect;
-use crate::app::{App, IconMode, Language, LogoMode, LogoQuality, SetupField};
+use crate::app::{App, IconMode, Language, LogoMode, LogoQuality, SetupField, SetupToggle};
+use crate::utils::{ByteUnit, TempUnit};
 
-pub fn render(frame: &mut Frame, app: &App) {
+pub fn render(frame: &mut Frame, app: &mut App) {
     if !app.show_setup {
         return;
     }
@@ -46,6 +47,21 @@ pub fn render(frame: &mut Frame, app: &App) {
     } else {
         label_style
     };
+    let units_label_style = if app.setup_field == SetupField::ByteUnit {
+        active_label_style
+    } else {
+        label_style
+    };
+    let temp_label_style = if app.setup_field == SetupField::TempUnit {
+        active_label_style
+    } else {
+        label_style
+    };
+    let gpu_poll_label_style = if app.setup_field == SetupField::GpuPollRate {
+        active_label_style
+    } else {
+        label_style
+    };
 
     let en_style = if app.language == Language::English {
         key_style
@@ -77,6 +93,11 @@ pub fn render(frame: &mut Frame, app: &App) {
     } else {
         hint_style
     };
+    let no_logo_style = if app.logo_mode == LogoMode::None {
+        key_style
+    } else {
+        hint_style
+    };
     let quality_style = if app.logo_quality == LogoQuality::Quality {
         key_style
     } else {
@@ -93,13 +114,51 @@ pub fn render(frame: &mut Frame, app: &App) {
         hint_style
     };
 
+    let iec_style = if app.byte_unit == ByteUnit::Iec {
+        key_style
+    } else {
+        hint_style
+    };
+    let si_style = if app.byte_unit == ByteUnit::Si {
+        key_style
+    } else {
+        hint_style
+    };
+    let celsius_style = if app.temp_unit == TempUnit::Celsius {
+        key_style
+    } else {
+        hint_style
+    };
+    let fahrenheit_style = if app.temp_unit == TempUnit::Fahrenheit {
+        key_style
+    } else {
+        hint_style
+    };
+
     let ru_label = tr(app.language, "Russian", "Русский");
-    let lines = vec![
+    let mut lines = vec![
         Line::from(Span::styled(
             tr(app.language, "Setup", "Настройки"),
             label_style,
         )),
         Line::from(""),
+    ];
+    // Row of the focused field, kept in view when the popup is too short
+    let mut focus_row = 0;
+    let mut push_field = |lines: &mut Vec<Line<'static>>, field: SetupField, line| {
+        if app.setup_field == field {
+            // Scrolling back to the first field shows the title again
+            focus_row = if field == SetupField::ALL[0] {
+                0
+            } else {
+                lines.len()
+            };
+        }
+        lines.push(line);
+    };
+    push_field(
+        &mut lines,
+        SetupField::Language,
         Line::from(vec![
             Span::styled(
                 tr(app.language, "Language: ", "Язык: "),
@@ -109,21 +168,35 @@ pub fn render(frame: &mut Frame, app: &App) {
             Span::styled("  ", hint_style),
             Span::styled(ru_label, ru_style),
         ]),
-        Line::from(""),
+    );
+    lines.push(Line::from(""));
+    push_field(
+        &mut lines,
+        SetupField::IconMode,
         Line::from(vec![
             Span::styled(tr(app.language, "Icons: ", "Иконки: "), icon_label_style),
             Span::styled("Nerd Fonts", nerd_style),
             Span::styled("  ", hint_style),
             Span::styled(tr(app.language, "Text", "Текст"), text_icon_style),
         ]),
-        Line::from(""),
+    );
+    lines.push(Line::from(""));
+    push_field(
+        &mut lines,
+        SetupField::LogoMode,
         Line::from(vec![
             Span::styled(tr(app.language, "Logo: ", "Логотип: "), logo_label_style),
             Span::styled("ASCII", ascii_style),
             Span::styled("  ", hint_style),
             Span::styled("SVG", svg_style),
+            Span::styled("  ", hint_style),
+            Span::styled(tr(app.language, "None", "Нет"), no_logo_style),
         ]),
-        Line::from(""),
+    );
+    lines.push(Line::from(""));
+    push_field(
+        &mut lines,
+        SetupField::LogoQuality,
         Line::from(vec![
             Span::styled(
                 tr(app.language, "Quality: ", "Качество: "),
@@ -135,6 +208,33 @@ pub fn render(frame: &mut Frame, app: &App) {
             Span::styled("  ", hint_style),
             Span::styled(tr(app.language, "Detailed", "Детальный"), pixel_style),
         ]),
+    );
+    lines.push(Line::from(""));
+    push_field(
+        &mut lines,
+        SetupField::ByteUnit,
+        Line::from(vec![
+            Span::styled(tr(app.language, "Units: ", "Единицы: "), units_label_style),
+            Span::styled("IEC (KiB)", iec_style),
+            Span::styled("  ", hint_style),
+            Span::styled("SI (kB)", si_style),
+        ]),
+    );
+    lines.push(Line::from(""));
+    push_field(
+        &mut lines,
+        SetupField::TempUnit,
+        Line::from(vec![
+            Span::styled(
+                tr(app.language, "Temperature: ", "Температура: "),
+                temp_label_style,
+            ),
+            Span::styled("°C", celsius_style),
+            Span::styled("  ", hint_style),
+            Span::styled("°F", fahrenheit_style),
+        ]),
+    );
+    lines.extend([
         Line::from(""),
         Line::from(vec![
             Span::styled(tr(app.language, "Theme: ", "Тема: "), label_style),
@@ -152,29 +252,94 @@ pub fn render(frame: &mut Frame, app: &App) {
             Span::styled(tr(app.language, "Refresh rate: ", "Частота: "), label_style),
             Span::styled(tr(app.language, "(coming soon)", "(скоро)"), hint_style),
         ]),
+    ]);
+    push_field(
+        &mut lines,
+        SetupField::GpuPollRate,
         Line::from(vec![
-            Span::styled(tr(app.language, "GPU: ", "GPU: "), label_style),
-            Span::styled(tr(app.language, "(coming soon)", "(скоро)"), hint_style),
-        ]),
-        Line::from(""),
-        Line::from(vec![
-            Span::styled("Up/Down", key_style),
-            Span::styled(
-                format!(" {}  ", tr(app.language, "select", "выбор")),
-                hint_style,
-            ),
-            Span::styled("Left/Right", key_style),
             Span::styled(
-                format!(" {}  ", tr(app.language, "change", "изменить")),
-                hint_style,
+                tr(app.language, "GPU poll: ", "Опрос GPU: "),
+                gpu_poll_label_style,
             ),
-            Span::styled("Esc", key_style),
             Span::styled(
-                format!(" {}", tr(app.language, "close", "закрыть")),
-                hint_style,
+                format!("{} ms", app.gpu_poll_rate.as_millis()),
+                if app.vram_enabled {
+                    key_style
+                } else {
+                    hint_style
+                },
             ),
         ]),
+    );
+    lines.push(Line::from(""));
+
+    // On/off options
+    for field in SetupField::ALL {
+        let SetupField::Toggle(toggle) = field else {
+            continue;
+        };
+        let label_style = if app.setup_field == field {
+            active_label_style
+        } else {
+            label_style
+        };
+        let (value, value_style) = if app.setup_toggle(toggle) {
+            (tr(app.language, "on", "вкл"), key_style)
+        } else {
+            (tr(app.language, "off", "выкл"), hint_style)
+        };
+        push_field(
+            &mut lines,
+            field,
+            Line::from(vec![
+                Span::styled(
+                    format!("{}: ", toggle_label(toggle, app.language)),
+                    label_style,
+                ),
+                Span::styled(value, value_style),
+            ]),
+        );
+    }
+
+    // The last inner row holds the key hints and never scrolls
+    let body_height = area.height.saturating_sub(3) as usize;
+    app.ensure_setup_visible(focus_row, lines.len(), body_height);
+    let total_rows = lines.len();
+    let mut body = lines
+        .into_iter()
+        .skip(app.setup_scroll)
+        .take(body_height)
+        .collect::<Vec<_>>();
+    body.resize(body_height, Line::from(""));
+
+    let mut hints = vec![
+        Span::styled("Up/Down", key_style),
+        Span::styled(
+            format!(" {}  ", tr(app.language, "select", "выбор")),
+            hint_style,
+        ),
+        Span::styled("Left/Right", key_style),
+        Span::styled(
+            format!(" {}  ", tr(app.language, "change", "изменить")),
+            hint_style,
+        ),
+        Span::styled("Esc", key_style),
+        Span::styled(
+            format!(" {}", tr(app.language, "close", "закрыть")),
+            hint_style,
+        ),
     ];
+    // Scroll indicator: arrows for the hidden directions
+    if total_rows > body_height {
+        let up = if app.setup_scroll > 0 { '↑' } else { ' ' };
+        let down = if app.setup_scroll + body_height < total_rows {
+            '↓'
+        } else {
+            ' '
+        };
+        hints.push(Span::styled(format!("  {up}{down}"), key_style));
+    }
+    body.push(Line::from(hints));
 
     let block = Block::default()
         .title(tr(app.language, "Setup", "Настройки"))
@@ -185,7 +350,22 @@ pub fn render(frame: &mut Frame, app: &App) {
                 .fg(COLOR_ACCENT)
                 .add_modifier(Modifier::BOLD),
         );
-    let paragraph = Paragraph::new(lines).block(block).wrap(Wrap { trim: true });
+    frame.render_widget(Paragraph::new(body).block(block), area);
+}
 
-    frame.render_widget(paragraph, area);
+fn toggle_label(toggle: SetupToggle, language: Language) -> &'static str {
+    match toggle {
+        SetupToggle::ShowTopCpu => tr(language, "Top CPU bars", "Топ по CPU"),
+        SetupToggle::ShowCountdown => tr(language, "Refresh countdown", "Отсчёт обновления"),
+        SetupToggle::GpuCompact => tr(language, "Compact GPU view", "Компактный GPU"),
+        SetupToggle::OverviewAllGpus => tr(language, "All GPUs in overview", "Все GPU в обзоре"),
+        SetupToggle::ReduceMotion => tr(language, "Reduce motion", "Без анимации"),
+        SetupToggle::Monochrome => tr(language, "Monochrome", "Монохромный режим"),
+        SetupToggle::ScriptNames => tr(language, "Script names", "Имена скриптов"),
+        SetupToggle::ShowPriority => tr(language, "PRI/NI columns", "Колонки PRI/NI"),
+        SetupToggle::ShowUnit => tr(language, "UNIT column", "Колонка UNIT"),
+        SetupToggle::ShowSwap => tr(language, "SWAP column", "Колонка SWAP"),
+        SetupToggle::ShowGpu => tr(language, "GPU columns", "Колонки GPU"),
+        SetupToggle::ConfirmQuit => tr(language, "Confirm quit", "Подтверждать выход"),
+    }
 }
@@ -7,7 +7,11 @@ use super::text::tr;
 use super::theme::{COLOR_MUTED, color_for_percent};
 use super::{panel_block, panel_block_focused};
 use crate::app::{App, Language};
-use crate::utils::{fit_text, format_bytes, percent, text_width};
+use crate::data::display_name;
+use crate::utils::{ByteUnit, fit_text, format_bytes_with, percent, render_bar, text_width};
+
+const TOP_CPU_COUNT: usize = 5;
+const TOP_CPU_NAME_WIDTH: usize = 24;
 
 pub fn render_with_focus(frame: &mut Frame, area: Rect, app: &App, focused: bool) {
     let chunks = Layout::default()
@@ -33,7 +37,12 @@ fn render_cpu_panel(frame: &mut Frame, area: Rect, app: &App, focused: bool) {
         return;
     }
 
-    let cpu_pct = clamp_pct(app.system.global_cpu_usage());
+    let has_cpus = !app.system.cpus().is_empty();
+    let cpu_pct = if has_cpus {
+        clamp_pct(app.system.global_cpu_usage())
+    } else {
+        0.0
+    };
     let total_rows = 2u16;
     let start_y = inner
         .y
@@ -61,7 +70,11 @@ fn render_cpu_panel(frame: &mut Frame, area: Rect, app: &App, focused: bool) {
             width: inner.width,
             height: 1,
         };
-        let metric_text = format!("{:>4.1}%", cpu_pct);
+        let metric_text = if has_cpus {
+            format!("{:>4.1}%", cpu_pct)
+        } else {
+            tr(app.language, "n/a", "н/д").to_string()
+        };
         render_centered_text(
             frame,
             metric_area,
@@ -120,7 +133,60 @@ fn render_memory_panel(frame: &mut Frame, area: Rect, app: &App, focused: bool)
         },
     ];
 
-    render_memory_metrics(frame, inner, app.language, &metrics);
+    render_memory_metrics(frame, inner, app.language, app.byte_unit, &metrics);
+}
+
+/// Height of the "Top by CPU" panel including borders
+pub fn top_cpu_height() -> u16 {
+    TOP_CPU_COUNT as u16 + 2
+}
+
+/// Compact bars for the busiest processes, independent of the table sort.
+pub fn render_top_cpu(frame: &mut Frame, area: Rect, app: &App) {
+    let title = format!(
+        "{} {TOP_CPU_COUNT} {}",
+        tr(app.language, "Top", "Топ"),
+        tr(app.language, "by CPU", "по CPU")
+    );
+    let block = panel_block(&title);
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+    if inner.width == 0 || inner.height == 0 {
+        return;
+    }
+
+    let mut top = app
+        .system
+        .processes()
+        .values()
+        .map(|process| (process.cpu_usage(), process.name()))
+        .collect::<Vec<_>>();
+    top.sort_by(|a, b| b.0.total_cmp(&a.0));
+    top.truncate(TOP_CPU_COUNT.min(inner.height as usize));
+
+    let width = inner.width as usize;
+    let name_width = TOP_CPU_NAME_WIDTH.min(width / 3);
+    let value_width = 7;
+    let bar_width = width.saturating_sub(name_width + value_width + 2);
+    let lines = top
+        .into_iter()
+        .map(|(cpu, name)| {
+            let (name, _) = display_name(name);
+            let pct = clamp_pct(cpu);
+            Line::from(vec![
+                Span::styled(
+                    format!("{:<name_width$} ", fit_text(&name, name_width)),
+                    Style::default().fg(Color::White),
+                ),
+                Span::styled(
+                    render_bar(pct, bar_width),
+                    Style::default().fg(color_for_percent(pct)),
+                ),
+                Span::styled(format!(" {cpu:>5.1}%"), Style::default().fg(COLOR_MUTED)),
+            ])
+        })
+        .collect::<Vec<_>>();
+    frame.render_widget(Paragraph::new(lines), inner);
 }
 
 #[derive(Clone, Copy)]
@@ -135,6 +201,7 @@ fn render_memory_metrics(
     frame: &mut Frame,
     area: Rect,
     language: Language,
+    byte_unit: ByteUnit,
     metrics: &[MetricSpec<'_>],
 ) {
     if area.width == 0 || area.height == 0 || metrics.is_empty() {
@@ -195,7 +262,8 @@ fn render_memory_metrics(
             height: 1,
         };
         if metric_area.y < bottom && metric_area.width > 0 {
-            let value = metric_value_text(language, metric.used, metric.total, metric.pct);
+            let value =
+                metric_value_text(language, byte_unit, metric.used, metric.total, metric.pct);
             render_centered_text(
                 frame,
                 metric_area,
@@ -254,12 +322,18 @@ fn render_line_gauge(
     frame.render_widget(gauge, area);
 }
 
-fn metric_value_text(language: Language, used: u64, total: u64, pct: f32) -> String {
+fn metric_value_text(
+    language: Language,
+    byte_unit: ByteUnit,
+    used: u64,
+    total: u64,
+    pct: f32,
+) -> String {
     if total > 0 {
         format!(
             "{}/{} {:>4.1}%",
-            format_bytes(used),
-            format_bytes(total),
+            format_bytes_with(used, byte_unit),
+            format_bytes_with(total, byte_unit),
             pct
         )
     } else {
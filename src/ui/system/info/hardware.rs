@@ -1,14 +1,16 @@
+use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::sync::OnceLock;
 use std::time::Duration;
 
-use sysinfo::Motherboard;
+use sysinfo::{Disk, Disks, Motherboard};
 
 use crate::app::{App, Language};
-use crate::data::gpu::{GpuKind, gpu_vendor_label, short_device_name};
+use crate::data::gpu::{GpuInfo, GpuKind, gpu_vendor_label, short_device_name};
+use crate::data::mounts::MountInfo;
 use crate::ui::text::tr;
-use crate::utils::{format_bytes, percent, run_command_with_timeout};
+use crate::utils::{format_bytes_with, percent, run_command_with_timeout};
 
 pub fn motherboard_summary() -> Option<String> {
     static CACHE: OnceLock<Option<String>> = OnceLock::new();
@@ -29,8 +31,12 @@ pub fn motherboard_summary() -> Option<String> {
         .clone()
 }
 
-pub fn cpu_overview_line(cpu_brand: &str, cpu_count: usize, cpu_list: &[sysinfo::Cpu]) -> String {
-    let mut line = format!("{cpu_brand} ({cpu_count})");
+/// "Brand (N) @ freq"; None while sysinfo reports no CPUs (some VMs/containers).
+pub fn cpu_overview_line(cpu_brand: &str, cpu_list: &[sysinfo::Cpu]) -> Option<String> {
+    if cpu_list.is_empty() {
+        return None;
+    }
+    let mut line = format!("{cpu_brand} ({})", cpu_list.len());
     if let Some((_min_mhz, max_mhz)) = cpu_passport_freq_range() {
         line.push_str(" @ ");
         line.push_str(&format_freq(max_mhz));
@@ -38,7 +44,16 @@ pub fn cpu_overview_line(cpu_brand: &str, cpu_count: usize, cpu_list: &[sysinfo:
         line.push_str(" @ ");
         line.push_str(&freq);
     }
-    line
+    Some(line)
+}
+
+/// "8P / 16L", or just the logical count when the physical one is unknown.
+pub fn cpu_cores_label(physical: Option<usize>, logical: usize) -> Option<String> {
+    match physical {
+        _ if logical == 0 => None,
+        Some(physical) if physical > 0 => Some(format!("{physical}P / {logical}L")),
+        _ => Some(format!("{logical}L")),
+    }
 }
 
 pub fn summarize_cpu_freq(cpus: &[sysinfo::Cpu]) -> Option<String> {
@@ -113,8 +128,24 @@ pub fn format_freq(mhz: u64) -> String {
     }
 }
 
-pub fn gpu_summary(app: &App, language: Language) -> Option<String> {
-    let (_idx, gpu) = app.selected_gpu()?;
+/// One overview line per GPU (e.g. iGPU and dGPU on hybrid laptops) with
+/// `overview_all_gpus`, otherwise just the selected GPU.
+pub fn gpu_summary_lines(app: &App, language: Language) -> Vec<String> {
+    if app.overview_all_gpus && app.gpu_list.len() > 1 {
+        return app
+            .gpu_list
+            .iter()
+            .map(|gpu| gpu_label(gpu, language))
+            .collect();
+    }
+    app.selected_gpu()
+        .map(|(_idx, gpu)| gpu_label(gpu, language))
+        .into_iter()
+        .collect()
+}
+
+/// "NVIDIA RTX 4070 [Discrete]"
+fn gpu_label(gpu: &GpuInfo, language: Language) -> String {
     let vendor = gpu_vendor_label(gpu);
     let device_name = gpu
         .device
@@ -137,19 +168,19 @@ pub fn gpu_summary(app: &App, language: Language) -> Option<String> {
         label.push_str(kind_label);
         label.push(']');
     }
-    Some(label)
+    label
 }
 
 pub fn disk_summary_lines(app: &App) -> Vec<String> {
     let mut entries = Vec::new();
     let mut seen = std::collections::HashSet::new();
-    for disk in app.disks.iter() {
+    for disk in unique_disks(&app.disks, &app.mount_table) {
         let total = disk.total_space();
         if total == 0 {
             continue;
         }
         let fs = disk.file_system().to_string_lossy();
-        if should_skip_fs(&fs) {
+        if !fs_visible(app, &fs) {
             continue;
         }
         let avail = disk.available_space();
@@ -157,8 +188,8 @@ pub fn disk_summary_lines(app: &App) -> Vec<String> {
         let pct = percent(used, total);
         let mut line = format!(
             "{} / {} ({pct:.0}%)",
-            format_bytes(used),
-            format_bytes(total)
+            format_bytes_with(used, app.byte_unit),
+            format_bytes_with(total, app.byte_unit)
         );
         if !fs.trim().is_empty() {
             line.push_str(" - ");
@@ -172,6 +203,43 @@ pub fn disk_summary_lines(app: &App) -> Vec<String> {
     entries.into_iter().map(|(_, line)| line).collect()
 }
 
+/// Disks with bind mounts and repeated mounts of one device collapsed, keeping
+/// the shortest mount point per (major, minor) device.
+pub(super) fn unique_disks<'a>(
+    all_disks: &'a Disks,
+    mounts: &HashMap<PathBuf, MountInfo>,
+) -> Vec<&'a Disk> {
+    let mut by_device: HashMap<(u32, u32), &Disk> = HashMap::new();
+    let mut disks = Vec::new();
+    for disk in all_disks.iter() {
+        let Some(device) = mounts.get(disk.mount_point()).map(|info| info.device) else {
+            disks.push(disk);
+            continue;
+        };
+        let mount_len = disk.mount_point().as_os_str().len();
+        by_device
+            .entry(device)
+            .and_modify(|current| {
+                if mount_len < current.mount_point().as_os_str().len() {
+                    *current = disk;
+                }
+            })
+            .or_insert(disk);
+    }
+    disks.extend(by_device.into_values());
+    disks.sort_by(|a, b| a.mount_point().cmp(b.mount_point()));
+    disks
+}
+
+/// Whether a filesystem type is listed: `show_fs` wins over `hide_fs`, which
+/// extends the built-in pseudo-filesystem list. The runtime toggle shows everything.
+pub(super) fn fs_visible(app: &App, fs: &str) -> bool {
+    if app.show_all_fs || app.show_fs.iter().any(|name| name == fs) {
+        return true;
+    }
+    !app.hide_fs.iter().any(|name| name == fs) && !should_skip_fs(fs)
+}
+
 fn should_skip_fs(fs: &str) -> bool {
     matches!(
         fs,
@@ -463,3 +531,19 @@ fn is_touchpad_name(name: &str) -> bool {
     let lower = name.to_ascii_lowercase();
     lower.contains("touchpad") || lower.contains("trackpad") || lower.contains("trackpoint")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_cpu_list_is_not_available() {
+        assert_eq!(cpu_overview_line("unknown", &[]), None);
+        assert_eq!(summarize_cpu_freq(&[]), None);
+        assert_eq!(max_cpu_freq(&[]), None);
+        assert_eq!(cpu_cores_label(None, 0), None);
+        assert_eq!(cpu_cores_label(Some(4), 0), None);
+        assert_eq!(cpu_cores_label(Some(0), 8).as_deref(), Some("8L"));
+        assert_eq!(cpu_cores_label(Some(4), 8).as_deref(), Some("4P / 8L"));
+    }
+}
@@ -26,6 +26,7 @@ pub const ICON_UPTIME: IconLabel = IconLabel::new("󰅐", "Uptime:");
 pub const ICON_BOARD: IconLabel = IconLabel::new("󰾰", "Board:");
 pub const ICON_CPU: IconLabel = IconLabel::new("󰻠", "CPU:");
 pub const ICON_GPU: IconLabel = IconLabel::new("󰍛", "GPU:");
+pub const ICON_VRAM: IconLabel = IconLabel::new("󰢮", "VRAM:");
 pub const ICON_MEM: IconLabel = IconLabel::new("", "RAM:");
 pub const ICON_DISK: IconLabel = IconLabel::new("", "Disk:");
 pub const ICON_DISPLAY: IconLabel = IconLabel::new("󰍹", "Display:");
@@ -11,14 +11,16 @@ use ratatui::prelude::*;
 use ratatui::widgets::Paragraph;
 use sysinfo::System;
 
-use crate::app::{App, SystemTab};
+use crate::app::{App, IconMode, SystemTab};
 use crate::ui::text::tr;
 use crate::ui::theme::{COLOR_ACCENT, COLOR_MUTED};
 use crate::utils::percent;
 
-use hardware::summarize_cpu_freq;
-use overview::{OverviewLayout, ensure_snapshot, push_overview_lines};
-use tabs::{TabLayout, push_cpu, push_disks, push_memory, push_network, push_temps};
+use hardware::{cpu_cores_label, summarize_cpu_freq};
+use overview::{
+    OverviewLayout, build_system_overview_snapshot, ensure_snapshot, push_overview_lines,
+};
+use tabs::{TabLayout, push_cpu, push_disks, push_memory, push_network, push_sensors, push_temps};
 
 pub fn render_info(frame: &mut Frame, area: Rect, app: &mut App) {
     if area.width == 0 || area.height == 0 {
@@ -27,6 +29,38 @@ pub fn render_info(frame: &mut Frame, area: Rect, app: &mut App) {
     render_info_body(frame, area, app);
 }
 
+/// Plain-text copy of the System overview (text icons, no styling) for pasting
+/// into bug reports.
+pub fn system_overview_text(app: &mut App) -> String {
+    if app.system_overview_snapshot.is_none() {
+        app.system_overview_snapshot = Some(build_system_overview_snapshot(app));
+    }
+    let Some(snapshot) = app.system_overview_snapshot.as_ref() else {
+        return String::new();
+    };
+    let layout = OverviewLayout {
+        width: usize::MAX / 2,
+        icon_style: Style::default(),
+        sep_style: Style::default(),
+        value_style: Style::default(),
+        icon_mode: IconMode::Text,
+    };
+    let mut lines = Vec::new();
+    push_overview_lines(&mut lines, snapshot, layout, tr(app.language, "n/a", "н/д"));
+    lines
+        .iter()
+        .map(|line| {
+            let text = line
+                .spans
+                .iter()
+                .map(|span| span.content.as_ref())
+                .collect::<String>();
+            text.trim_end().to_string()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 fn render_info_body(frame: &mut Frame, area: Rect, app: &mut App) {
     if area.width == 0 || area.height == 0 {
         return;
@@ -57,15 +91,10 @@ fn render_info_body(frame: &mut Frame, area: Rect, app: &mut App) {
         .map(|cpu| cpu.brand().to_string())
         .filter(|value| !value.trim().is_empty())
         .unwrap_or_else(|| unknown.to_string());
-    let cpu_count = cpu_list.len();
-    let cpu_physical = System::physical_core_count();
-    let cpu_cores = match cpu_physical {
-        Some(physical) if physical > 0 => format!("{physical}P / {cpu_count}L"),
-        _ => format!("{cpu_count}L"),
-    };
+    let cpu_cores = cpu_cores_label(System::physical_core_count(), cpu_list.len())
+        .unwrap_or_else(|| na.to_string());
     let cpu_freq = summarize_cpu_freq(cpu_list).unwrap_or_else(|| na.to_string());
-    let cpu_usage = app.system.global_cpu_usage();
-    let load = System::load_average();
+    let cpu_usage = (!cpu_list.is_empty()).then(|| app.system.global_cpu_usage());
     let total_mem = app.system.total_memory();
     let used_mem = app.system.used_memory();
     let free_mem = app.system.free_memory();
@@ -102,7 +131,14 @@ fn render_info_body(frame: &mut Frame, area: Rect, app: &mut App) {
         }
         SystemTab::Cpu => {
             push_cpu(
-                &mut lines, app, tab_layout, &cpu_brand, &cpu_cores, &cpu_freq, cpu_usage, load,
+                &mut lines,
+                app,
+                tab_layout,
+                &cpu_brand,
+                &cpu_cores,
+                &cpu_freq,
+                cpu_usage,
+                &app.load_average,
             );
         }
         SystemTab::Memory => {
@@ -120,6 +156,9 @@ fn render_info_body(frame: &mut Frame, area: Rect, app: &mut App) {
         SystemTab::Temps => {
             push_temps(&mut lines, app, tab_layout, na);
         }
+        SystemTab::Sensors => {
+            push_sensors(&mut lines, app, tab_layout, na);
+        }
     }
 
     let max_lines = area.height as usize;
@@ -129,3 +168,41 @@ fn render_info_body(frame: &mut Frame, area: Rect, app: &mut App) {
     let paragraph = Paragraph::new(lines);
     frame.render_widget(paragraph, area);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::Config;
+    use ratatui::backend::TestBackend;
+
+    #[test]
+    fn cpu_tab_renders_without_cpus() {
+        let mut app = App::new(Config {
+            vram_enabled: false,
+            ..Config::default()
+        });
+        app.system = System::new();
+        app.set_system_tab(SystemTab::Cpu);
+
+        let mut terminal = Terminal::new(TestBackend::new(80, 40)).unwrap();
+        terminal
+            .draw(|frame| render_info(frame, frame.area(), &mut app))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let rows: Vec<String> = (0..buffer.area.height)
+            .map(|y| {
+                (0..buffer.area.width)
+                    .map(|x| buffer[(x, y)].symbol())
+                    .collect()
+            })
+            .collect();
+        // Section headers share the label text but have no value
+        for label in ["Core Speed", "Cores", "Usage"] {
+            let row = rows
+                .iter()
+                .find(|row| row.starts_with(label) && row.trim_end().len() > label.len())
+                .unwrap_or_else(|| panic!("no {label} line in {rows:#?}"));
+            assert!(row.to_lowercase().contains("n/a"), "{row}");
+        }
+    }
+}
@@ -83,6 +83,26 @@ pub fn is_immutable_os() -> bool {
     Path::new("/run/ostree-booted").exists() || Path::new("/sysroot/ostree").exists()
 }
 
+/// "WSL" note when running under the Windows Subsystem for Linux, with the
+/// distro name when `WSL_DISTRO_NAME` is set.
+pub fn wsl_note() -> Option<String> {
+    static CACHE: OnceLock<Option<String>> = OnceLock::new();
+    CACHE
+        .get_or_init(|| {
+            let distro = std::env::var("WSL_DISTRO_NAME")
+                .ok()
+                .filter(|name| !name.is_empty());
+            let microsoft_kernel = fs::read_to_string("/proc/version")
+                .is_ok_and(|version| version.to_ascii_lowercase().contains("microsoft"));
+            match distro {
+                Some(distro) => Some(format!("WSL ({distro})")),
+                None if microsoft_kernel => Some("WSL".to_string()),
+                None => None,
+            }
+        })
+        .clone()
+}
+
 pub fn format_uptime_long(uptime_secs: u64, language: Language) -> String {
     let mut remaining = uptime_secs;
     let days = remaining / 86_400;
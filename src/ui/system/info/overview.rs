@@ -3,21 +3,22 @@ use ratatui::text::Line;
 use sysinfo::System;
 
 use crate::app::{App, IconMode, SystemOverviewSnapshot, SystemTab};
+use crate::data::gpu::total_vram;
 use crate::ui::text::tr;
-use crate::utils::{format_bytes, percent};
+use crate::utils::{format_bytes_with, percent};
 
 use super::hardware::{
-    cpu_overview_line, disk_summary_lines, display_summary, gpu_summary, motherboard_summary,
+    cpu_overview_line, disk_summary_lines, display_summary, gpu_summary_lines, motherboard_summary,
     mouse_name,
 };
 use super::icons::{
     ICON_BOARD, ICON_CPU, ICON_DE, ICON_DISK, ICON_DISPLAY, ICON_DISTRO, ICON_GPU, ICON_KERNEL,
     ICON_MEM, ICON_MOUSE, ICON_OS, ICON_PKG, ICON_SHELL, ICON_TERM, ICON_UPTIME, ICON_USER,
-    ICON_WM,
+    ICON_VRAM, ICON_WM,
 };
 use super::layout::push_icon_line;
-use super::os::{distro_variant_line, format_uptime_long, os_release};
-use super::packages::package_summary;
+use super::os::{distro_variant_line, format_uptime_long, os_release, wsl_note};
+use super::packages::{PackageSummary, package_summary};
 use super::software::{desktop_environment, shell_name, terminal_name, window_manager};
 
 #[derive(Clone, Copy)]
@@ -30,9 +31,24 @@ pub(super) struct OverviewLayout {
 }
 
 pub(super) fn ensure_snapshot(app: &mut App) {
-    if app.system_tab == SystemTab::Overview && app.system_overview_snapshot.is_none() {
-        let snapshot = build_system_overview_snapshot(app);
-        app.system_overview_snapshot = Some(snapshot);
+    if app.system_tab != SystemTab::Overview {
+        return;
+    }
+    let language = app.language;
+    match app.system_overview_snapshot.as_mut() {
+        None => {
+            let snapshot = build_system_overview_snapshot(app);
+            app.system_overview_snapshot = Some(snapshot);
+        }
+        Some(snapshot) if snapshot.package_pending => {
+            // The package count finishes on a background thread; patch it in once ready
+            if let PackageSummary::Ready(summary) = package_summary() {
+                snapshot.package_line =
+                    summary.unwrap_or_else(|| tr(language, "n/a", "н/д").to_string());
+                snapshot.package_pending = false;
+            }
+        }
+        Some(_) => {}
     }
 }
 
@@ -116,10 +132,22 @@ pub(super) fn push_overview_lines(
         layout.value_style,
         layout.icon_mode,
     );
+    for gpu_line in &snapshot.gpu_lines {
+        push_icon_line(
+            lines,
+            &ICON_GPU,
+            gpu_line.clone(),
+            layout.width,
+            layout.icon_style,
+            layout.sep_style,
+            layout.value_style,
+            layout.icon_mode,
+        );
+    }
     push_icon_line(
         lines,
-        &ICON_GPU,
-        snapshot.gpu_line.clone(),
+        &ICON_VRAM,
+        snapshot.vram_line.clone(),
         layout.width,
         layout.icon_style,
         layout.sep_style,
@@ -175,26 +203,30 @@ pub(super) fn push_overview_lines(
         layout.value_style,
         layout.icon_mode,
     );
-    push_icon_line(
-        lines,
-        &ICON_MOUSE,
-        snapshot.mouse_line.clone(),
-        layout.width,
-        layout.icon_style,
-        layout.sep_style,
-        layout.value_style,
-        layout.icon_mode,
-    );
-    push_icon_line(
-        lines,
-        &ICON_DE,
-        snapshot.de_line.clone(),
-        layout.width,
-        layout.icon_style,
-        layout.sep_style,
-        layout.value_style,
-        layout.icon_mode,
-    );
+    if let Some(mouse_line) = snapshot.mouse_line.as_ref() {
+        push_icon_line(
+            lines,
+            &ICON_MOUSE,
+            mouse_line.clone(),
+            layout.width,
+            layout.icon_style,
+            layout.sep_style,
+            layout.value_style,
+            layout.icon_mode,
+        );
+    }
+    if let Some(de_line) = snapshot.de_line.as_ref() {
+        push_icon_line(
+            lines,
+            &ICON_DE,
+            de_line.clone(),
+            layout.width,
+            layout.icon_style,
+            layout.sep_style,
+            layout.value_style,
+            layout.icon_mode,
+        );
+    }
     push_icon_line(
         lines,
         &ICON_WM,
@@ -237,7 +269,7 @@ pub(super) fn push_overview_lines(
     );
 }
 
-fn build_system_overview_snapshot(app: &App) -> SystemOverviewSnapshot {
+pub(super) fn build_system_overview_snapshot(app: &App) -> SystemOverviewSnapshot {
     let unknown = tr(app.language, "unknown", "неизвестно");
     let na = tr(app.language, "n/a", "н/д");
 
@@ -267,18 +299,36 @@ fn build_system_overview_snapshot(app: &App) -> SystemOverviewSnapshot {
         .map(|cpu| cpu.brand().to_string())
         .filter(|value| !value.trim().is_empty())
         .unwrap_or_else(|| unknown.to_string());
-    let cpu_count = cpu_list.len();
-    let cpu_line = cpu_overview_line(&cpu_brand, cpu_count, cpu_list);
+    let cpu_line = cpu_overview_line(&cpu_brand, cpu_list).unwrap_or_else(|| na.to_string());
 
-    let gpu_line = gpu_summary(app, app.language).unwrap_or_else(|| na.to_string());
+    // GPU data arrives from the monitor thread after startup
+    let gpu_na = if app.gpu_probing() {
+        tr(app.language, "probing GPU…", "опрос GPU…")
+    } else {
+        na
+    };
+    let mut gpu_lines = gpu_summary_lines(app, app.language);
+    if gpu_lines.is_empty() {
+        gpu_lines.push(gpu_na.to_string());
+    }
+    let vram_line = total_vram(&app.gpu_list)
+        .map(|(used, total)| {
+            let pct = percent(used, total);
+            format!(
+                "{} / {} ({pct:.0}%)",
+                format_bytes_with(used, app.byte_unit),
+                format_bytes_with(total, app.byte_unit)
+            )
+        })
+        .unwrap_or_else(|| gpu_na.to_string());
 
     let total_mem = app.system.total_memory();
     let used_mem = app.system.used_memory();
     let mem_pct = percent(used_mem, total_mem);
     let mem_line = format!(
         "{} / {} ({mem_pct:.0}%)",
-        format_bytes(used_mem),
-        format_bytes(total_mem)
+        format_bytes_with(used_mem, app.byte_unit),
+        format_bytes_with(total_mem, app.byte_unit)
     );
 
     let mut disk_lines = disk_summary_lines(app);
@@ -286,13 +336,23 @@ fn build_system_overview_snapshot(app: &App) -> SystemOverviewSnapshot {
         disk_lines.push(na.to_string());
     }
 
-    let display_line = display_summary(app.language).unwrap_or_else(|| na.to_string());
-    let mouse_line = mouse_name().unwrap_or_else(|| na.to_string());
-    let de_line = desktop_environment().unwrap_or_else(|| na.to_string());
+    // xrandr/DRM, input devices and the desktop belong to Windows under WSL,
+    // so one WSL line replaces all three
+    let (display_line, mouse_line, de_line) = match wsl_note() {
+        Some(note) => (note, None, None),
+        None => (
+            display_summary(app.language).unwrap_or_else(|| na.to_string()),
+            Some(mouse_name().unwrap_or_else(|| na.to_string())),
+            Some(desktop_environment().unwrap_or_else(|| na.to_string())),
+        ),
+    };
     let wm_line = window_manager(app).unwrap_or_else(|| na.to_string());
     let shell_line = shell_name().unwrap_or_else(|| na.to_string());
     let terminal_line = terminal_name(app).unwrap_or_else(|| na.to_string());
-    let package_line = package_summary().unwrap_or_else(|| na.to_string());
+    let (package_line, package_pending) = match package_summary() {
+        PackageSummary::Pending => (tr(app.language, "counting…", "подсчёт…").to_string(), true),
+        PackageSummary::Ready(summary) => (summary.unwrap_or_else(|| na.to_string()), false),
+    };
 
     SystemOverviewSnapshot {
         user_host,
@@ -302,7 +362,8 @@ fn build_system_overview_snapshot(app: &App) -> SystemOverviewSnapshot {
         uptime_line,
         board_line,
         cpu_line,
-        gpu_line,
+        gpu_lines,
+        vram_line,
         mem_line,
         disk_lines,
         display_line,
@@ -312,5 +373,6 @@ fn build_system_overview_snapshot(app: &App) -> SystemOverviewSnapshot {
         shell_line,
         terminal_line,
         package_line,
+        package_pending,
     }
 }
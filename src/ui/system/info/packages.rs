@@ -1,93 +1,93 @@
 use std::env;
 use std::fs;
 use std::path::Path;
-use std::sync::OnceLock;
+use std::sync::{Once, OnceLock};
+use std::thread;
 use std::time::Duration;
 
 use crate::utils::run_command_with_timeout;
 
-pub fn package_summary() -> Option<String> {
-    static CACHE: OnceLock<Option<String>> = OnceLock::new();
-    CACHE.get_or_init(package_summary_inner).clone()
-}
-
-fn package_summary_inner() -> Option<String> {
-    let mut parts = Vec::new();
-    let timeout = Duration::from_secs(2);
+type PackageProbe = fn(Duration) -> Option<usize>;
 
+/// Package managers in display order. Each probe runs on its own thread.
+const PACKAGE_PROBES: &[(&str, PackageProbe)] = &[
     // RPM-based (Fedora, RHEL, openSUSE) - exclude gpg-pubkey packages
-    if let Some(count) = count_rpm(timeout)
-        && count > 0
-    {
-        parts.push(format!("{count} (rpm)"));
-    }
+    ("rpm", count_rpm),
     // Debian-based (Debian, Ubuntu)
-    if let Some(count) =
+    ("dpkg", |timeout| {
         count_command_lines("dpkg-query", &["-f", "${binary:Package}\\n", "-W"], timeout)
-        && count > 0
-    {
-        parts.push(format!("{count} (dpkg)"));
-    }
+    }),
     // Arch-based
-    if let Some(count) = count_command_lines("pacman", &["-Qq"], timeout)
-        && count > 0
-    {
-        parts.push(format!("{count} (pacman)"));
-    }
+    ("pacman", |timeout| {
+        count_command_lines("pacman", &["-Qq"], timeout)
+    }),
     // Gentoo
-    if let Some(count) = count_portage()
-        && count > 0
-    {
-        parts.push(format!("{count} (portage)"));
-    }
+    ("portage", |_| count_portage()),
     // Void Linux
-    if let Some(count) = count_command_lines("xbps-query", &["-l"], timeout)
-        && count > 0
-    {
-        parts.push(format!("{count} (xbps)"));
-    }
+    ("xbps", |timeout| {
+        count_command_lines("xbps-query", &["-l"], timeout)
+    }),
     // Alpine Linux
-    if let Some(count) = count_apk(timeout)
-        && count > 0
-    {
-        parts.push(format!("{count} (apk)"));
-    }
+    ("apk", count_apk),
     // Solus
-    if let Some(count) = count_command_lines("eopkg", &["li"], timeout)
-        && count > 0
-    {
-        parts.push(format!("{count} (eopkg)"));
-    }
+    ("eopkg", |timeout| {
+        count_command_lines("eopkg", &["li"], timeout)
+    }),
     // NixOS / Nix
-    if let Some(count) = count_nix(timeout)
-        && count > 0
-    {
-        parts.push(format!("{count} (nix)"));
-    }
+    ("nix", count_nix),
     // Flatpak (all packages including runtimes)
-    if let Some(count) = count_flatpak(timeout)
-        && count > 0
-    {
-        parts.push(format!("{count} (flatpak)"));
-    }
-    // Snap
-    if let Some(count) = count_command_lines("snap", &["list"], timeout) {
-        let count = count.saturating_sub(1); // Skip header line
-        if count > 0 {
-            parts.push(format!("{count} (snap)"));
-        }
-    }
+    ("flatpak", count_flatpak),
+    // Snap (skip header line)
+    ("snap", |timeout| {
+        count_command_lines("snap", &["list"], timeout).map(|count| count.saturating_sub(1))
+    }),
     // Homebrew (macOS/Linux)
-    if let Some(count) = count_command_lines("brew", &["list", "--formula"], timeout)
-        && count > 0
-    {
-        parts.push(format!("{count} (brew)"));
-    }
-    if let Some(count) = count_command_lines("brew", &["list", "--cask"], timeout)
-        && count > 0
-    {
-        parts.push(format!("{count} (brew-cask)"));
-    }
+    ("brew", |timeout| {
+        count_command_lines("brew", &["list", "--formula"], timeout)
+    }),
+    ("brew-cask", |timeout| {
+        count_command_lines("brew", &["list", "--cask"], timeout)
+    }),
+];
+
+/// Package count state for the overview.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PackageSummary {
+    /// The background count has not finished yet.
+    Pending,
+    Ready(Option<String>),
+}
+
+/// Returns the cached package summary, starting the background count on first call.
+pub fn package_summary() -> PackageSummary {
+    static CACHE: OnceLock<Option<String>> = OnceLock::new();
+    static STARTED: Once = Once::new();
+    if let Some(summary) = CACHE.get() {
+        return PackageSummary::Ready(summary.clone());
+    }
+    STARTED.call_once(|| {
+        thread::spawn(|| {
+            let _ = CACHE.set(package_summary_inner());
+        });
+    });
+    PackageSummary::Pending
+}
+
+fn package_summary_inner() -> Option<String> {
+    let timeout = Duration::from_secs(2);
+    let parts: Vec<String> = thread::scope(|scope| {
+        let handles: Vec<_> = PACKAGE_PROBES
+            .iter()
+            .map(|(label, probe)| (*label, scope.spawn(move || probe(timeout))))
+            .collect();
+        handles
+            .into_iter()
+            .filter_map(|(label, handle)| {
+                let count = handle.join().ok().flatten()?;
+                (count > 0).then(|| format!("{count} ({label})"))
+            })
+            .collect()
+    });
 
     if parts.is_empty() {
         None
@@ -6,7 +6,7 @@ use std::time::Duration;
 use sysinfo::Pid;
 
 use crate::app::App;
-use crate::utils::run_command_with_timeout;
+use crate::utils::{run_command_with_stderr, run_command_with_timeout};
 
 pub fn desktop_environment() -> Option<String> {
     static CACHE: OnceLock<Option<String>> = OnceLock::new();
@@ -85,14 +85,62 @@ pub fn window_manager(app: &App) -> Option<String> {
         }
     }
     let mut wm = wm?;
-    if let Some(session) = session_type() {
+    let session = session_type();
+    if session == Some("Wayland")
+        && let Some(version) = compositor_version(&wm)
+    {
+        wm.push(' ');
+        wm.push_str(&version);
+    }
+    if let Some(session) = session {
         wm.push_str(" (");
         wm.push_str(session);
+        if session == "X11"
+            && let Some(server) = x_server_version()
+        {
+            wm.push_str(", ");
+            wm.push_str(&server);
+        }
         wm.push(')');
     }
     Some(wm)
 }
 
+/// X server name and major.minor version, e.g. "Xorg 1.21"
+fn x_server_version() -> Option<String> {
+    static CACHE: OnceLock<Option<String>> = OnceLock::new();
+    CACHE
+        .get_or_init(|| {
+            // Xorg prints its version banner to stderr
+            let output =
+                run_command_with_stderr("Xorg", &["-version"], Duration::from_millis(400))?;
+            let version = extract_version_token(&output)?;
+            let short: Vec<&str> = version.split('.').take(2).collect();
+            Some(format!("Xorg {}", short.join(".")))
+        })
+        .clone()
+}
+
+/// Version of the Wayland compositor detected as the window manager
+fn compositor_version(wm: &str) -> Option<String> {
+    static CACHE: OnceLock<Option<String>> = OnceLock::new();
+    CACHE
+        .get_or_init(|| {
+            let (command, args): (&str, &[&str]) = match wm {
+                "KWin" => ("kwin_wayland", &["--version"]),
+                "Mutter" => ("gnome-shell", &["--version"]),
+                "Sway" => ("sway", &["--version"]),
+                "Hyprland" => ("Hyprland", &["--version"]),
+                "Wayfire" => ("wayfire", &["--version"]),
+                "River" => ("river", &["-version"]),
+                "LabWC" => ("labwc", &["--version"]),
+                _ => return None,
+            };
+            command_version(command, args)
+        })
+        .clone()
+}
+
 fn session_type() -> Option<&'static str> {
     let session = env::var("XDG_SESSION_TYPE").ok()?;
     if session.eq_ignore_ascii_case("wayland") {
@@ -124,12 +172,36 @@ fn shell_name_inner() -> Option<String> {
         _ => None,
     }
     .and_then(|value| extract_version_token(&value));
-    Some(match version {
+    let shell = match version {
         Some(version) => format!("{name} {version}"),
         None => name,
+    };
+    Some(match prompt_framework() {
+        Some(framework) => format!("{shell} ({framework})"),
+        None => shell,
     })
 }
 
+/// Prompt framework detected from the variables it exports into the environment.
+fn prompt_framework() -> Option<&'static str> {
+    let is_set = |key: &str| env::var_os(key).is_some_and(|value| !value.is_empty());
+    if is_set("STARSHIP_SHELL") || is_set("STARSHIP_SESSION_KEY") {
+        return Some("starship");
+    }
+    if is_set("POSH_THEME") || is_set("POSH_SHELL_VERSION") {
+        return Some("oh-my-posh");
+    }
+    let zsh_theme = env::var("ZSH_THEME").unwrap_or_default();
+    if zsh_theme.contains("powerlevel10k") || is_set("POWERLEVEL9K_INSTANT_PROMPT") {
+        return Some("powerlevel10k");
+    }
+    // oh-my-zsh exports its install dir as $ZSH
+    if env::var("ZSH").is_ok_and(|path| path.contains("oh-my-zsh")) {
+        return Some("oh-my-zsh");
+    }
+    None
+}
+
 pub fn terminal_name(app: &App) -> Option<String> {
     static CACHE: OnceLock<Option<String>> = OnceLock::new();
     CACHE.get_or_init(|| terminal_name_inner(app)).clone()
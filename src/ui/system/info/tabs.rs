@@ -1,15 +1,24 @@
 use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::Instant;
 
 use ratatui::prelude::{Color, Style};
 use ratatui::text::{Line, Span};
-use sysinfo::LoadAvg;
+use sysinfo::{Disk, LoadAvg};
 
-use crate::app::App;
-use crate::data::cpu::CpuDetails;
+use crate::app::{App, DiskLabelMode, TempsSort};
+use crate::data::cpu::{CpuDetails, MitigationLevel, Mitigations, cpu_mitigations};
+use crate::data::mounts::MountInfo;
+use crate::data::sensors::split_component_label;
 use crate::data::{cpu_caches, cpu_details, lookup_cpu_codename};
 use crate::ui::text::tr;
-use crate::utils::{format_bytes, percent, text_width};
+use crate::ui::theme::{COLOR_HOT, COLOR_WARN, alert_flash_style};
+use crate::utils::{
+    format_bytes_compact, format_bytes_with, format_temp, format_temp_compact, percent, text_width,
+};
 
+use super::hardware::{fs_visible, unique_disks};
 use super::layout::{push_header, push_line};
 
 #[derive(Clone, Copy)]
@@ -29,8 +38,8 @@ pub(super) fn push_cpu(
     cpu_brand: &str,
     cpu_cores: &str,
     cpu_freq: &str,
-    cpu_usage: f32,
-    load: LoadAvg,
+    cpu_usage: Option<f32>,
+    load: &LoadAvg,
 ) {
     let details = cpu_details();
     let caches = cpu_caches();
@@ -52,6 +61,8 @@ pub(super) fn push_cpu(
         tr(app.language, "Cores", "Ядра"),
         tr(app.language, "Usage", "Загр."),
         tr(app.language, "Load", "Нагрузка"),
+        tr(app.language, "Tasks", "Задачи"),
+        "PSI cpu",
         "L1 Data",
         "L2",
         "L3",
@@ -210,7 +221,7 @@ pub(super) fn push_cpu(
     push_line(
         lines,
         tr(app.language, "Usage", "Загр."),
-        format!("{cpu_usage:.1}%"),
+        cpu_usage.map_or_else(|| na.to_string(), |usage| format!("{usage:.1}%")),
         layout.width,
         label_width,
         layout.label_style,
@@ -227,6 +238,56 @@ pub(super) fn push_cpu(
         layout.value_style,
     );
 
+    // Running/total scheduler tasks from /proc/loadavg (Linux only)
+    if let Some(tasks) = app.task_counts {
+        push_line(
+            lines,
+            tr(app.language, "Tasks", "Задачи"),
+            format!(
+                "{} {} / {} {}",
+                tasks.running,
+                tr(app.language, "running", "выполняются"),
+                tasks.total,
+                tr(app.language, "total", "всего")
+            ),
+            layout.width,
+            label_width,
+            layout.label_style,
+            layout.value_style,
+        );
+    }
+
+    // Pressure stall info is omitted on kernels without PSI
+    if let Some(pressure) = app.pressure.cpu {
+        push_line(
+            lines,
+            "PSI cpu",
+            pressure.format(),
+            layout.width,
+            label_width,
+            layout.label_style,
+            layout.value_style,
+        );
+    }
+
+    // Omitted when the kernel has no vulnerabilities directory
+    if let Some(mitigations) = cpu_mitigations() {
+        let value_style = match mitigations.level() {
+            MitigationLevel::All => layout.value_style,
+            MitigationLevel::Partial => layout.value_style.fg(COLOR_WARN),
+            MitigationLevel::Off => layout.value_style.fg(COLOR_HOT),
+        };
+        push_line(
+            lines,
+            tr(app.language, "Mitigations", "Защита"),
+            mitigation_summary(mitigations, app),
+            layout.width,
+            label_width,
+            layout.label_style,
+            value_style,
+        );
+    }
+
     // Section: Cache
     push_header(
         lines,
@@ -279,6 +340,31 @@ pub(super) fn push_cpu(
     }
 }
 
+/// "partial (4/5) · vulnerable: mds"
+fn mitigation_summary(mitigations: &Mitigations, app: &App) -> String {
+    let level = match mitigations.level() {
+        MitigationLevel::All => tr(app.language, "all", "все"),
+        MitigationLevel::Partial => tr(app.language, "partial", "частично"),
+        MitigationLevel::Off => tr(app.language, "off", "выкл"),
+    };
+    let mut summary = level.to_string();
+    if mitigations.affected() > 0 {
+        summary.push_str(&format!(
+            " ({}/{})",
+            mitigations.mitigated,
+            mitigations.affected()
+        ));
+    }
+    if !mitigations.vulnerable.is_empty() {
+        summary.push_str(&format!(
+            " · {}: {}",
+            tr(app.language, "vulnerable", "уязвимо"),
+            mitigations.vulnerable.join(", ")
+        ));
+    }
+    summary
+}
+
 #[allow(clippy::too_many_arguments)]
 pub(super) fn push_memory(
     lines: &mut Vec<Line<'static>>,
@@ -304,8 +390,8 @@ pub(super) fn push_memory(
         tr(app.language, "RAM", "ОЗУ"),
         format!(
             "{} / {} ({mem_pct:.0}%)",
-            format_bytes(used_mem),
-            format_bytes(total_mem)
+            format_bytes_with(used_mem, app.byte_unit),
+            format_bytes_with(total_mem, app.byte_unit)
         ),
         layout.width,
         layout.label_width,
@@ -315,7 +401,7 @@ pub(super) fn push_memory(
     push_line(
         lines,
         tr(app.language, "Avail", "Дост."),
-        format_bytes(avail_mem),
+        format_bytes_with(avail_mem, app.byte_unit),
         layout.width,
         layout.label_width,
         layout.label_style,
@@ -324,7 +410,7 @@ pub(super) fn push_memory(
     push_line(
         lines,
         tr(app.language, "Free", "Своб."),
-        format_bytes(free_mem),
+        format_bytes_with(free_mem, app.byte_unit),
         layout.width,
         layout.label_width,
         layout.label_style,
@@ -335,23 +421,61 @@ pub(super) fn push_memory(
         tr(app.language, "Swap", "Swap"),
         format!(
             "{} / {} ({swap_pct:.0}%)",
-            format_bytes(used_swap),
-            format_bytes(total_swap)
+            format_bytes_with(used_swap, app.byte_unit),
+            format_bytes_with(total_swap, app.byte_unit)
         ),
         layout.width,
         layout.label_width,
         layout.label_style,
         layout.value_style,
     );
+
+    // /proc/meminfo breakdown (Linux only)
+    if let Some(details) = app.meminfo {
+        for (label, value) in [
+            (tr(app.language, "Buffers", "Буферы"), details.buffers),
+            (tr(app.language, "Cached", "Кэш"), details.cached),
+            (tr(app.language, "Dirty", "Грязные"), details.dirty),
+            (tr(app.language, "Shmem", "Shmem"), details.shmem),
+        ] {
+            push_line(
+                lines,
+                label,
+                format_bytes_with(value, app.byte_unit),
+                layout.width,
+                layout.label_width,
+                layout.label_style,
+                layout.value_style,
+            );
+        }
+    }
+
+    // Pressure stall info is omitted on kernels without PSI
+    for (label, pressure) in [
+        ("PSI mem", app.pressure.memory),
+        ("PSI io", app.pressure.io),
+    ] {
+        if let Some(pressure) = pressure {
+            push_line(
+                lines,
+                label,
+                pressure.format(),
+                layout.width,
+                layout.label_width,
+                layout.label_style,
+                layout.value_style,
+            );
+        }
+    }
 }
 
 pub(super) fn push_disks(lines: &mut Vec<Line<'static>>, app: &App, layout: TabLayout, na: &str) {
-    push_header(
-        lines,
-        tr(app.language, "Disks", "Диски"),
-        layout.width,
-        layout.section_style,
-    );
+    let title = if app.show_all_fs {
+        tr(app.language, "Disks (all filesystems)", "Диски (все ФС)")
+    } else {
+        tr(app.language, "Disks", "Диски")
+    };
+    push_header(lines, title, layout.width, layout.section_style);
     if app.disks.is_empty() {
         push_line(
             lines,
@@ -364,21 +488,34 @@ pub(super) fn push_disks(lines: &mut Vec<Line<'static>>, app: &App, layout: TabL
         );
         return;
     }
-    for disk in app.disks.iter() {
+    for disk in unique_disks(&app.disks, &app.mount_table) {
+        if !fs_visible(app, &disk.file_system().to_string_lossy()) {
+            continue;
+        }
         let total = disk.total_space();
         let avail = disk.available_space();
         let used = total.saturating_sub(avail);
         let pct = percent(used, total);
-        let mount = disk.mount_point().display().to_string();
+        let label = match app.disk_label_mode {
+            DiskLabelMode::MountPoint => disk.mount_point().display().to_string(),
+            DiskLabelMode::Device => disk_device_name(disk, &app.mount_table),
+        };
         let fs = disk.file_system().to_string_lossy();
+        let temp = disk
+            .name()
+            .to_str()
+            .and_then(|name| app.disk_temps.get(name))
+            .copied()
+            .map(|temp| format_temp_compact(temp, app.temp_unit))
+            .unwrap_or_else(|| na.to_string());
         let value = format!(
-            "{} / {} ({pct:.0}%) {fs}",
-            format_bytes(used),
-            format_bytes(total)
+            "{} / {} ({pct:.0}%) {fs} {temp}",
+            format_bytes_with(used, app.byte_unit),
+            format_bytes_with(total, app.byte_unit)
         );
         push_line(
             lines,
-            &mount,
+            &label,
             value,
             layout.width,
             layout.label_width,
@@ -388,6 +525,18 @@ pub(super) fn push_disks(lines: &mut Vec<Line<'static>>, app: &App, layout: TabL
     }
 }
 
+/// Backing device of a disk: sysinfo's name, else the mountinfo source.
+fn disk_device_name(disk: &Disk, mounts: &HashMap<PathBuf, MountInfo>) -> String {
+    let name = disk.name().to_string_lossy();
+    if name.starts_with('/') {
+        return name.into_owned();
+    }
+    mounts
+        .get(disk.mount_point())
+        .map(|info| info.source.clone())
+        .unwrap_or_else(|| name.into_owned())
+}
+
 pub(super) fn push_network(
     lines: &mut Vec<Line<'static>>,
     app: &App,
@@ -414,21 +563,21 @@ pub(super) fn push_network(
         return;
     }
     let mut networks = app.networks.iter().collect::<Vec<_>>();
-    networks.sort_by(|(a, _), (b, _)| a.cmp(b));
+    networks.sort_by_key(|(a, _)| *a);
     for (name, data) in networks {
         let value = if let Some(secs) = net_refresh {
             let rx_rate = (data.received() as f64 / secs).round() as u64;
             let tx_rate = (data.transmitted() as f64 / secs).round() as u64;
             format!(
                 "rx {}/s tx {}/s",
-                format_bytes(rx_rate),
-                format_bytes(tx_rate)
+                format_bytes_compact(rx_rate, app.byte_unit),
+                format_bytes_compact(tx_rate, app.byte_unit)
             )
         } else {
             format!(
                 "rx {} tx {}",
-                format_bytes(data.total_received()),
-                format_bytes(data.total_transmitted())
+                format_bytes_with(data.total_received(), app.byte_unit),
+                format_bytes_with(data.total_transmitted(), app.byte_unit)
             )
         };
         push_line(
@@ -444,12 +593,11 @@ pub(super) fn push_network(
 }
 
 pub(super) fn push_temps(lines: &mut Vec<Line<'static>>, app: &App, layout: TabLayout, na: &str) {
-    push_header(
-        lines,
-        tr(app.language, "Temps", "Темп."),
-        layout.width,
-        layout.section_style,
-    );
+    let header = match app.temps_sort {
+        TempsSort::Temperature => tr(app.language, "Temps", "Темп."),
+        TempsSort::Label => tr(app.language, "Temps (A-Z)", "Темп. (А-Я)"),
+    };
+    push_header(lines, header, layout.width, layout.section_style);
     if app.components.is_empty() {
         push_line(
             lines,
@@ -467,18 +615,31 @@ pub(super) fn push_temps(lines: &mut Vec<Line<'static>>, app: &App, layout: TabL
         .iter()
         .map(|component| (component.label().to_string(), component.temperature()))
         .collect::<Vec<_>>();
-    temps.sort_by(
-        |(a_label, a_temp), (b_label, b_temp)| match (a_temp, b_temp) {
-            (Some(a_temp), Some(b_temp)) => b_temp.partial_cmp(a_temp).unwrap_or(Ordering::Equal),
-            (Some(_), None) => Ordering::Less,
-            (None, Some(_)) => Ordering::Greater,
-            (None, None) => a_label.cmp(b_label),
-        },
-    );
+    match app.temps_sort {
+        TempsSort::Temperature => {
+            temps.sort_by(
+                |(a_label, a_temp), (b_label, b_temp)| match (a_temp, b_temp) {
+                    (Some(a_temp), Some(b_temp)) => {
+                        b_temp.partial_cmp(a_temp).unwrap_or(Ordering::Equal)
+                    }
+                    (Some(_), None) => Ordering::Less,
+                    (None, Some(_)) => Ordering::Greater,
+                    (None, None) => a_label.cmp(b_label),
+                },
+            )
+        }
+        TempsSort::Label => temps.sort_by(|(a_label, _), (b_label, _)| a_label.cmp(b_label)),
+    }
+    let now = Instant::now();
     for (label, temp) in temps {
         let value = temp
-            .map(|value| format!("{value:.1}°C"))
+            .map(|value| format_temp(value, app.temp_unit))
             .unwrap_or_else(|| na.to_string());
+        let value_style = if app.cpu_temp_flashing(&label, temp, now) {
+            alert_flash_style()
+        } else {
+            layout.value_style
+        };
         push_line(
             lines,
             &label,
@@ -486,7 +647,77 @@ pub(super) fn push_temps(lines: &mut Vec<Line<'static>>, app: &App, layout: TabL
             layout.width,
             layout.label_width,
             layout.label_style,
+            value_style,
+        );
+    }
+}
+
+/// Temperatures, fans and power draw grouped by source chip.
+pub(super) fn push_sensors(lines: &mut Vec<Line<'static>>, app: &App, layout: TabLayout, na: &str) {
+    let mut chips: Vec<(String, Vec<(String, String)>)> = Vec::new();
+    for component in app.components.iter() {
+        let Some(temp) = component.temperature() else {
+            continue;
+        };
+        let (chip, sensor) = split_component_label(component.label());
+        let sensor = if sensor.is_empty() { "temp" } else { sensor };
+        let idx = sensor_chip_index(&mut chips, chip);
+        chips[idx]
+            .1
+            .push((sensor.to_string(), format_temp(temp, app.temp_unit)));
+    }
+    for hwmon in &app.hwmon_chips {
+        let idx = sensor_chip_index(&mut chips, &hwmon.name);
+        for (label, rpm) in &hwmon.fans {
+            chips[idx].1.push((label.clone(), format!("{rpm} RPM")));
+        }
+        for (label, watts) in &hwmon.power {
+            chips[idx].1.push((label.clone(), format!("{watts:.1} W")));
+        }
+    }
+
+    if chips.is_empty() {
+        push_header(
+            lines,
+            tr(app.language, "Sensors", "Датчики"),
+            layout.width,
+            layout.section_style,
+        );
+        push_line(
+            lines,
+            tr(app.language, "Sensors", "Датчики"),
+            na.to_string(),
+            layout.width,
+            layout.label_width,
+            layout.label_style,
             layout.value_style,
         );
+        return;
+    }
+
+    chips.sort_by(|a, b| a.0.cmp(&b.0));
+    for (chip, readings) in chips {
+        push_header(lines, &chip, layout.width, layout.section_style);
+        for (label, value) in readings {
+            push_line(
+                lines,
+                &label,
+                value,
+                layout.width,
+                layout.label_width,
+                layout.label_style,
+                layout.value_style,
+            );
+        }
+    }
+}
+
+fn sensor_chip_index(chips: &mut Vec<(String, Vec<(String, String)>)>, chip: &str) -> usize {
+    match chips.iter().position(|(name, _)| name == chip) {
+        Some(idx) => idx,
+        None => {
+            chips.push((chip.to_string(), Vec::new()));
+            chips.len() - 1
+        }
     }
 }
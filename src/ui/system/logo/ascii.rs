@@ -2,7 +2,7 @@ use std::fs;
 use std::path::PathBuf;
 
 use crate::app::{
-    AsciiCell, AsciiLogo, LogoCell, LogoMode, LogoPalette, LogoQuality, RenderedLogo,
+    AsciiCell, AsciiLogo, LogoCell, LogoMode, LogoPalette, LogoQuality, RenderedLogo, RgbColor,
 };
 
 pub(super) fn load_ascii_logo(path: PathBuf) -> Option<AsciiLogo> {
@@ -100,6 +100,7 @@ pub(super) fn render_ascii_logo(
     quality: LogoQuality,
     width: u16,
     height: u16,
+    accent: RgbColor,
 ) -> RenderedLogo {
     let target_w = width as usize;
     let target_h = height as usize;
@@ -107,12 +108,13 @@ pub(super) fn render_ascii_logo(
     let mut cells = Vec::with_capacity(target_w * target_h);
     for row in scaled {
         for cell in row {
-            let fg = cell
-                .color_index
-                .and_then(|idx| palette.color_for_index(idx));
+            let fg = match cell.color_index {
+                Some(idx) => palette.color_for_index(idx, accent),
+                None => palette.default_color(accent),
+            };
             cells.push(LogoCell {
                 ch: cell.ch,
-                fg,
+                fg: Some(fg),
                 bg: None,
             });
         }
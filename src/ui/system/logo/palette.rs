@@ -1,5 +1,5 @@
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use serde::Deserialize;
 
@@ -15,6 +15,15 @@ struct PaletteFile {
     colors: Option<Vec<[u8; 3]>>,
 }
 
+/// Palette files in lookup order, watched for hot-reload.
+pub(super) fn palette_paths(root: &Path) -> [PathBuf; 3] {
+    [
+        root.join(PALETTE_JSON),
+        root.join(PALETTE_YAML),
+        root.join(PALETTE_YML),
+    ]
+}
+
 pub(super) fn load_palette(root: &Path) -> LogoPalette {
     let json_path = root.join(PALETTE_JSON);
     if let Some(palette) = load_palette_json(&json_path) {
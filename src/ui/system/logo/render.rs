@@ -1,28 +1,36 @@
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime};
 
 use ratatui::prelude::*;
 use ratatui::style::{Color, Style};
 
-use crate::app::{App, LogoCache, LogoCell, LogoMode, LogoQuality, RenderedLogo};
+use crate::app::RgbColor;
+use crate::app::{App, LogoCache, LogoCell, LogoMode, LogoQuality, RenderedLogo, StatusLevel};
+use crate::ui::theme::{COLOR_ACCENT, resolve_color};
 
 use super::{ascii, palette, svg};
 
 const LOGO_DIR: &str = "logo";
 const ASCII_DIR: &str = "ascii";
 const SVG_DIR: &str = "svg";
+/// How often the logo files are checked for changes
+const RELOAD_CHECK_INTERVAL: Duration = Duration::from_secs(1);
 
 pub fn render_logo(frame: &mut Frame, area: Rect, app: &mut App) {
-    if area.width == 0 || area.height == 0 {
+    if area.width == 0 || area.height == 0 || app.logo_mode == LogoMode::None {
         return;
     }
 
     let preferred = app.logo_mode;
     let quality = app.logo_quality;
+    let truecolor = app.truecolor;
+    let monochrome = app.monochrome;
+    let resize_settled = app.resize_settled();
     let cache = ensure_logo_cache(app);
-    let Some(mode) = select_logo_mode(cache, preferred) else {
+    let Some(mode) = select_logo_mode(cache, preferred, monochrome) else {
         let blank = RenderedLogo::blank(preferred, quality, area.width, area.height);
-        draw_rendered_logo(frame, area, &blank);
+        draw_rendered_logo(frame, area, &blank, truecolor, monochrome);
         return;
     };
 
@@ -32,7 +40,13 @@ pub fn render_logo(frame: &mut Frame, area: Rect, app: &mut App) {
             || rendered.width != area.width
             || rendered.height != area.height
     });
-    if needs_render {
+    // Mid drag-resize keep drawing the previous raster; rebuild once settled
+    let resizing = !resize_settled
+        && cache
+            .rendered
+            .as_ref()
+            .is_some_and(|rendered| rendered.mode == mode && rendered.quality == quality);
+    if needs_render && !resizing {
         cache.rendered = Some(build_rendered_logo(
             cache,
             mode,
@@ -43,20 +57,48 @@ pub fn render_logo(frame: &mut Frame, area: Rect, app: &mut App) {
     }
 
     if let Some(rendered) = cache.rendered.as_ref() {
-        draw_rendered_logo(frame, area, rendered);
+        draw_rendered_logo(frame, area, rendered, truecolor, monochrome);
     }
 }
 
 fn ensure_logo_cache(app: &mut App) -> &mut LogoCache {
-    if app.logo_cache.is_none() {
-        app.logo_cache = Some(load_logo_cache());
+    let reload = match app.logo_cache.as_mut() {
+        None => true,
+        Some(cache) => {
+            let due = cache
+                .checked_at
+                .is_none_or(|checked| checked.elapsed() >= RELOAD_CHECK_INTERVAL);
+            if due {
+                cache.checked_at = Some(Instant::now());
+            }
+            due && sources_changed(&cache.sources)
+        }
+    };
+    if reload {
+        let cache = load_logo_cache();
+        // Report a broken SVG once per load instead of silently drawing nothing
+        if let Some(err) = cache.svg_error.as_ref()
+            && (app.logo_mode == LogoMode::Svg || cache.ascii.is_none())
+        {
+            let fallback = if cache.ascii.is_some() {
+                "using the ASCII logo"
+            } else {
+                "no logo shown"
+            };
+            app.set_status(
+                StatusLevel::Warn,
+                format!("SVG logo failed to load ({err}); {fallback}"),
+            );
+        }
+        app.logo_cache = Some(cache);
     }
     app.logo_cache.as_mut().expect("logo cache initialized")
 }
 
-fn select_logo_mode(cache: &LogoCache, preferred: LogoMode) -> Option<LogoMode> {
+fn select_logo_mode(cache: &LogoCache, preferred: LogoMode, monochrome: bool) -> Option<LogoMode> {
     let has_ascii = cache.ascii.is_some();
-    let has_svg = cache.svg.is_some();
+    // An SVG raster is nothing but colored blocks, so monochrome keeps to ASCII art
+    let has_svg = cache.svg.is_some() && !monochrome;
     match preferred {
         LogoMode::Ascii if has_ascii => Some(LogoMode::Ascii),
         LogoMode::Svg if has_svg => Some(LogoMode::Svg),
@@ -72,12 +114,43 @@ fn load_logo_cache() -> LogoCache {
         return cache;
     };
 
+    let ascii_dir = root.join(ASCII_DIR);
+    let svg_dir = root.join(SVG_DIR);
+    let ascii_file = first_file(&ascii_dir, None);
+    let svg_file = first_file(&svg_dir, Some("svg"));
+
+    // Directory mtimes catch logos being added or removed
+    let mut watched = vec![root.clone(), ascii_dir, svg_dir];
+    watched.extend(palette::palette_paths(&root));
+    watched.extend(ascii_file.clone());
+    watched.extend(svg_file.clone());
+    cache.sources = watched
+        .into_iter()
+        .map(|path| {
+            let mtime = modified(&path);
+            (path, mtime)
+        })
+        .collect();
+    cache.checked_at = Some(Instant::now());
+
     cache.palette = palette::load_palette(&root);
-    cache.ascii = first_file(&root.join(ASCII_DIR), None).and_then(ascii::load_ascii_logo);
-    cache.svg = first_file(&root.join(SVG_DIR), Some("svg")).and_then(svg::load_svg_logo);
+    cache.ascii = ascii_file.and_then(ascii::load_ascii_logo);
+    match svg_file.map(svg::load_svg_logo) {
+        Some(Ok(logo)) => cache.svg = Some(logo),
+        Some(Err(err)) => cache.svg_error = Some(err),
+        None => {}
+    }
     cache
 }
 
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|meta| meta.modified()).ok()
+}
+
+fn sources_changed(sources: &[(PathBuf, Option<SystemTime>)]) -> bool {
+    sources.iter().any(|(path, mtime)| modified(path) != *mtime)
+}
+
 fn logo_root() -> Option<PathBuf> {
     dirs::config_dir().map(|base| base.join("rtop").join(LOGO_DIR))
 }
@@ -123,32 +196,45 @@ fn build_rendered_logo(
         LogoMode::Ascii => cache
             .ascii
             .as_ref()
-            .map(|logo| ascii::render_ascii_logo(logo, &cache.palette, quality, width, height))
+            .map(|logo| {
+                ascii::render_ascii_logo(logo, &cache.palette, quality, width, height, accent_rgb())
+            })
             .unwrap_or_else(|| RenderedLogo::blank(mode, quality, width, height)),
         LogoMode::Svg => cache
             .svg
             .as_ref()
             .map(|logo| svg::render_svg_logo(logo, quality, width, height))
             .unwrap_or_else(|| RenderedLogo::blank(mode, quality, width, height)),
+        LogoMode::None => RenderedLogo::blank(mode, quality, width, height),
     }
 }
 
-fn draw_rendered_logo(frame: &mut Frame, area: Rect, rendered: &RenderedLogo) {
+fn draw_rendered_logo(
+    frame: &mut Frame,
+    area: Rect,
+    rendered: &RenderedLogo,
+    truecolor: bool,
+    monochrome: bool,
+) {
     let buffer = frame.buffer_mut();
     for y in 0..area.height {
         for x in 0..area.width {
-            let idx = (y as usize) * rendered.width as usize + x as usize;
-            let cell = rendered
-                .cells
-                .get(idx)
-                .copied()
-                .unwrap_or_else(LogoCell::blank);
+            // A stale raster from before a resize may not match the area
+            let cell = if x < rendered.width && y < rendered.height {
+                let idx = (y as usize) * rendered.width as usize + x as usize;
+                rendered.cells.get(idx).copied()
+            } else {
+                None
+            }
+            .unwrap_or_else(LogoCell::blank);
             let mut style = Style::default();
-            if let Some(fg) = cell.fg {
-                style = style.fg(Color::Rgb(fg.r, fg.g, fg.b));
+            // Monochrome draws plain characters; a colored background would
+            // otherwise come out as reverse video
+            if let Some(fg) = cell.fg.filter(|_| !monochrome) {
+                style = style.fg(resolve_color(Color::Rgb(fg.r, fg.g, fg.b), truecolor));
             }
-            if let Some(bg) = cell.bg {
-                style = style.bg(Color::Rgb(bg.r, bg.g, bg.b));
+            if let Some(bg) = cell.bg.filter(|_| !monochrome) {
+                style = style.bg(resolve_color(Color::Rgb(bg.r, bg.g, bg.b), truecolor));
             }
             if let Some(buf_cell) = buffer.cell_mut((area.x + x, area.y + y)) {
                 let mut symbol_buf = [0u8; 4];
@@ -159,3 +245,14 @@ fn draw_rendered_logo(frame: &mut Frame, area: Rect, rendered: &RenderedLogo) {
         }
     }
 }
+
+fn accent_rgb() -> RgbColor {
+    match COLOR_ACCENT {
+        Color::Rgb(r, g, b) => RgbColor { r, g, b },
+        _ => RgbColor {
+            r: 255,
+            g: 255,
+            b: 255,
+        },
+    }
+}
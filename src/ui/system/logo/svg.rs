@@ -9,14 +9,24 @@ use crate::app::{LogoCell, LogoMode, LogoQuality, RenderedLogo, RgbColor, RgbaCo
 const MAX_SVG_DIM: u32 = 2048;
 const ALPHA_THRESHOLD: u8 = 10;
 
-pub(super) fn load_svg_logo(path: PathBuf) -> Option<SvgLogo> {
-    let data = fs::read(&path).ok()?;
+/// Load and parse the SVG logo; the error names the file and what went wrong.
+pub(super) fn load_svg_logo(path: PathBuf) -> Result<SvgLogo, String> {
+    let name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let data = fs::read(&path).map_err(|err| format!("{name}: {err}"))?;
+    parse_svg(&data, path.parent().map(|dir| dir.to_path_buf()))
+        .map_err(|err| format!("{name}: {err}"))
+}
+
+fn parse_svg(data: &[u8], resources_dir: Option<PathBuf>) -> Result<SvgLogo, usvg::Error> {
     let options = usvg::Options {
-        resources_dir: path.parent().map(|dir| dir.to_path_buf()),
+        resources_dir,
         ..Default::default()
     };
-    let tree = usvg::Tree::from_data(&data, &options).ok()?;
-    Some(SvgLogo { tree })
+    let tree = usvg::Tree::from_data(data, &options)?;
+    Ok(SvgLogo { tree })
 }
 
 pub(super) fn render_svg_logo(
@@ -289,3 +299,16 @@ fn fit_dimensions_u32(src_w: u32, src_h: u32, max_w: u32, max_h: u32) -> (u32, u
     let new_h = (src_h as f32 * scale).round().max(1.0) as u32;
     (new_w.min(max_w), new_h.min(max_h))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn malformed_svg_reports_error() {
+        let err = parse_svg(b"<svg", None).unwrap_err();
+        assert!(!err.to_string().is_empty());
+        let ok = br#"<svg xmlns="http://www.w3.org/2000/svg" width="4" height="4"/>"#;
+        assert!(parse_svg(ok, None).is_ok());
+    }
+}
@@ -1,11 +1,13 @@
 mod info;
+
+pub use info::system_overview_text;
 mod logo;
 use ratatui::prelude::*;
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, BorderType, Borders};
 
 use super::text::tr;
-use crate::app::{App, SystemTab, SystemTabRegion};
+use crate::app::{App, LogoMode, SystemTab, SystemTabRegion};
 use crate::ui::theme::{COLOR_ACCENT, COLOR_BORDER, COLOR_MUTED};
 use crate::utils::{fit_text, text_width};
 
@@ -28,7 +30,15 @@ pub fn render(frame: &mut Frame, area: Rect, app: &mut App) {
         width: inner.width,
         height: inner.height.saturating_sub(1),
     };
-    if app.system_tab == SystemTab::Overview {
+    if app.system_tab == SystemTab::Overview && app.logo_mode == LogoMode::None {
+        let info_area = Rect {
+            x: content_area.x.saturating_add(1),
+            y: content_area.y.saturating_add(1),
+            width: content_area.width.saturating_sub(1),
+            height: content_area.height.saturating_sub(1),
+        };
+        info::render_info(frame, info_area, app);
+    } else if app.system_tab == SystemTab::Overview {
         let chunks = Layout::default()
             .direction(Direction::Horizontal)
             .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
@@ -92,6 +102,7 @@ fn system_title_line(app: &mut App, area: Rect) -> Line<'static> {
         SystemTab::Disks,
         SystemTab::Network,
         SystemTab::Temps,
+        SystemTab::Sensors,
     ];
     let mut x = area.x.saturating_add(1).saturating_add(used as u16);
     for tab in tabs {
@@ -152,5 +163,6 @@ fn tab_label(tab: SystemTab, language: crate::app::Language) -> &'static str {
         SystemTab::Disks => tr(language, "Disk", "Диск"),
         SystemTab::Network => tr(language, "Net", "Сеть"),
         SystemTab::Temps => tr(language, "Temp", "Темп"),
+        SystemTab::Sensors => tr(language, "Sensors", "Датчики"),
     }
 }
@@ -1,4 +1,5 @@
-use ratatui::style::Color;
+use ratatui::buffer::Buffer;
+use ratatui::style::{Color, Modifier, Style};
 
 pub const COLOR_ACCENT: Color = Color::Rgb(78, 190, 210);
 pub const COLOR_MUTED: Color = Color::Rgb(138, 148, 158);
@@ -6,6 +7,8 @@ pub const COLOR_BORDER: Color = Color::Rgb(78, 86, 96);
 pub const COLOR_GOOD: Color = Color::Rgb(95, 200, 120);
 pub const COLOR_WARN: Color = Color::Rgb(230, 180, 70);
 pub const COLOR_HOT: Color = Color::Rgb(230, 90, 70);
+pub const COLOR_NEW_BG: Color = Color::Rgb(60, 70, 30);
+pub const COLOR_MATCH_BG: Color = Color::Rgb(110, 85, 20);
 
 pub fn color_for_percent(pct: f32) -> Color {
     if pct < 50.0 {
@@ -16,3 +19,146 @@ pub fn color_for_percent(pct: f32) -> Color {
         COLOR_HOT
     }
 }
+
+/// Style for a value that just crossed an `[alerts]` threshold.
+pub fn alert_flash_style() -> Style {
+    Style::default()
+        .fg(Color::White)
+        .bg(COLOR_HOT)
+        .add_modifier(Modifier::BOLD | Modifier::SLOW_BLINK)
+}
+
+/// Whether the terminal advertises 24-bit color via `COLORTERM`.
+pub fn detect_truecolor() -> bool {
+    std::env::var("COLORTERM")
+        .map(|value| matches!(value.to_ascii_lowercase().as_str(), "truecolor" | "24bit"))
+        .unwrap_or(false)
+}
+
+/// Quantize RGB colors to the 256-color palette when truecolor is unavailable.
+pub fn resolve_color(color: Color, truecolor: bool) -> Color {
+    match color {
+        Color::Rgb(r, g, b) if !truecolor => Color::Indexed(rgb_to_ansi256(r, g, b)),
+        other => other,
+    }
+}
+
+/// Downgrade every RGB cell of a rendered frame (theme constants are truecolor).
+pub fn downgrade_buffer(buffer: &mut Buffer) {
+    for cell in buffer.content.iter_mut() {
+        cell.fg = resolve_color(cell.fg, false);
+        cell.bg = resolve_color(cell.bg, false);
+    }
+}
+
+/// Strip every color from a rendered frame for `[display] monochrome`,
+/// keeping the meaning of the theme colors as text attributes.
+pub fn monochrome_buffer(buffer: &mut Buffer) {
+    for cell in buffer.content.iter_mut() {
+        cell.modifier |= monochrome_modifier(cell.fg, cell.bg);
+        cell.fg = Color::Reset;
+        cell.bg = Color::Reset;
+    }
+}
+
+fn monochrome_modifier(fg: Color, bg: Color) -> Modifier {
+    let from_bg = match bg {
+        Color::Reset => Modifier::empty(),
+        COLOR_MATCH_BG => Modifier::UNDERLINED,
+        COLOR_NEW_BG => Modifier::ITALIC,
+        COLOR_HOT => Modifier::REVERSED | Modifier::BOLD,
+        // Selection and any other highlight
+        _ => Modifier::REVERSED,
+    };
+    let from_fg = match fg {
+        COLOR_HOT | Color::Red | Color::LightRed => Modifier::BOLD | Modifier::UNDERLINED,
+        COLOR_WARN | Color::Yellow | COLOR_ACCENT => Modifier::BOLD,
+        _ => Modifier::empty(),
+    };
+    from_bg | from_fg
+}
+
+/// Nearest xterm-256 index: either the 6x6x6 color cube or the grayscale ramp.
+fn rgb_to_ansi256(r: u8, g: u8, b: u8) -> u8 {
+    const LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+    let cube_index = |value: u8| -> u8 {
+        match value {
+            0..48 => 0,
+            48..115 => 1,
+            _ => (value - 35) / 40,
+        }
+    };
+    let distance = |(r2, g2, b2): (u8, u8, u8)| -> u32 {
+        let dr = i32::from(r) - i32::from(r2);
+        let dg = i32::from(g) - i32::from(g2);
+        let db = i32::from(b) - i32::from(b2);
+        (dr * dr + dg * dg + db * db) as u32
+    };
+
+    let (ri, gi, bi) = (cube_index(r), cube_index(g), cube_index(b));
+    let cube = (
+        LEVELS[ri as usize],
+        LEVELS[gi as usize],
+        LEVELS[bi as usize],
+    );
+    let cube_code = 16 + 36 * ri + 6 * gi + bi;
+
+    let avg = ((u16::from(r) + u16::from(g) + u16::from(b)) / 3) as u8;
+    let gray_step = if avg > 238 {
+        23
+    } else {
+        avg.saturating_sub(3) / 10
+    };
+    let gray_value = 8 + 10 * gray_step;
+    let gray_code = 232 + gray_step;
+
+    if distance((gray_value, gray_value, gray_value)) < distance(cube) {
+        gray_code
+    } else {
+        cube_code
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rgb_maps_to_cube_and_grayscale() {
+        assert_eq!(rgb_to_ansi256(255, 0, 0), 196);
+        assert_eq!(rgb_to_ansi256(0, 0, 0), 16);
+        assert_eq!(rgb_to_ansi256(128, 128, 128), 244);
+    }
+
+    #[test]
+    fn monochrome_maps_colors_to_attributes() {
+        assert_eq!(
+            monochrome_modifier(Color::Reset, Color::Rgb(40, 48, 58)),
+            Modifier::REVERSED
+        );
+        assert_eq!(
+            monochrome_modifier(COLOR_HOT, Color::Reset),
+            Modifier::BOLD | Modifier::UNDERLINED
+        );
+        assert_eq!(
+            monochrome_modifier(COLOR_MUTED, Color::Reset),
+            Modifier::empty()
+        );
+
+        let mut buffer = Buffer::empty(ratatui::layout::Rect::new(0, 0, 1, 1));
+        buffer.content[0].set_style(Style::default().fg(COLOR_WARN).bg(COLOR_MATCH_BG));
+        monochrome_buffer(&mut buffer);
+        let cell = &buffer.content[0];
+        assert_eq!((cell.fg, cell.bg), (Color::Reset, Color::Reset));
+        assert_eq!(cell.modifier, Modifier::BOLD | Modifier::UNDERLINED);
+    }
+
+    #[test]
+    fn resolve_keeps_rgb_on_truecolor() {
+        assert_eq!(
+            resolve_color(Color::Rgb(1, 2, 3), true),
+            Color::Rgb(1, 2, 3)
+        );
+        assert_eq!(resolve_color(Color::White, false), Color::White);
+    }
+}
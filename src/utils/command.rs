@@ -1,10 +1,59 @@
+use std::ffi::OsStr;
 use std::io::{self, Read};
 use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::mpsc;
 use std::thread;
 use std::time::{Duration, Instant};
 
+static EXEC_DISABLED: AtomicBool = AtomicBool::new(false);
+
+/// Disables (or re-enables) spawning of external commands process-wide.
+pub fn set_exec_disabled(disabled: bool) {
+    EXEC_DISABLED.store(disabled, Ordering::Relaxed);
+}
+
+pub fn exec_disabled() -> bool {
+    EXEC_DISABLED.load(Ordering::Relaxed)
+}
+
 pub fn run_command_with_timeout(command: &str, args: &[&str], timeout: Duration) -> Option<String> {
+    run_command_capture(command, args, timeout).map(|(output, _)| output)
+}
+
+/// Start `command` without waiting for it; the child is reaped on a
+/// background thread so it never lingers as a zombie.
+pub fn spawn_detached(command: &str, args: &[&OsStr]) -> io::Result<()> {
+    if exec_disabled() {
+        return Err(io::Error::other("external commands are disabled"));
+    }
+    let mut child = Command::new(command)
+        .args(args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+    thread::spawn(move || {
+        let _ = child.wait();
+    });
+    Ok(())
+}
+
+/// Like [`run_command_with_timeout`], but appends stderr to the output for
+/// tools that print their version there (e.g. `Xorg -version`).
+pub fn run_command_with_stderr(command: &str, args: &[&str], timeout: Duration) -> Option<String> {
+    run_command_capture(command, args, timeout).map(|(output, error_output)| output + &error_output)
+}
+
+/// Run `command`, returning (stdout, stderr) on success
+fn run_command_capture(
+    command: &str,
+    args: &[&str],
+    timeout: Duration,
+) -> Option<(String, String)> {
+    if exec_disabled() {
+        return None;
+    }
     let mut child = Command::new(command)
         .args(args)
         .stdout(Stdio::piped())
@@ -53,7 +102,7 @@ pub fn run_command_with_timeout(command: &str, args: &[&str], timeout: Duration)
     let output = out_rx.recv().ok()?;
     let error_output = err_rx.recv().unwrap_or_default();
     if success {
-        Some(output)
+        Some((output, error_output))
     } else {
         let command_display = if args.is_empty() {
             command.to_string()
@@ -1,34 +1,159 @@
 use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
+use super::civil::civil_from_days;
+use super::timezone::local_offset_secs;
+
 pub fn text_width(value: &str) -> usize {
     UnicodeWidthStr::width(value)
 }
 
-pub fn format_bytes(bytes: u64) -> String {
-    const UNIT: f64 = 1024.0;
-    let bytes = bytes as f64;
+/// Byte size convention: IEC powers of 1024 (KiB) or SI powers of 1000 (kB)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ByteUnit {
+    #[default]
+    Iec,
+    Si,
+}
+
+impl ByteUnit {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "iec" | "binary" => Some(ByteUnit::Iec),
+            "si" | "decimal" => Some(ByteUnit::Si),
+            _ => None,
+        }
+    }
 
-    if bytes < UNIT {
-        return format!("{bytes:.0} B");
+    pub fn code(self) -> &'static str {
+        match self {
+            ByteUnit::Iec => "iec",
+            ByteUnit::Si => "si",
+        }
     }
 
-    let kb = bytes / UNIT;
-    if kb < UNIT {
-        return format!("{kb:.1} KiB");
+    pub fn toggle(self) -> Self {
+        match self {
+            ByteUnit::Iec => ByteUnit::Si,
+            ByteUnit::Si => ByteUnit::Iec,
+        }
     }
 
-    let mb = kb / UNIT;
-    if mb < UNIT {
-        return format!("{mb:.1} MiB");
+    fn base_and_suffixes(self) -> (f64, [&'static str; 4]) {
+        match self {
+            ByteUnit::Iec => (1024.0, ["KiB", "MiB", "GiB", "TiB"]),
+            ByteUnit::Si => (1000.0, ["kB", "MB", "GB", "TB"]),
+        }
     }
 
-    let gb = mb / UNIT;
-    if gb < UNIT {
-        return format!("{gb:.1} GiB");
+    fn compact_suffixes(self) -> [&'static str; 4] {
+        match self {
+            ByteUnit::Iec => ["K", "M", "G", "T"],
+            ByteUnit::Si => ["k", "M", "G", "T"],
+        }
     }
+}
 
-    let tb = gb / UNIT;
-    format!("{tb:.1} TiB")
+/// Temperature scale for display; sensors always report Celsius
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TempUnit {
+    #[default]
+    Celsius,
+    Fahrenheit,
+}
+
+impl TempUnit {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "c" | "celsius" => Some(TempUnit::Celsius),
+            "f" | "fahrenheit" => Some(TempUnit::Fahrenheit),
+            _ => None,
+        }
+    }
+
+    pub fn code(self) -> &'static str {
+        match self {
+            TempUnit::Celsius => "c",
+            TempUnit::Fahrenheit => "f",
+        }
+    }
+
+    pub fn toggle(self) -> Self {
+        match self {
+            TempUnit::Celsius => TempUnit::Fahrenheit,
+            TempUnit::Fahrenheit => TempUnit::Celsius,
+        }
+    }
+
+    pub fn suffix(self) -> &'static str {
+        match self {
+            TempUnit::Celsius => "°C",
+            TempUnit::Fahrenheit => "°F",
+        }
+    }
+
+    fn convert(self, celsius: f32) -> f32 {
+        match self {
+            TempUnit::Celsius => celsius,
+            TempUnit::Fahrenheit => celsius * 9.0 / 5.0 + 32.0,
+        }
+    }
+}
+
+/// "45.5°C" / "113.9°F"
+pub fn format_temp(celsius: f32, unit: TempUnit) -> String {
+    format!("{:.1}{}", unit.convert(celsius), unit.suffix())
+}
+
+/// Whole degrees for tight spots: "46°C" / "114°F"
+pub fn format_temp_compact(celsius: f32, unit: TempUnit) -> String {
+    format!("{:.0}{}", unit.convert(celsius), unit.suffix())
+}
+
+pub fn format_bytes(bytes: u64) -> String {
+    format_bytes_with(bytes, ByteUnit::Iec)
+}
+
+pub fn format_bytes_with(bytes: u64, unit: ByteUnit) -> String {
+    let (base, suffixes) = unit.base_and_suffixes();
+    let mut value = bytes as f64;
+
+    if value < base {
+        return format!("{value:.0} B");
+    }
+
+    for (idx, suffix) in suffixes.iter().enumerate() {
+        value /= base;
+        if value < base || idx == suffixes.len() - 1 {
+            return format!("{value:.1} {suffix}");
+        }
+    }
+    unreachable!("the last suffix always returns")
+}
+
+/// Short form for tight rate columns: "512B", "1.2G", "37M".
+///
+/// One decimal below 10, whole numbers above, and never four digits when the
+/// next unit up fits.
+pub fn format_bytes_compact(bytes: u64, unit: ByteUnit) -> String {
+    let (base, _) = unit.base_and_suffixes();
+    let suffixes = unit.compact_suffixes();
+    let mut value = bytes as f64;
+
+    if value < base {
+        return format!("{bytes}B");
+    }
+
+    for (idx, suffix) in suffixes.iter().enumerate() {
+        value /= base;
+        let last = idx == suffixes.len() - 1;
+        if value < 9.95 {
+            return format!("{value:.1}{suffix}");
+        }
+        if value.round() < base || last {
+            return format!("{value:.0}{suffix}");
+        }
+    }
+    unreachable!("the last suffix always returns")
 }
 
 pub fn mib_to_bytes(mib: u64) -> u64 {
@@ -100,6 +225,73 @@ pub fn format_duration_short(secs: u64) -> String {
     }
 }
 
+/// Format CPU time like top's TIME+ column: "MM:SS.cc" (minutes are not wrapped)
+pub fn format_cpu_time(ms: u64) -> String {
+    let centis = (ms / 10) % 100;
+    let secs = (ms / 1000) % 60;
+    let minutes = ms / 60_000;
+    format!("{minutes}:{secs:02}.{centis:02}")
+}
+
+/// Clock style for absolute timestamps, from `[display] time_format`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TimeFormat {
+    #[default]
+    TwentyFourHour,
+    TwelveHour,
+}
+
+impl TimeFormat {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "24h" | "24" => Some(TimeFormat::TwentyFourHour),
+            "12h" | "12" => Some(TimeFormat::TwelveHour),
+            _ => None,
+        }
+    }
+
+    /// Width of a `format_start_time` result
+    pub fn timestamp_width(self) -> usize {
+        match self {
+            TimeFormat::TwentyFourHour => 16,
+            TimeFormat::TwelveHour => 19,
+        }
+    }
+}
+
+/// Format an epoch timestamp as local "YYYY-MM-DD HH:MM" (system timezone)
+pub fn format_start_time(epoch: u64, time_format: TimeFormat) -> String {
+    let epoch = epoch as i64;
+    format_timestamp(epoch, local_offset_secs(epoch), time_format)
+}
+
+fn format_timestamp(epoch: i64, offset_secs: i64, time_format: TimeFormat) -> String {
+    let local = epoch + offset_secs;
+    let (year, month, day) = civil_from_days(local.div_euclid(86_400));
+    let secs_of_day = local.rem_euclid(86_400);
+    format!(
+        "{year:04}-{month:02}-{day:02} {}",
+        format_time_of_day(secs_of_day as u64, time_format)
+    )
+}
+
+/// "14:03" or "02:03 PM" for seconds since local midnight
+pub fn format_time_of_day(secs_of_day: u64, time_format: TimeFormat) -> String {
+    let hours = secs_of_day / 3_600 % 24;
+    let minutes = secs_of_day % 3_600 / 60;
+    match time_format {
+        TimeFormat::TwentyFourHour => format!("{hours:02}:{minutes:02}"),
+        TimeFormat::TwelveHour => {
+            let suffix = if hours < 12 { "AM" } else { "PM" };
+            let hours = match hours % 12 {
+                0 => 12,
+                hours => hours,
+            };
+            format!("{hours:02}:{minutes:02} {suffix}")
+        }
+    }
+}
+
 pub fn percent(used: u64, total: u64) -> f32 {
     if total == 0 {
         0.0
@@ -125,6 +317,35 @@ pub fn render_bar(pct: f32, width: usize) -> String {
 mod tests {
     use super::*;
 
+    #[test]
+    fn format_timestamp_applies_offset() {
+        let format = TimeFormat::TwentyFourHour;
+        assert_eq!(
+            format_timestamp(1_717_250_580, 0, format),
+            "2024-06-01 14:03"
+        );
+        assert_eq!(
+            format_timestamp(1_717_250_580, 3 * 3_600, format),
+            "2024-06-01 17:03"
+        );
+        assert_eq!(format_timestamp(0, -3_600, format), "1969-12-31 23:00");
+    }
+
+    #[test]
+    fn format_timestamp_twelve_hour() {
+        let format = TimeFormat::TwelveHour;
+        assert_eq!(
+            format_timestamp(1_717_250_580, 0, format),
+            "2024-06-01 02:03 PM"
+        );
+        assert_eq!(format_time_of_day(0, format), "12:00 AM");
+        assert_eq!(format_time_of_day(12 * 3_600 + 59, format), "12:00 PM");
+        assert_eq!(
+            format_timestamp(1_717_250_580, 0, format).len(),
+            format.timestamp_width()
+        );
+    }
+
     #[test]
     fn format_duration_minutes_seconds() {
         assert_eq!(format_duration(75), "1m 15s");
@@ -162,6 +383,13 @@ mod tests {
         assert_eq!(format_duration_short(90061), "1d 01h");
     }
 
+    #[test]
+    fn format_cpu_time_minutes_seconds_centis() {
+        assert_eq!(format_cpu_time(0), "0:00.00");
+        assert_eq!(format_cpu_time(61_234), "1:01.23");
+        assert_eq!(format_cpu_time(6_000_000), "100:00.00");
+    }
+
     #[test]
     fn format_bytes_units() {
         assert_eq!(format_bytes(0), "0 B");
@@ -183,6 +411,51 @@ mod tests {
         assert_eq!(format_bytes(2 * 1024 * 1024 * 1024 * 1024), "2.0 TiB");
     }
 
+    #[test]
+    fn format_bytes_compact_boundaries() {
+        let iec = ByteUnit::Iec;
+        assert_eq!(format_bytes_compact(0, iec), "0B");
+        assert_eq!(format_bytes_compact(1023, iec), "1023B");
+        assert_eq!(format_bytes_compact(1024, iec), "1.0K");
+        assert_eq!(format_bytes_compact(10 * 1024 - 1, iec), "10K");
+        assert_eq!(format_bytes_compact(1_288_490_189, iec), "1.2G");
+        // Rounds up to 1024K, so it moves to the next unit
+        assert_eq!(format_bytes_compact(1024 * 1024 - 1, iec), "1.0M");
+        assert_eq!(format_bytes_compact(u64::MAX, iec), "16777216T");
+    }
+
+    #[test]
+    fn format_temp_units() {
+        assert_eq!(format_temp(45.5, TempUnit::Celsius), "45.5°C");
+        assert_eq!(format_temp(100.0, TempUnit::Fahrenheit), "212.0°F");
+        assert_eq!(format_temp_compact(45.5, TempUnit::Fahrenheit), "114°F");
+        assert_eq!(format_temp_compact(-40.0, TempUnit::Fahrenheit), "-40°F");
+        assert_eq!(TempUnit::parse("F"), Some(TempUnit::Fahrenheit));
+        assert_eq!(TempUnit::parse("celsius"), Some(TempUnit::Celsius));
+        assert_eq!(TempUnit::parse("k"), None);
+    }
+
+    #[test]
+    fn format_bytes_compact_si() {
+        let si = ByteUnit::Si;
+        assert_eq!(format_bytes_compact(999, si), "999B");
+        assert_eq!(format_bytes_compact(1_000, si), "1.0k");
+        assert_eq!(format_bytes_compact(37_000_000, si), "37M");
+        assert_eq!(format_bytes_compact(999_999, si), "1.0M");
+    }
+
+    #[test]
+    fn format_bytes_si_units() {
+        assert_eq!(format_bytes_with(999, ByteUnit::Si), "999 B");
+        assert_eq!(format_bytes_with(1_000, ByteUnit::Si), "1.0 kB");
+        assert_eq!(format_bytes_with(1_500_000, ByteUnit::Si), "1.5 MB");
+        assert_eq!(format_bytes_with(1024 * 1024, ByteUnit::Iec), "1.0 MiB");
+        assert_eq!(
+            format_bytes_with(2_000_000_000_000_000, ByteUnit::Si),
+            "2000.0 TB"
+        );
+    }
+
     #[test]
     fn mib_to_bytes_conversion() {
         assert_eq!(mib_to_bytes(0), 0);
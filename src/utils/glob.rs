@@ -0,0 +1,48 @@
+/// Match `text` against a shell-style pattern where `*` matches any run of
+/// characters and `?` a single character.
+pub fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    // Last `*` seen and the text position it was tried at, for backtracking
+    let mut star: Option<(usize, usize)> = None;
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p, t));
+                p += 1;
+            }
+            Some(&ch) if ch == '?' || ch == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match star {
+                Some((star_p, star_t)) => {
+                    p = star_p + 1;
+                    t = star_t + 1;
+                    star = Some((star_p, star_t + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|ch| *ch == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn glob_match_wildcards() {
+        assert!(glob_match("systemd-journald", "systemd-journald"));
+        assert!(!glob_match("systemd-journald", "systemd-journal"));
+        assert!(glob_match("kworker/*", "kworker/0:1-events"));
+        assert!(glob_match("*worker*", "kworker/u8:2"));
+        assert!(glob_match("k?hread*", "kthreadd"));
+        assert!(glob_match("*", ""));
+        assert!(!glob_match("kworker/*", "kswapd0"));
+        assert!(glob_match("a*b*c", "axxbyyc"));
+        assert!(!glob_match("a*b*c", "axxbyy"));
+    }
+}
@@ -1,8 +1,19 @@
+mod civil;
+mod clipboard;
 mod command;
 mod format;
+mod glob;
+mod timezone;
 
-pub use command::run_command_with_timeout;
+pub use clipboard::copy_to_clipboard;
+pub use command::{
+    exec_disabled, run_command_with_stderr, run_command_with_timeout, set_exec_disabled,
+    spawn_detached,
+};
 pub use format::{
-    fit_text, format_bytes, format_duration, format_duration_short, mib_to_bytes, percent,
-    render_bar, take_width, text_width,
+    ByteUnit, TempUnit, TimeFormat, fit_text, format_bytes, format_bytes_compact,
+    format_bytes_with, format_cpu_time, format_duration, format_duration_short, format_start_time,
+    format_temp, format_temp_compact, format_time_of_day, mib_to_bytes, percent, render_bar,
+    take_width, text_width,
 };
+pub use glob::glob_match;
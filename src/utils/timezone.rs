@@ -0,0 +1,369 @@
+use std::env;
+use std::fs;
+use std::sync::OnceLock;
+
+use super::civil::{civil_from_days, days_from_civil};
+
+/// Local UTC offset (seconds east of UTC) at `epoch`, from TZ or /etc/localtime.
+///
+/// Falls back to UTC when no zone information is available.
+pub fn local_offset_secs(epoch: i64) -> i64 {
+    static ZONE: OnceLock<Option<Zone>> = OnceLock::new();
+    ZONE.get_or_init(load_local_zone)
+        .as_ref()
+        .map(|zone| zone.offset_at(epoch))
+        .unwrap_or(0)
+}
+
+struct Zone {
+    /// (transition time, offset) pairs sorted by time
+    transitions: Vec<(i64, i64)>,
+    initial_offset: i64,
+    /// POSIX TZ rule used after the last transition
+    rule: Option<PosixRule>,
+}
+
+impl Zone {
+    fn offset_at(&self, epoch: i64) -> i64 {
+        let idx = self.transitions.partition_point(|(time, _)| *time <= epoch);
+        if idx == self.transitions.len()
+            && let Some(rule) = self.rule.as_ref()
+        {
+            return rule.offset_at(epoch);
+        }
+        match idx {
+            0 => self.initial_offset,
+            idx => self.transitions[idx - 1].1,
+        }
+    }
+}
+
+fn load_local_zone() -> Option<Zone> {
+    if let Ok(tz) = env::var("TZ")
+        && !tz.is_empty()
+    {
+        let name = tz.trim_start_matches(':');
+        let path = if name.starts_with('/') {
+            name.to_string()
+        } else {
+            format!("/usr/share/zoneinfo/{name}")
+        };
+        if let Some(zone) = fs::read(path).ok().and_then(|data| parse_tzif(&data)) {
+            return Some(zone);
+        }
+        // TZ may hold a POSIX rule directly (e.g. "CET-1CEST,M3.5.0,M10.5.0/3")
+        if let Some(rule) = parse_posix_tz(name) {
+            return Some(Zone {
+                transitions: Vec::new(),
+                initial_offset: rule.std_offset,
+                rule: Some(rule),
+            });
+        }
+    }
+    let data = fs::read("/etc/localtime").ok()?;
+    parse_tzif(&data)
+}
+
+fn parse_tzif(data: &[u8]) -> Option<Zone> {
+    let header = TzifHeader::parse(data)?;
+    let (header, body, time_size) = if header.version >= b'2' {
+        // Skip the 32-bit block and use the 64-bit one that follows
+        let v2 = data.get(44 + header.block_len(4)..)?;
+        (TzifHeader::parse(v2)?, v2.get(44..)?, 8)
+    } else {
+        (header, data.get(44..)?, 4)
+    };
+
+    let times_len = header.timecnt * time_size;
+    let times = body.get(..times_len)?;
+    let indices = body.get(times_len..times_len + header.timecnt)?;
+    let types_start = times_len + header.timecnt;
+    let types = body.get(types_start..types_start + header.typecnt * 6)?;
+    let offsets: Vec<i64> = types
+        .chunks_exact(6)
+        .map(|chunk| i64::from(i32::from_be_bytes([chunk[0], chunk[1], chunk[2], chunk[3]])))
+        .collect();
+
+    let transitions = times
+        .chunks_exact(time_size)
+        .zip(indices)
+        .filter_map(|(time, idx)| {
+            let time = if time_size == 8 {
+                i64::from_be_bytes(time.try_into().ok()?)
+            } else {
+                i64::from(i32::from_be_bytes(time.try_into().ok()?))
+            };
+            Some((time, *offsets.get(*idx as usize)?))
+        })
+        .collect();
+
+    let rule = if time_size == 8 {
+        let footer = body.get(header.block_len(8)..).unwrap_or_default();
+        std::str::from_utf8(footer)
+            .ok()
+            .and_then(|footer| footer.trim_matches('\n').lines().next())
+            .and_then(parse_posix_tz)
+    } else {
+        None
+    };
+
+    Some(Zone {
+        transitions,
+        initial_offset: offsets.first().copied().unwrap_or(0),
+        rule,
+    })
+}
+
+struct TzifHeader {
+    version: u8,
+    isutcnt: usize,
+    isstdcnt: usize,
+    leapcnt: usize,
+    timecnt: usize,
+    typecnt: usize,
+    charcnt: usize,
+}
+
+impl TzifHeader {
+    fn parse(data: &[u8]) -> Option<Self> {
+        if data.get(..4)? != b"TZif" {
+            return None;
+        }
+        let count = |idx: usize| -> Option<usize> {
+            let start = 20 + idx * 4;
+            let bytes = data.get(start..start + 4)?;
+            Some(u32::from_be_bytes(bytes.try_into().ok()?) as usize)
+        };
+        Some(Self {
+            version: *data.get(4)?,
+            isutcnt: count(0)?,
+            isstdcnt: count(1)?,
+            leapcnt: count(2)?,
+            timecnt: count(3)?,
+            typecnt: count(4)?,
+            charcnt: count(5)?,
+        })
+    }
+
+    /// Length of the data block following the header for the given time size
+    fn block_len(&self, time_size: usize) -> usize {
+        self.timecnt * time_size
+            + self.timecnt
+            + self.typecnt * 6
+            + self.charcnt
+            + self.leapcnt * (time_size + 4)
+            + self.isstdcnt
+            + self.isutcnt
+    }
+}
+
+struct PosixRule {
+    std_offset: i64,
+    dst: Option<DstRule>,
+}
+
+struct DstRule {
+    offset: i64,
+    start: RuleDate,
+    end: RuleDate,
+}
+
+/// "Mm.w.d/time" transition date: day `weekday` of week `week` (5 = last) in `month`
+struct RuleDate {
+    month: u32,
+    week: u32,
+    weekday: u32,
+    time: i64,
+}
+
+impl RuleDate {
+    /// Local time of the transition in `year`, in seconds since the epoch
+    fn local_secs(&self, year: i64) -> i64 {
+        let first = days_from_civil(year, self.month, 1);
+        // 1970-01-01 was a Thursday (weekday 4)
+        let first_weekday = (first + 4).rem_euclid(7) as u32;
+        let mut day = 1 + (self.weekday + 7 - first_weekday) % 7 + (self.week - 1) * 7;
+        let month_len = days_in_month(year, self.month);
+        while day > month_len {
+            day -= 7;
+        }
+        days_from_civil(year, self.month, day) * 86_400 + self.time
+    }
+}
+
+impl PosixRule {
+    fn offset_at(&self, epoch: i64) -> i64 {
+        let Some(dst) = self.dst.as_ref() else {
+            return self.std_offset;
+        };
+        let (year, _, _) = civil_from_days((epoch + self.std_offset).div_euclid(86_400));
+        let start = dst.start.local_secs(year) - self.std_offset;
+        let end = dst.end.local_secs(year) - dst.offset;
+        let in_dst = if start < end {
+            epoch >= start && epoch < end
+        } else {
+            // Southern hemisphere: DST spans the new year
+            epoch >= start || epoch < end
+        };
+        if in_dst { dst.offset } else { self.std_offset }
+    }
+}
+
+/// Parse a POSIX TZ string. Only "Mm.w.d" transition dates are supported; zones
+/// using the "Jn" or "n" forms keep the offset of their last listed transition.
+fn parse_posix_tz(value: &str) -> Option<PosixRule> {
+    let rest = skip_zone_name(value)?;
+    let (std_offset, rest) = parse_hms(rest)?;
+    // POSIX offsets are west-positive
+    let std_offset = -std_offset;
+    if rest.is_empty() {
+        return Some(PosixRule {
+            std_offset,
+            dst: None,
+        });
+    }
+    let rest = skip_zone_name(rest)?;
+    let (dst_offset, rest) = match rest.strip_prefix(',') {
+        Some(_) => (std_offset + 3_600, rest),
+        None => {
+            let (offset, rest) = parse_hms(rest)?;
+            (-offset, rest)
+        }
+    };
+    let mut dates = rest.strip_prefix(',')?.split(',');
+    let start = parse_rule_date(dates.next()?)?;
+    let end = parse_rule_date(dates.next()?)?;
+    Some(PosixRule {
+        std_offset,
+        dst: Some(DstRule {
+            offset: dst_offset,
+            start,
+            end,
+        }),
+    })
+}
+
+fn skip_zone_name(value: &str) -> Option<&str> {
+    if let Some(rest) = value.strip_prefix('<') {
+        let end = rest.find('>')?;
+        return Some(&rest[end + 1..]);
+    }
+    let end = value
+        .find(|ch: char| !ch.is_ascii_alphabetic())
+        .unwrap_or(value.len());
+    (end >= 3).then(|| &value[end..])
+}
+
+/// Parse "[+-]hh[:mm[:ss]]" into seconds, returning the unparsed remainder
+fn parse_hms(value: &str) -> Option<(i64, &str)> {
+    let (sign, value) = match value.as_bytes().first()? {
+        b'-' => (-1, &value[1..]),
+        b'+' => (1, &value[1..]),
+        _ => (1, value),
+    };
+    let end = value
+        .find(|ch: char| !ch.is_ascii_digit() && ch != ':')
+        .unwrap_or(value.len());
+    let mut secs = 0;
+    for (idx, part) in value[..end].split(':').enumerate() {
+        let part = part.parse::<i64>().ok()?;
+        secs += part * [3_600, 60, 1].get(idx)?;
+    }
+    Some((sign * secs, &value[end..]))
+}
+
+fn parse_rule_date(value: &str) -> Option<RuleDate> {
+    let (date, time) = match value.split_once('/') {
+        Some((date, time)) => (date, parse_hms(time)?.0),
+        None => (value, 7_200),
+    };
+    let mut parts = date.strip_prefix('M')?.split('.');
+    let month = parts.next()?.parse().ok()?;
+    let week = parts.next()?.parse().ok()?;
+    let weekday = parts.next()?.parse().ok()?;
+    if !(1..=12).contains(&month) || !(1..=5).contains(&week) || weekday > 6 {
+        return None;
+    }
+    Some(RuleDate {
+        month,
+        week,
+        weekday,
+        time,
+    })
+}
+
+fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if (year % 4 == 0 && year % 100 != 0) || year % 400 == 0 => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn posix_rule_applies_dst() {
+        let rule = parse_posix_tz("CET-1CEST,M3.5.0,M10.5.0/3").unwrap();
+        // 2024-01-15 12:00 UTC -> winter, UTC+1
+        assert_eq!(rule.offset_at(1_705_320_000), 3_600);
+        // 2024-07-15 12:00 UTC -> summer, UTC+2
+        assert_eq!(rule.offset_at(1_721_044_800), 7_200);
+    }
+
+    /// Europe/Berlin from tzdata, with both the 32-bit and 64-bit blocks
+    const BERLIN: &[u8] = include_bytes!("testdata/Europe_Berlin.tzif");
+
+    fn utc(year: i64, month: u32, day: u32, hour: i64) -> i64 {
+        days_from_civil(year, month, day) * 86_400 + hour * 3_600
+    }
+
+    #[test]
+    fn tzif_transitions_follow_dst() {
+        let zone = parse_tzif(BERLIN).unwrap();
+        // Transitions happen at 01:00 UTC on the last Sunday of March/October
+        assert_eq!(zone.offset_at(utc(2024, 3, 31, 1) - 1), 3_600);
+        assert_eq!(zone.offset_at(utc(2024, 3, 31, 1)), 7_200);
+        assert_eq!(zone.offset_at(utc(2024, 10, 27, 1) - 1), 7_200);
+        assert_eq!(zone.offset_at(utc(2024, 10, 27, 1)), 3_600);
+        // Double summer time in 1947 (UTC+3)
+        assert_eq!(zone.offset_at(utc(1947, 6, 1, 12)), 10_800);
+        // Local mean time before the first transition
+        assert_eq!(zone.offset_at(utc(1880, 1, 1, 0)), 3_208);
+    }
+
+    #[test]
+    fn tzif_footer_rule_covers_later_years() {
+        let zone = parse_tzif(BERLIN).unwrap();
+        assert!(zone.rule.is_some());
+        let last = zone.transitions.last().unwrap().0;
+        assert!(last < utc(2100, 1, 1, 0));
+        assert_eq!(zone.offset_at(utc(2100, 3, 28, 1) - 1), 3_600);
+        assert_eq!(zone.offset_at(utc(2100, 3, 28, 1)), 7_200);
+        assert_eq!(zone.offset_at(utc(2100, 10, 31, 1)), 3_600);
+    }
+
+    #[test]
+    fn tzif_v1_block_parses_alone() {
+        let header = TzifHeader::parse(BERLIN).unwrap();
+        let mut v1 = BERLIN[..44 + header.block_len(4)].to_vec();
+        v1[4] = 0;
+        let zone = parse_tzif(&v1).unwrap();
+        assert!(zone.rule.is_none());
+        assert_eq!(zone.offset_at(utc(2024, 7, 15, 12)), 7_200);
+        assert_eq!(zone.offset_at(utc(2024, 1, 15, 12)), 3_600);
+
+        assert!(parse_tzif(&BERLIN[..100]).is_none());
+        assert!(parse_tzif(b"not a zone file").is_none());
+    }
+
+    #[test]
+    fn posix_rule_without_dst() {
+        let rule = parse_posix_tz("<+03>-3").unwrap();
+        assert_eq!(rule.offset_at(1_721_044_800), 10_800);
+        assert_eq!(parse_posix_tz("UTC0").unwrap().offset_at(0), 0);
+    }
+}